@@ -0,0 +1,90 @@
+use num_rational::BigRational;
+use num_traits::{One, Zero};
+
+use crate::parsers::number::Data;
+use crate::parsers::number::exact::{parse_numeral, table_value};
+
+/// The decimal point of Chinese numerals: `三点五` is 3.5.
+const POINTS: [char; 2] = ['点', '點'];
+
+/// Converts Chinese and Japanese numerals: `三百五十` -> 350, `一万二千` -> 12000,
+/// `二十一` -> 21, `3万五千` -> 35000, `壹佰贰拾` -> 120, `二〇二四` -> 2024, `三点五` -> 3.5.
+///
+/// Digits (`一`, `壹`, `3`) multiply the unit after them (`十`, `百`, `千`), which go down within
+/// each group of a myriad (`万`, `億`, `兆`). The myriads go down too, and a unit alone is one
+/// of it: `十五` is 15. `None` without any CJK digit or unit.
+pub fn words2cjk(text: &str, data: &Data) -> Option<BigRational> {
+    let (digits, units) = (data.cjk_digits(), data.cjk_units());
+    let text = text.trim();
+    if !text.chars().any(|c| digits.contains_key(&c) || units.contains_key(&c)) {
+        return None;
+    }
+    let (whole, fraction) = match text.split_once(POINTS) {
+        Some((whole, fraction)) => (whole, Some(fraction)),
+        None => (text, None),
+    };
+    let mut value = whole_part(whole, data)?;
+    if let Some(fraction) = fraction {
+        let fraction: Option<String> = fraction.chars().map(|c| ascii_digit(c, data)).collect();
+        value += parse_numeral(&format!("0.{}", fraction?))?;
+    }
+    Some(value)
+}
+
+/// A CJK or ASCII digit as ASCII.
+fn ascii_digit(c: char, data: &Data) -> Option<char> {
+    match data.cjk_digits().get(&c) {
+        Some(digit) => char::from_digit(*digit as u32, 10),
+        None => c.is_ascii_digit().then_some(c),
+    }
+}
+
+fn whole_part(text: &str, data: &Data) -> Option<BigRational> {
+    let (units, myriads) = (data.cjk_units(), data.myriads());
+    let mut total = BigRational::zero();
+    // the number below the current myriad
+    let mut group = BigRational::zero();
+    // the digits before the next unit, `二〇二四` is read digit by digit
+    let mut digits = String::new();
+    let mut last_unit: Option<BigRational> = None;
+    let mut last_myriad: Option<BigRational> = None;
+    for c in text.chars() {
+        if let Some(digit) = ascii_digit(c, data) {
+            digits.push(digit);
+            continue;
+        }
+        if matches!(c, '.' | ',') {
+            digits.push(c);
+            continue;
+        }
+        let before = if digits.is_empty() { None } else { Some(parse_numeral(&digits)?) };
+        digits.clear();
+        if let Some(unit) = units.get(&c) {
+            let unit = table_value(*unit)?;
+            if last_unit.as_ref().is_some_and(|last| *last <= unit) {
+                return None;
+            }
+            group += before.unwrap_or_else(BigRational::one) * &unit;
+            last_unit = Some(unit);
+        } else if let Some(myriad) = myriads.get(&c) {
+            let myriad = table_value(*myriad)?;
+            if last_myriad.as_ref().is_some_and(|last| *last <= myriad) {
+                return None;
+            }
+            group += before.unwrap_or_else(BigRational::zero);
+            if group.is_zero() {
+                group = BigRational::one();
+            }
+            total += &group * &myriad;
+            group = BigRational::zero();
+            last_unit = None;
+            last_myriad = Some(myriad);
+        } else {
+            return None;
+        }
+    }
+    if !digits.is_empty() {
+        group += parse_numeral(&digits)?;
+    }
+    Some(total + group)
+}
@@ -1,34 +1,84 @@
 use std::collections::HashMap;
 use std::borrow::Cow;
+use std::cmp::Ordering;
 use std::hash::{Hash, Hasher};
 
-use crate::parsers::number::{DataHolder, Data};
+use fancy_regex::Regex;
+use lazy_static::lazy_static;
 
+use crate::parsers::number::{DataHolder, Data, words2complex, words2fraction, words2roman};
+use crate::parsers::number::locale::canonical_numerals;
+use crate::parsers::number::constants::{
+    BINARY, COMPLEX, FLOAT, HEX, INTEGER, OCTAL, ORDINAL, RATIONAL, ROMAN, SPOKEN, SUPERSCRIPT,
+};
+
+lazy_static! {
+    static ref RATIONAL_RE: Regex = Regex::new(r"^(?:[-+]?\d+\s+)?[-+]?\d+/\d+$").unwrap();
+}
+
+#[derive(Clone)]
 pub struct CompStr {
     pub val: DataHolder,
     pub data: Data,
     _is_ordinal: bool,
     _is_suffix: bool,
+    _is_denominator: bool,
     _informal_exact: bool,
-    _informal_multiplyable: bool
+    _informal_multiplyable: bool,
+    _value: Option<f64>,
 }
 
 impl CompStr {
     
     pub fn new(val: DataHolder, data: Data) -> Self {
+        let _value = match val.type_ {
+            "text" => {
+                let text = val.text.clone().unwrap_or_default();
+                data.all_nums().get(&text).copied().or_else(|| {
+                    // numerals compare by value too, but `nan` and `inf` are words
+                    if text.chars().any(|c| c.is_ascii_digit()) {
+                        canonical_numerals(&text, &data).parse::<f64>().ok()
+                    } else {
+                        None
+                    }
+                })
+            }
+            "float" => val.float,
+            _ => val.int.map(|v| v as f64),
+        };
         CompStr { 
-            val: val,
-            data: data,
+            val,
+            data,
             _is_ordinal: false,
             _is_suffix: false,
+            _is_denominator: false,
             _informal_exact: false,
             _informal_multiplyable: false,
+            _value,
         }
     }
+
+    /// A sentinel that compares greater than every number, used to start a chain of multiples.
+    pub fn always_big(data: Data) -> Self {
+        let mut comp = CompStr::new(DataHolder {
+            type_: "text",
+            text: Some("always-big".to_string()),
+            int: None,
+            float: None,
+        }, data);
+        comp._value = Some(f64::INFINITY);
+        comp
+    }
+
+    /// The numeric value of the token, `None` for words that aren't numbers.
+    pub fn value(&self) -> Option<f64> {
+        self._value
+    }
     
     pub fn string(&mut self, strg: String) {
         self._is_ordinal = self.data.ordinals().contains_key(&strg);
         self._is_suffix = self.data.suffixes_by_name().contains_key(&strg);
+        self._is_denominator = self.data.fraction_denominators().contains_key(&strg);
         self._informal_exact = self.data.informal_exact().contains_key(&strg);
         self._informal_multiplyable = self.data.informals_multiplyable().contains_key(&strg);
     }
@@ -36,79 +86,53 @@ impl CompStr {
     pub fn ones(&mut self) -> bool {
         let string = match self.val.type_ {
             "text" => self.val.text.clone().unwrap(),
-            "float" => self.val.float.clone().unwrap().to_string(),
-            _ => self.val.int.clone().unwrap().to_string(),
+            "float" => self.val.float.unwrap().to_string(),
+            _ => self.val.int.unwrap().to_string(),
         };
-        for (k, v) in &self.data.ones() {
-            if string == k.to_string() || string == v.to_string() {
-                return true;
-            }
-        }
-        false
+        in_table(self.data.ones(), &string)
     }
     
     pub fn tens(&mut self) -> bool {
         let string = match self.val.type_ {
             "text" => self.val.text.clone().unwrap(),
-            "float" => self.val.float.clone().unwrap().to_string(),
-            _ => self.val.int.clone().unwrap().to_string(),
+            "float" => self.val.float.unwrap().to_string(),
+            _ => self.val.int.unwrap().to_string(),
         };
-        for (k, v) in &self.data.tens() {
-            if string == k.to_string() || string == v.to_string() {
-                return true;
-            }
-        }
-        false
+        in_table(self.data.tens(), &string)
     }
     
     pub fn teens(&mut self) -> bool {
         let string = match self.val.type_ {
             "text" => self.val.text.clone().unwrap(),
-            "float" => self.val.float.clone().unwrap().to_string(),
-            _ => self.val.int.clone().unwrap().to_string(),
+            "float" => self.val.float.unwrap().to_string(),
+            _ => self.val.int.unwrap().to_string(),
         };
-        for (k, v) in &self.data.teens_and_ten() {
-            if string == k.to_string() || string == v.to_string() {
-                return true;
-            }
-        }
-        false
+        in_table(self.data.teens_and_ten(), &string)
     }
         
     pub fn multiples(&mut self) -> bool {
         let string = match self.val.type_ {
             "text" => self.val.text.clone().unwrap(),
-            "float" => self.val.float.clone().unwrap().to_string(),
-            _ => self.val.int.clone().unwrap().to_string(),
+            "float" => self.val.float.unwrap().to_string(),
+            _ => self.val.int.unwrap().to_string(),
         };
-        for (k, v) in &self.data.multiples() {
-            if string == k.to_string() || string == v.to_string() {
-                return true;
-            }
-        }
-        false
+        in_table(self.data.multiples(), &string)
     }
     
     pub fn hundred(&mut self) -> bool {
         let string = match self.val.type_ {
             "text" => self.val.text.clone().unwrap(),
-            "float" => self.val.float.clone().unwrap().to_string(),
-            _ => self.val.int.clone().unwrap().to_string(),
+            "float" => self.val.float.unwrap().to_string(),
+            _ => self.val.int.unwrap().to_string(),
         };
-        let hundreds = vec!["100", "hundred"];
-        for k in &hundreds {
-            if string == k.to_string() {
-                return true;
-            }
-        }
-        false
+        string == "100" || self.data.hundreds().contains_key(&string)
     }
 
     pub fn is_point(&mut self) -> bool {
         let string = match self.val.type_ {
             "text" => self.val.text.clone().unwrap(),
-            "float" => self.val.float.clone().unwrap().to_string(),
-            _ => self.val.int.clone().unwrap().to_string(),
+            "float" => self.val.float.unwrap().to_string(),
+            _ => self.val.int.unwrap().to_string(),
         };
         self.data.points().contains(&string)
     }
@@ -116,18 +140,10 @@ impl CompStr {
     pub fn is_num_word(&mut self) -> bool {
         let string = match self.val.type_ {
             "text" => self.val.text.clone().unwrap(),
-            "float" => self.val.float.clone().unwrap().to_string(),
-            _ => self.val.int.clone().unwrap().to_string(),
+            "float" => self.val.float.unwrap().to_string(),
+            _ => self.val.int.unwrap().to_string(),
         };
-        let mut all_: HashMap<String, f64> = HashMap::new();
-        all_.extend(self.data.all_valid());
-        all_.extend(self.data.informal_all());
-        for (k, _) in &all_ {
-            if string == k.to_string() {
-                return true;
-            }
-        }
-        false
+        self.data.all_valid().contains_key(&string) || self.data.informal_all().contains_key(&string)
     }
     
     pub fn informal_exact(&self) -> bool {
@@ -141,8 +157,8 @@ impl CompStr {
     pub fn is_and(&mut self) -> bool {
         let string = match self.val.type_ {
             "text" => self.val.text.clone().unwrap(),
-            "float" => self.val.float.clone().unwrap().to_string(),
-            _ => self.val.int.clone().unwrap().to_string(),
+            "float" => self.val.float.unwrap().to_string(),
+            _ => self.val.int.unwrap().to_string(),
         };
         self.data.ands().contains(&string)
     }
@@ -150,8 +166,8 @@ impl CompStr {
     pub fn is_a(&mut self) -> bool {
         let string = match self.val.type_ {
             "text" => self.val.text.clone().unwrap(),
-            "float" => self.val.float.clone().unwrap().to_string(),
-            _ => self.val.int.clone().unwrap().to_string(),
+            "float" => self.val.float.unwrap().to_string(),
+            _ => self.val.int.unwrap().to_string(),
         };
         self.data.a().contains(&string)
     }
@@ -163,6 +179,16 @@ impl CompStr {
     pub fn is_suffix(&self) -> bool {
         self._is_suffix
     }
+
+    /// `third`, `thirds`, `half`, `quarters` ...
+    pub fn is_denominator(&self) -> bool {
+        self._is_denominator
+    }
+
+    /// A plural denominator takes any numerator: `five eighths` but `one eighth`.
+    pub fn is_plural_denominator(&self) -> bool {
+        self._is_denominator && self.val.text.as_deref().is_some_and(|t| t.ends_with('s'))
+    }
     
     pub fn __lt__(&self, _other: DataHolder) -> bool {
         false
@@ -180,13 +206,13 @@ impl CompStr {
     pub fn __eq__(&mut self, other: DataHolder) -> bool {
         let string = match self.val.type_ {
             "text" => self.val.text.clone().unwrap(),
-            "float" => self.val.float.clone().unwrap().to_string(),
-            _ => self.val.int.clone().unwrap().to_string(),
+            "float" => self.val.float.unwrap().to_string(),
+            _ => self.val.int.unwrap().to_string(),
         };
         let other_string = match other.type_ {
             "text" => other.text.clone().unwrap(),
-            "float" => other.float.clone().unwrap().to_string(),
-            _ => other.int.clone().unwrap().to_string(),
+            "float" => other.float.unwrap().to_string(),
+            _ => other.int.unwrap().to_string(),
         };
         string.to_lowercase() == other_string.to_lowercase()
     }
@@ -199,136 +225,125 @@ impl CompStr {
     }
 }
 
-/*
-class ModInt(int, DataAttrGetter):
-    
-    def __init__(self, val):
-        super().__init__(int(val))
-
-    @property
-    def is_num_word(self):
-        return True
-
-    @property
-    def is_ordinal(self):
-        return self._is_ordinal
-        
-    @is_ordinal.setter
-    def is_ordinal(self, val: bool):
-        self._is_ordinal = val
+// Tokens compare by value; a word that isn't a number is neither smaller,
+// greater nor equal to anything.
+impl PartialEq for CompStr {
+    fn eq(&self, other: &Self) -> bool {
+        self._value.is_some() && self._value == other._value
+    }
+}
 
+impl PartialOrd for CompStr {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self._value?.partial_cmp(&other._value?)
+    }
+}
 
-class ModFloat(float, DataAttrGetter):
-    
-    def __init__(self, val):
-        super().__init__(float(val))
+impl PartialEq<f64> for CompStr {
+    fn eq(&self, other: &f64) -> bool {
+        self._value == Some(*other)
+    }
+}
 
-    @property
-    def is_num_word(self):
-        return True
+impl PartialOrd<f64> for CompStr {
+    fn partial_cmp(&self, other: &f64) -> Option<Ordering> {
+        self._value?.partial_cmp(other)
+    }
+}
 
-    @property
-    def is_ordinal(self):
-        return False
+/// Works out how a matched number was written (spoken, hexadecimal, ordinal ...).
+pub struct NumberInfo {
+    data: Data,
+}
 
-cdef get_suffix(n, data):
-    v = ""
-    suffixes = tuple(data.SUFFIXES.keys())
-    max_len = len(max(suffixes, key=len))
-    if not n.lower().endswith(suffixes):
-        return v
-    for ch in n[::-1]:
-        if ch.isalpha():
-            v += ch
-        else:
-            break
-    if len(v) > max_len:
-        return ""
-    return v[::-1]
+impl NumberInfo {
 
-cdef match(regex, text, data):
-    res = re.search(
-        f"(?:{regex})",
-        text,
-        data.FLAGS.get(regex) or data.DEFAULT_RE_FLAGS,
-    )
-    return res and res.group() == text
+    pub fn new(data: Data) -> Self {
+        NumberInfo { data }
+    }
 
+    pub fn number_type(&mut self, num_string: &str) -> &'static str {
+        let superscripts = self.data.superscript_ones();
+        let fractions = self.data.superscript_fractions();
+        if num_string.chars().any(|c| superscripts.contains_key(&c) || fractions.contains_key(&c)) {
+            SUPERSCRIPT
+        } else if self.data.config().parse_complex.unwrap_or(false) && words2complex(num_string, &self.data).is_some() {
+            COMPLEX
+        } else if self.data.config().parse_roman.unwrap_or(false) && words2roman(num_string, &self.data).is_some() {
+            ROMAN
+        } else if RATIONAL_RE.is_match(num_string).unwrap_or(false) {
+            RATIONAL
+        } else if self.is_spoken(num_string) && words2fraction(num_string, &self.data).is_some() {
+            // `a third` is a fraction, `twenty third` an ordinal
+            SPOKEN
+        } else if self.get_ordinal_suffix(num_string).is_some() {
+            ORDINAL
+        } else if full_match(&self.data.regexes().binary, num_string) {
+            BINARY
+        } else if full_match(&self.data.regexes().hex, num_string) {
+            HEX
+        } else if full_match(&self.data.regexes().oct, num_string) {
+            OCTAL
+        } else if self.is_spoken(num_string) {
+            SPOKEN
+        } else if self.is_integer(num_string) {
+            INTEGER
+        } else {
+            FLOAT
+        }
+    }
 
-def convert_match(n, converter, data):
-    n = converter([n], data)
-    if len(n) != 1:
-        return 
-    return n[0]
+    pub fn get_ordinal_suffix(&mut self, num_string: &str) -> Option<String> {
+        let lower = num_string.to_lowercase();
+        // `3rd` and `twenty third` but not `thousand`
+        let last_word = lower.rsplit(|c: char| !c.is_alphanumeric()).next().unwrap_or_default();
+        if !full_match(&self.data.regexes().ordinal_numeral, last_word)
+            && !self.data.ordinals().contains_key(last_word) {
+            return None;
+        }
+        let suffix = self.data
+            .ordinal_suffixes()
+            .iter()
+            .find(|suffix| lower.ends_with(suffix.as_str()))
+            .cloned();
+        // `primero` doesn't end with a suffix
+        match suffix {
+            None if self.data.ordinals().contains_key(last_word) => Some(String::new()),
+            suffix => suffix,
+        }
+    }
 
+    pub fn is_spoken(&mut self, num_string: &str) -> bool {
+        // `三百五十`
+        let (digits, units) = (self.data.cjk_digits(), self.data.cjk_units());
+        if num_string.chars().any(|c| digits.contains_key(&c) || units.contains_key(&c)) {
+            return true;
+        }
+        let valid = self.data.all_valid();
+        num_string
+            .split(|c: char| !c.is_alphabetic())
+            .flat_map(|word| self.data.split_compound(word))
+            .any(|word| valid.contains_key(&word.to_lowercase()))
+    }
 
-cdef class NumberInfo:
-    
-    def __cinit__(self, data):
-        self.data = data
-    
-    cpdef generate(
-        self,
-        str num_string,
-        num_val,
-        tokens,
-    ):
-        d = {}
-        data = self.data
-        if isinstance(num_val, complex):
-            d[NUMBER_TYPE] = NumberType.COMPLEX
-        elif set(num_string) & {*data.SUPERSCRIPT_ONES, *data.SUPERSCRIPT_FRACTIONS}:
-            d[NUMBER_TYPE] = NumberType.SUPERSCRIPT
-        elif self.get_ordinal_suffix(num_string):
-            suffix = self.get_ordinal_suffix(num_string)
-            d[NUMBER_TYPE] = NumberType.ORDINAL
-            d["suffix"] = suffix
-            
-        elif match(data.BINARY_REGEX, num_string, data):
-            d[NUMBER_TYPE] = NumberType.BINARY
-            
-        elif match(self.data.HEX_REGEX, num_string, data):
-            d[NUMBER_TYPE] = NumberType.HEX
-            
-        elif match(self.data.OCT_REGEX, num_string, data):
-            d[NUMBER_TYPE] = NumberType.OCTAL
-            
-        elif self.is_spoken(num_string):
-            d[NUMBER_TYPE] = NumberType.SPOKEN
-            
-        elif self.is_integer(num_string):
-            d[NUMBER_TYPE] = NumberType.INTEGER
-            
-        elif self.is_float(num_string):
-            d[NUMBER_TYPE] = NumberType.FLOAT
-        
-        d[VALUE_TYPE] = NumberType.INTEGER
-        if isinstance(num_val, float):
-            d[VALUE_TYPE] = NumberType.FLOAT
-        if isinstance(num_val, complex):
-            d[VALUE_TYPE] = NumberType.COMPLEX
-            d[NUMBER_TYPE] = NumberType.COMPLEX
-        
-        return d
+    pub fn is_integer(&mut self, num_string: &str) -> bool {
+        if self.is_spoken(num_string) {
+            return false;
+        }
+        !num_string.to_lowercase().contains(['e', '.'])
+    }
 
-    cdef get_ordinal_suffix(self, str num_string):
-        s = num_string.lower()
-        if s.endswith(tuple(self.data.ORDINAL_SUFFIXES)):
-            return num_string[-2:]
+    pub fn is_float(&mut self, num_string: &str) -> bool {
+        !self.is_spoken(num_string) && !self.is_integer(num_string)
+    }
+}
 
-    cdef is_spoken(self, str s):
-        tks = tokenize(Pipe(data=self.data)(s), data=self.data)
-        if len(tks) > 1:
-            return True
-        return tks[0].isalpha()
-    
-    cdef is_integer(self, str s):
-        if self.is_spoken(s):
-            return False
-        return not bool(set("e.") & set(s.lower()))
+/// Whether `string` is a word of `table` or one of its values written out: `one`, `1`.
+fn in_table(table: &HashMap<String, f64>, string: &str) -> bool {
+    table.contains_key(string)
+        || string.parse::<f64>().is_ok_and(|n| n.to_string() == string && table.values().any(|v| *v == n))
+}
 
-    cdef is_float(self, str s):
-        if self.is_spoken(s) or self.is_integer(s):
-            return False
-        return True
-*/
\ No newline at end of file
+fn full_match(re: &Regex, text: &str) -> bool {
+    re.is_match(text).unwrap_or(false)
+}
@@ -0,0 +1,50 @@
+use crate::parsers::number::{words2num, Data, DataHolder};
+
+/// Splits a complex number into its real and imaginary parts.
+///
+/// Typed: `3+4i`, `5 - 2i`, `-2.5j`, `3+i`. Spoken: `two plus three i`, `minus one minus i`.
+/// Each part is converted with [`words2num`], a missing imaginary coefficient is one.
+pub fn words2complex(text: &str, data: &Data) -> Option<(f64, f64)> {
+    let text = text.trim();
+    // a clone shares the tables while `data` converts the parts
+    let shared = data.clone();
+    let units = shared.imaginary_units();
+    let unit = units.iter().find(|u| text.ends_with(u.as_str()))?;
+    let body = &text[..text.len() - unit.len()];
+    let signs = shared.complex_signs();
+    // the unit follows a number or a sign: `4i`, `3+i`, `four i` but not `pi` or `I`
+    let last_word = body.split_whitespace().last()?.to_lowercase();
+    let follows_number = body.ends_with(|c: char| c.is_ascii_digit() || c == '+' || c == '-')
+        || (body.ends_with(char::is_whitespace)
+            && (data.all_nums().contains_key(&last_word) || signs.contains_key(&last_word)));
+    if !follows_number {
+        return None;
+    }
+    let body = body.trim_end();
+
+    let split = &shared.regexes().complex_split;
+    let (real, sign, imag) = match split.captures(body).ok().flatten() {
+        Some(caps) => (
+            part(caps.name("real")?.as_str(), data)?,
+            *signs.get(&caps.name("sign")?.as_str().to_lowercase())?,
+            caps.name("imag")?.as_str().trim(),
+        ),
+        None => (0.0, 1.0, body),
+    };
+    let imag = match imag {
+        "" | "+" => 1.0,
+        "-" => -1.0,
+        imag => part(imag, data)?,
+    };
+    Some((real, sign * imag))
+}
+
+fn part(text: &str, data: &Data) -> Option<f64> {
+    words2num(DataHolder {
+        type_: "text",
+        text: Some(text.trim().to_string()),
+        int: None,
+        float: None,
+    }, data)?
+    .to_f64()
+}
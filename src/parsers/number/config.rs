@@ -1,10 +1,18 @@
 
 
+use crate::parsers::number::locale::NumberLocale;
+use crate::parsers::number::scales::NumberScale;
+
 #[derive(Clone, Debug)]
 pub struct Config {
     pub language: Option<String>,
     pub signs_allowed: Option<bool>,
+    /// Extract complex numbers (`3+4i`, `two plus three i`) instead of separate parts.
     pub parse_complex: Option<bool>,
+    /// Extract Roman numerals (`XIV`, `Chapter IX`, `Louis XVI`), see [`words2roman`](crate::parsers::number::words2roman).
+    pub parse_roman: Option<bool>,
+    /// Accept Roman numerals outside the standard form, eg: `IIII` and `IC`.
+    pub lenient_roman: Option<bool>,
     pub bounded_numbers: Option<bool>,
     pub mixed_nums: Option<bool>,
     pub merge: Option<bool>,
@@ -12,6 +20,21 @@ pub struct Config {
     pub merge_implied: Option<bool>,
     pub merge_points: Option<bool>,
     pub merge_informals: Option<bool>,
+    /// Join powers (`5 × 10^3`, `10⁶`, `ten to the sixth`, `two squared`), see [`merge_powers`](crate::parsers::number::merge_powers).
+    pub merge_powers: Option<bool>,
+    /// Compute values exactly (big integers and decimals) instead of in `f64`.
+    pub exact: Option<bool>,
+    /// Accept numerals with the wrong ordinal suffix, eg: `21th`.
+    pub lenient_ordinals: Option<bool>,
+    /// Read numbers the way source code writes them: `0755` is octal, `10u32` and `1.5f`
+    /// have type suffixes. Off by default, `0755` is 755 in prose and `1.5f` is femto.
+    pub code_literals: Option<bool>,
+    /// The decimal mark and digit grouping of numerals: `1,234.56`, `1.234,56`, `12,34,567`.
+    /// English by default, see [`NumberLocale`].
+    pub number_locale: Option<NumberLocale>,
+    /// What `billion`, `trillion` and the `B` suffix are worth, see [`NumberScale`]. The scale
+    /// of `language` when `None`, and matches depending on it are flagged as ambiguous.
+    pub number_scale: Option<NumberScale>,
     pub exclude_separators: Option<Vec<String>>,
     pub exclude_suffixes: Option<Vec<String>>,
 }
@@ -22,6 +45,8 @@ impl Default for Config {
             language: Some("en".to_string()),
             signs_allowed: Some(false),
             parse_complex: Some(false),
+            parse_roman: Some(false),
+            lenient_roman: Some(false),
             bounded_numbers: Some(false),
             mixed_nums: Some(true),
             merge: Some(true),
@@ -29,6 +54,12 @@ impl Default for Config {
             merge_implied: Some(false),
             merge_points: Some(false),
             merge_informals: Some(true),
+            merge_powers: Some(true),
+            exact: Some(false),
+            lenient_ordinals: Some(false),
+            code_literals: Some(false),
+            number_locale: Some(NumberLocale::default()),
+            number_scale: None,
             exclude_separators: None,
             exclude_suffixes: vec!["m".to_string(), "y".to_string()].into(),
         }
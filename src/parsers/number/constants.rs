@@ -1,20 +1,36 @@
-pub static DEFAULT_LANGUAGE: &'static str = "en";
-pub static FIRST_EXTRACTION_REGEXES: &'static str = "FIRST_EXTRACTION_REGEXES";
-pub static FLAGS: &'static str = "FLAGS";
-pub static LAST_EXTRACTION_REGEXES: &'static str = "LAST_EXTRACTION_REGEXES";
+use fancy_regex::Regex;
+use lazy_static::lazy_static;
 
-pub static NUMBER_TYPE: &'static str = "number_type";
-pub static VALUE_TYPE: &'static str = "value_type";
+pub static DEFAULT_LANGUAGE: &str = "en";
+pub static FIRST_EXTRACTION_REGEXES: &str = "FIRST_EXTRACTION_REGEXES";
+pub static FLAGS: &str = "FLAGS";
+pub static LAST_EXTRACTION_REGEXES: &str = "LAST_EXTRACTION_REGEXES";
 
+pub static NUMBER: &str = "number";
+pub static RANGE: &str = "range";
+pub static PERCENTAGE: &str = "percentage";
+pub static MONEY: &str = "money";
+pub static QUANTITY: &str = "quantity";
+pub static NUMBER_TYPE: &str = "number_type";
+pub static VALUE_TYPE: &str = "value_type";
 
-pub static ORDINAL: &'static str = "ordinal";
-pub static FLOAT: &'static str = "float";
-pub static INTEGER: &'static str = "integer";
-pub static COMPLEX: &'static str = "complex";
-pub static BINARY: &'static str = "binary";
-pub static OCTAL: &'static str = "octal";
-pub static HEX: &'static str = "hexadecimal";
-pub static SPOKEN: &'static str = "spoken";
-pub static SUPERSCRIPT: &'static str = "superscript";
 
-pub static _REPLACEMENT: &'static str = "?";
\ No newline at end of file
+pub static ORDINAL: &str = "ordinal";
+pub static FLOAT: &str = "float";
+pub static RATIONAL: &str = "rational";
+pub static INTEGER: &str = "integer";
+pub static COMPLEX: &str = "complex";
+pub static BINARY: &str = "binary";
+pub static OCTAL: &str = "octal";
+pub static HEX: &str = "hexadecimal";
+pub static ROMAN: &str = "roman";
+pub static SPOKEN: &str = "spoken";
+pub static SUPERSCRIPT: &str = "superscript";
+pub static POWER: &str = "power";
+
+pub static _REPLACEMENT: &str = "?";
+
+lazy_static! {
+    /// A hyphen between two letters, read as a space: `twenty-one`, `two-thirds`.
+    pub(crate) static ref WORD_HYPHEN: Regex = Regex::new(r"(?<=\p{L})-(?=\p{L})").unwrap();
+}
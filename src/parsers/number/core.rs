@@ -1,155 +1,284 @@
-# coding: utf-8
-# cython: embedsignature=True
-# cython: language_level=3
-# cython: profile=False
-
-import regex as re
-from .ejtoken import tokenize
-from .normalize cimport (
-    Pipe,
-    recover_real_indices_and_match,
-    normalize_and,
-)
-from .utils import text_span_replace, count_spaces, HashableDict
-from .words2num import _words2num
-from .constants import (
-    FIRST_EXTRACTION_REGEXES,
-    LAST_EXTRACTION_REGEXES,
-    FLAGS,
-    _REPLACEMENT,
-)
-cimport cython
-from .logic cimport Logic
-from .classes cimport NumberInfo
-
-
-cdef _get_idxs_from_bool(bool_container):
-    built_idxs = []
-    cdef int i
-    cdef bint truth
-    for i, truth in enumerate(bool_container):
-        if not truth:
-            built_idxs.append(i)
-    return built_idxs
-
-@cython.boundscheck(False)
-@cython.wraparound(False)
-cdef _get_numbers_from_idxs(numbers, idxs):
-    cdef int prev_idx, end
-    prev_idx = 0
-    nums = []
-    for end in idxs:
-        nums.append(numbers[prev_idx: end + 1])
-        prev_idx = end + 1
-    return nums
-
-cdef _check_and_point(numbers, data):
-    logic = Logic(numbers, data)
-    bool_container = logic.apply_sequence_logic() 
-    return bool_container
-
-cdef first_extraction(str text, data):
-    """ extract direct numbers like:
-            -6.7 4'444 1e-35 23.8k' """
-    rreturn = []
-    regexes = getattr(data, FIRST_EXTRACTION_REGEXES, [])
-    text = _replace(text, regexes, rreturn, data=data)
-    return (text, # we pass the text to the next Pipeline
-    rreturn)
-
-@cython.wraparound(False)
-cdef _replace(str text, regexes, rreturn, data):
-    flags = data.DEFAULT_RE_FLAGS
-    flags_dict = getattr(data, FLAGS, {})
-    cdef int i, lc, rc, start, end
-    for i, regex in enumerate(regexes):
-        try:
-            matches = re.finditer(
-            regex,
-            text,
-            flags_dict.get(regex) or flags
-            )
-        except Exception as e:
-            print(regex, "->", i)
-            raise e
-        if matches:
-            for match in matches:
-                if match.group():
-                    lc,rc = count_spaces(match.group())
-                    start, end = (
-                        match.span()[0] + lc,
-                        match.span()[1] - rc
-                    )
-                    rreturn.append(
-                    (
-                        match.group().strip(),
-                        (start, end)
-                    ))
-                    # we replace the found number with `$` to avoid the next Pipeline extracting the same number again
-                    text = text_span_replace(text, _REPLACEMENT * (end - start), (start, end))
-    return text
-
-def info_gen(
-    str num_string,
-    num_val,
-    spans,
-    data,
-):
-    d = HashableDict()
-    cleaned = Pipe(data=data)(num_string)
-    tokens = tokenize(cleaned, data=data)
-    info_generator = NumberInfo(data)
-    info = info_generator.generate(
-        num_string,
-        num_val,
-        tokens,
-    )
-    d.update(info)
-    d["span"] = spans
-    d["value"] = num_val
-    return d
-    
-cpdef parse(str text, data):
-    
-    # extract numbers 1
-    remaining_words, matches = first_extraction(text, data)
-    
-    cleaned = Pipe(data=data)(remaining_words)
-    tokens = tokenize(cleaned, data=data)
-    bools = _check_and_point(tokens, data)
-    end_idxs = _get_idxs_from_bool(bools)
-    nums = _get_numbers_from_idxs(tokens, end_idxs)
-    norm_nums = nums
-    norm_nums = normalize_and(norm_nums, data=data)
-    # get real indices
-    real, text_repl = recover_real_indices_and_match(remaining_words, norm_nums, data)
-    real.extend(matches)
-    # extract remaining numbers 3
-    last_extraction_regexes = getattr(data, LAST_EXTRACTION_REGEXES, [])
-    
-    text_repl = _replace(text_repl, last_extraction_regexes, real, data)
-    rt_final = []
-    spans = set()
-    for n in real:
-        num_string = n[0]
-        span = n[1]
-        info = gen_info(num_string, span, data=data)
-        if info[2].get("value") is None or span[1] in spans:
-            continue
-        spans.add(span[1])
-        rt_final.append(info)
-    
-    #sort by span
-    rt_final.sort(key=__key1)
-    rt_final = list(map(__map, rt_final))
-    return rt_final
-    
-def __key1(n):
-    return n[2]["span"]
-
-def __map(d):
-    return {"text": d[0], "value": d[1], **d[2]}
-
-cdef gen_info(str num_string, span, data):
-    value = _words2num(num_string, data)
-    return num_string, value, info_gen(num_string, value, span, data)
\ No newline at end of file
+use fancy_regex::Regex;
+
+use crate::entity::EntityParser;
+use crate::tokens::Token;
+use crate::parsers::number::{
+    tokenize, normalize_and, recover_real_indices_and_match, words2num,
+    Config, Data, DataHolder, Logic, Merger, NumberForm, NumberValue, Pipe,
+};
+use crate::parsers::number::classes::NumberInfo;
+use crate::parsers::number::literals::in_date_or_time;
+use crate::parsers::number::locale::canonical_numerals;
+use crate::parsers::number::scales::ambiguous_scale;
+use crate::parsers::number::scripts::{digit_script, AsciiDigits};
+use crate::parsers::number::systems::number_system;
+use crate::parsers::number::constants::{NUMBER, POWER, _REPLACEMENT};
+use crate::parsers::number::normalize::SpannedText;
+use crate::parsers::number::ordinals::is_time_second;
+use crate::parsers::number::powers::{e_notation, Power};
+use crate::parsers::number::qualifiers::{find_qualifier, Qualifier};
+use crate::parsers::number::roman::find_romans;
+
+/// A number found in free text.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NumberMatch {
+    pub text: String,
+    pub span: (usize, usize),
+    pub value: NumberValue,
+    pub number_type: &'static str,
+    /// `about`, `more than`, `≤` ... around the number, see [`find_qualifier`].
+    pub qualifier: Option<Qualifier>,
+    /// The mantissa and exponent of `5 × 10^3` or `1.2e-3`, which are both of type `power`,
+    /// see [`Power`].
+    pub power: Option<Power>,
+}
+
+/// Extracts every number in a text, written with digits or spoken.
+///
+/// Extraction runs in three passes: direct numbers (`-6.7`, `4'444`, `23.8k`, `0xff` ...)
+/// are taken out first, then runs of number words, then whatever plain numerals remain.
+/// Adjacent numbers are then merged as configured, see [`Merger`], and each number gets the
+/// qualifier around it, as in `about 30` or `more than 5`.
+pub struct NumberParser {
+    data: Data,
+}
+
+impl NumberParser {
+
+    pub fn new(config: Config) -> Self {
+        NumberParser {
+            data: Data::new(config),
+        }
+    }
+
+    /// A parser reading numbers with the vocabularies of `data`, see [`Data::with_language`].
+    pub fn with_data(data: Data) -> Self {
+        NumberParser { data }
+    }
+
+    pub fn data(&self) -> &Data {
+        &self.data
+    }
+
+    /// The numbers of `text`. Digits of any script are read (`٣٤٥`, `१२३`, `１２３`) and
+    /// reported by [`NumberValue::script`], spans are in `text`.
+    pub fn extract(&mut self, text: &str) -> Vec<NumberMatch> {
+        let ascii = AsciiDigits::new(text);
+        let mut numbers = self.extract_ascii(&ascii.text);
+        for number in &mut numbers {
+            ascii.restore(number, text);
+            let form = NumberForm { script: digit_script(&number.text), ..number.value.form() };
+            number.value = number.value.clone().with_form(form);
+        }
+        numbers
+    }
+
+    fn extract_ascii(&mut self, text: &str) -> Vec<NumberMatch> {
+        // extract numbers 1
+        let (remaining_words, mut matches) = first_extraction(text, &self.data);
+        // `Chapter IX`, before `I` and `X` are read as words
+        let remaining_words = if self.data.config().parse_roman.unwrap_or(false) {
+            roman_extraction(remaining_words, &self.data, &mut matches)
+        } else {
+            remaining_words
+        };
+
+        let cleaned = Pipe::normalize(remaining_words.clone(), &self.data);
+        let tokens = tokenize(&cleaned);
+        let bools = check_and_point(&tokens, &self.data);
+        let end_idxs = get_idxs_from_bool(&bools);
+        let nums = get_numbers_from_idxs(&tokens, &end_idxs);
+        let norm_nums = normalize_and(nums, &self.data);
+        // get real indices
+        let (mut real, text_repl) = recover_real_indices_and_match(remaining_words, norm_nums, Some(&self.data));
+        real.extend(matches);
+        // extract remaining numbers 3
+        replace(text_repl, &self.data.regexes().last_extraction, &mut real);
+
+        let mut info = NumberInfo::new(self.data.clone());
+        let exact = self.data.config().exact.unwrap_or(false);
+        let code_literals = self.data.config().code_literals.unwrap_or(false);
+        let mut ends: Vec<usize> = Vec::new();
+        let mut numbers: Vec<NumberMatch> = Vec::new();
+        for (num_string, span) in real {
+            if ends.contains(&span.1) {
+                continue;
+            }
+            let (num_string, span) = with_article(text, num_string, span, &numbers, &self.data);
+            // `1.234,56` -> `1234.56`, read the way of `Config::number_locale`
+            let mut numeral = canonical_numerals(&num_string, &self.data);
+            // the `05` of `2024-01-05` isn't a C octal
+            if code_literals && numeral.starts_with('0') && numeral.bytes().all(|b| b.is_ascii_digit()) && in_date_or_time(text, span) {
+                numeral = match numeral.trim_start_matches('0') {
+                    "" => "0".to_string(),
+                    digits => digits.to_string(),
+                };
+            }
+            let holder = DataHolder {
+                type_: "text",
+                text: Some(numeral.clone()),
+                int: None,
+                float: None,
+            };
+            let Some(value) = words2num(holder, &self.data) else {
+                continue;
+            };
+            // `one second please` is a moment, not the 2nd
+            if value.is_ordinal() && is_time_second(text, span, &self.data) {
+                continue;
+            }
+            // `5 lakh`, `3万`, `5 billion`
+            let form = NumberForm {
+                system: number_system(&num_string, &self.data),
+                ambiguous_scale: ambiguous_scale(&num_string, &self.data),
+                ..value.form()
+            };
+            let value = value.with_form(form);
+            ends.push(span.1);
+            // `1.2e-3` is a power like `1.2 × 10^-3`
+            let power = e_notation(&numeral, exact);
+            numbers.push(NumberMatch {
+                number_type: if power.is_some() { POWER } else { info.number_type(&numeral) },
+                power,
+                text: num_string,
+                span,
+                value,
+                qualifier: None,
+            });
+        }
+        // sort by span
+        numbers.sort_by_key(|n| n.span);
+        let mut numbers = Merger::new(self.data.clone()).merge(numbers, text);
+        // `more than 5` is a bound, not just 5
+        for number in &mut numbers {
+            number.qualifier = find_qualifier(text, number.span);
+        }
+        numbers
+    }
+}
+
+impl Default for NumberParser {
+    fn default() -> Self {
+        NumberParser::new(Config::default())
+    }
+}
+
+impl EntityParser for NumberParser {
+    fn parse(&mut self, text: &str) -> Vec<Token> {
+        self.extract(text)
+            .into_iter()
+            .map(|n| Token::new(n.text, Some(NUMBER.to_string()), Some(n.span)))
+            .collect()
+    }
+}
+
+/// The texts and spans an extraction of [`NumberParser`] finds in a text.
+pub type Extraction = fn(&mut NumberParser, &str) -> Vec<(String, (usize, usize))>;
+
+/// Finds what an [`Extraction`] finds as entities inside an `ExtractionPipeline`, such as
+/// the ranges of [`NumberEntityParser::ranges`].
+pub struct NumberEntityParser {
+    parser: NumberParser,
+    entity: &'static str,
+    extract: Extraction,
+}
+
+impl NumberEntityParser {
+    /// Finds the texts and spans of `extract` as `entity` entities.
+    pub fn new(config: Config, entity: &'static str, extract: Extraction) -> Self {
+        NumberEntityParser { parser: NumberParser::new(config), entity, extract }
+    }
+}
+
+impl EntityParser for NumberEntityParser {
+    fn parse(&mut self, text: &str) -> Vec<Token> {
+        (self.extract)(&mut self.parser, text)
+            .into_iter()
+            .map(|(text, span)| Token::new(text, Some(self.entity.to_string()), Some(span)))
+            .collect()
+    }
+}
+
+/// The number with the article it is counted from: `a hundred`, `a million`.
+/// The article is a word of its own, not the end of `5a` or part of a number found before.
+fn with_article(text: &str, num_string: String, span: (usize, usize), numbers: &[NumberMatch], data: &Data) -> SpannedText {
+    let first = num_string.split_whitespace().next().unwrap_or_default().to_lowercase();
+    if !data.multiples().contains_key(&first) {
+        return (num_string, span);
+    }
+    let before = text[..span.0].trim_end();
+    let word = &before[before.trim_end_matches(char::is_alphabetic).len()..];
+    if before.len() == span.0 || !data.a().iter().any(|a| a.eq_ignore_ascii_case(word)) {
+        return (num_string, span);
+    }
+    let start = before.len() - word.len();
+    let joined = before[..start].chars().next_back().is_some_and(char::is_alphanumeric);
+    if joined || numbers.iter().any(|n| n.span.0 <= start && start < n.span.1) {
+        return (num_string, span);
+    }
+    (text[start..span.1].to_string(), (start, span.1))
+}
+
+fn get_idxs_from_bool(bool_container: &[bool]) -> Vec<usize> {
+    bool_container
+        .iter()
+        .enumerate()
+        .filter(|(_, truth)| !**truth)
+        .map(|(i, _)| i)
+        .collect()
+}
+
+fn get_numbers_from_idxs(numbers: &[String], idxs: &[usize]) -> Vec<Vec<String>> {
+    let mut prev_idx = 0;
+    let mut nums = Vec::new();
+    for &end in idxs {
+        nums.push(numbers[prev_idx..=end].to_vec());
+        prev_idx = end + 1;
+    }
+    nums
+}
+
+fn check_and_point(numbers: &[String], data: &Data) -> Vec<bool> {
+    let mut logic = Logic::new(numbers.to_vec(), data.clone());
+    logic.apply_sequence_logic()
+}
+
+/// Extracts direct numbers like: `-6.7 4'444 1e-35 23.8k`
+fn first_extraction(text: &str, data: &Data) -> (String, Vec<SpannedText>) {
+    let mut rreturn = Vec::new();
+    // we pass the text to the next Pipeline
+    let text = replace(text.to_string(), &data.regexes().first_extraction, &mut rreturn);
+    (text, rreturn)
+}
+
+/// Extracts Roman numerals like: `XIV`, `Louis XVI`
+fn roman_extraction(mut text: String, data: &Data, rreturn: &mut Vec<SpannedText>) -> String {
+    for (numeral, (start, end)) in find_romans(&text, data) {
+        text.replace_range(start..end, &_REPLACEMENT.repeat(end - start));
+        rreturn.push((numeral, (start, end)));
+    }
+    text
+}
+
+fn replace(mut text: String, regexes: &[Regex], rreturn: &mut Vec<SpannedText>) -> String {
+    for re in regexes {
+        let spans: Vec<(usize, usize)> = re
+            .find_iter(&text)
+            .filter_map(|mat| mat.ok())
+            .map(|mat| (mat.start(), mat.end()))
+            .collect();
+        for (start, end) in spans {
+            let matched = &text[start..end];
+            let lc = matched.len() - matched.trim_start().len();
+            let rc = matched.len() - matched.trim_end().len();
+            let (start, end) = (start + lc, end - rc);
+            if start >= end {
+                continue;
+            }
+            rreturn.push((text[start..end].to_string(), (start, end)));
+            // we replace the found number with `?` to avoid the next Pipeline extracting the same number again
+            text.replace_range(start..end, &_REPLACEMENT.repeat(end - start));
+        }
+    }
+    text
+}
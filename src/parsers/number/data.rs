@@ -1,207 +1,461 @@
 use std::collections::HashMap;
+use std::sync::{Arc, OnceLock};
+
+use fancy_regex::Regex;
 
 use crate::tokenizers::{DEFAULT_RE_FLAGS, EXTENDED, IGNORECASE};
 use crate::utils::regex_::{RegexProcessor};
 use crate::parsers::number::config::Config;
+use crate::parsers::number::languages::{language_pack, split_compound, LanguagePack};
+use crate::parsers::number::literals::{float_suffix_pattern, integer_suffix_pattern};
+use crate::parsers::number::locale::{Grouping, NumberLocale};
+use crate::parsers::number::scales::{is_scaled, rescale, NumberScale};
 
 
 const B_LEFT: &str = r"(?<![a-zA-Z_])";
 const B_RIGHT: &str = r"(?![a-zA-Z_])";
 
+/// Declares the tables of [`Data`]: each is built once by the `Builder` method of
+/// the same name and read through a `Data` method returning a reference.
+macro_rules! tables {
+    ($($(#[$meta:meta])* $name:ident: $type:ty,)*) => {
+        struct Tables {
+            $($name: $type,)*
+        }
+
+        impl Tables {
+            fn build(builder: &Builder) -> Self {
+                Self { $($name: builder.$name(),)* }
+            }
+        }
+
+        impl Data {
+            $(
+                $(#[$meta])*
+                pub fn $name(&self) -> &$type {
+                    &self.tables.$name
+                }
+            )*
+        }
+    };
+}
+
+tables! {
+    a: Vec<String>,
+    ands: Vec<String>,
+    points: Vec<String>,
+    negatives: Vec<String>,
+    imaginary_units: Vec<String>,
+    complex_signs: HashMap<String, f64>,
+    /// The zero of years like `nineteen oh five`, the plain zero in languages without one.
+    year_zero: String,
+    zeros: Vec<String>,
+    ones: HashMap<String, f64>,
+    ordinal_ones: HashMap<String, f64>,
+    /// `hundred` and the words that multiply like it, see [`LanguagePack::hundreds`].
+    hundreds: HashMap<String, f64>,
+    /// `lakh` and `crore`, in every language, see [`NumberSystem::Indian`](crate::parsers::number::NumberSystem).
+    indian_multiples: HashMap<String, f64>,
+    multiples: HashMap<String, f64>,
+    /// The words and suffixes worth a different number in each [`NumberScale`]: `billion`, `bn`.
+    scaled_words: HashMap<String, f64>,
+    teens_and_ten: HashMap<String, f64>,
+    ordinal_teens_and_ten: HashMap<String, f64>,
+    ordinal_tens: HashMap<String, f64>,
+    tens: HashMap<String, f64>,
+    ordinal_multiples: HashMap<String, f64>,
+    /// The words a number written as one is made of, empty when the language doesn't
+    /// write them so, see [`LanguagePack::splits_compounds`].
+    compound_vocabulary: Vec<String>,
+    suffixes: HashMap<String, f64>,
+    suffixes_by_name: HashMap<String, f64>,
+    informal_exact: HashMap<String, f64>,
+    informals_multiplyable: HashMap<String, f64>,
+    superscript_ones: HashMap<char, f64>,
+    superscript_ones_regex: String,
+    subscript_ones: HashMap<char, f64>,
+    subscript_ones_regex: String,
+    superscript_fractions: HashMap<char, f64>,
+    /// `万`, `億` ..., see [`NumberSystem::Myriad`](crate::parsers::number::NumberSystem).
+    myriads: HashMap<char, f64>,
+    /// Digits grouped by myriads: `3万`, `1億2000万`.
+    myriad_regex: String,
+    /// `一` to `九` with `〇`, `零`, `两` and the financial forms `壹`, `貳` ..., see
+    /// [`words2cjk`](crate::parsers::number::words2cjk).
+    cjk_digits: HashMap<char, f64>,
+    /// `十`, `百`, `千` and their financial forms `拾`, `佰`, `仟`.
+    cjk_units: HashMap<char, f64>,
+    /// Chinese and Japanese numerals, also mixed with digits: `三百五十`, `3万五千`.
+    cjk_numeral_regex: String,
+    fraction_denominators: HashMap<String, f64>,
+    fraction_regex: String,
+    superscript_fractions_regex: String,
+    ordinal_suffixes: Vec<String>,
+    ordinals: HashMap<String, f64>,
+    informal_all: HashMap<String, f64>,
+    all_nums: HashMap<String, f64>,
+    all_valid: HashMap<String, f64>,
+    _tens: String,
+    _ones: String,
+    _ordinal_ones: String,
+    _teens: String,
+    _multiples: String,
+    _suffixes: String,
+    _negs: String,
+    _points: String,
+    hyphen: String,
+    integer_regex: String,
+    float_regex: String,
+    any_number_regex: String,
+    /// A numeral written the way of `Config::number_locale`, where [`Data::any_number_regex`]
+    /// is written the English way the conversions read.
+    numeral_regex: String,
+    /// A numeral of `Config::number_locale` grouped with spaces, `1 234 567,5`, empty when
+    /// the locale doesn't group with spaces.
+    spaced_numeral_regex: String,
+    complex_number_regex: String,
+    spoken_complex_number_regex: String,
+    /// `0b1010`, `0B1010_1010`, `0b1010u8`.
+    binary_regex: String,
+    /// `0xFF`, `0XFF_FF`, `0xFFu8`.
+    hex_regex: String,
+    /// `0o755`, `0O7_55`, and `0755` with `Config::code_literals`.
+    oct_regex: String,
+    /// Decimal literals with a type suffix, `10u32`, `1.5f`, `2.0f64`, empty without
+    /// `Config::code_literals`.
+    typed_literal_regex: String,
+    _all_ones: Vec<String>,
+    number_followed_by_power_regex: String,
+    suffix_name_regex: String,
+    number_followed_by_suffix_regex: String,
+    informals_exact_regex: String,
+    informals_multiplyable_regex: String,
+    ordinal_numeral_regex: String,
+    first_extraction_regexes: Vec<String>,
+    last_extraction_regexes: Vec<String>,
+    /// A comma after a multiple of a thousand, which is dropped: `million,`.
+    rep_commas_regex: String,
+    /// The sign between the real and imaginary parts of a complex number.
+    complex_split_regex: String,
+}
+
+/// The regexes of the number parser, compiled from the patterns of a [`Data`] the first
+/// time they are used and shared by its clones.
+///
+/// The whole-match ones are anchored with `^(?:..)$`.
+pub struct CompiledRegexes {
+    /// [`Data::first_extraction_regexes`] with the default flags.
+    pub first_extraction: Vec<Regex>,
+    /// [`Data::last_extraction_regexes`] with the default flags.
+    pub last_extraction: Vec<Regex>,
+    /// The hyphens of `twenty-one` but not `re-enroll`.
+    pub hyphen: Regex,
+    /// The whole-match patterns of a lone token that is still a number.
+    pub check_valid: Vec<Regex>,
+    /// A comma after a multiple of a thousand: `million,`.
+    pub rep_commas: Regex,
+    /// [`Data::numeral_regex`] anywhere.
+    pub numeral: Regex,
+    /// An SI suffix after a digit.
+    pub digit_suffix: Regex,
+    /// `1st` as a whole.
+    pub ordinal_numeral: Regex,
+    /// `5k`, `3 kilo` as a whole.
+    pub number_followed_by_suffix: Regex,
+    /// `5k`, `3 kilo` anywhere.
+    pub suffix: Regex,
+    /// `1st` anywhere.
+    pub ordinal: Regex,
+    pub binary: Regex,
+    pub hex: Regex,
+    pub oct: Regex,
+    /// The sign between the real and imaginary parts of a complex number.
+    pub complex_split: Regex,
+}
+
+impl CompiledRegexes {
+    fn build(data: &Data) -> Self {
+        let compile = |pattern: &str| Regex::new(pattern).unwrap();
+        let whole = |pattern: &str| compile(&format!("^(?:{pattern})$"));
+        let flagged = |patterns: &[String]| {
+            patterns.iter().map(|p| compile(&format!("{DEFAULT_RE_FLAGS}{p}"))).collect()
+        };
+        Self {
+            first_extraction: flagged(data.first_extraction_regexes()),
+            last_extraction: flagged(data.last_extraction_regexes()),
+            hyphen: compile(data.hyphen()),
+            check_valid: [
+                data.ordinal_numeral_regex(),
+                data.number_followed_by_suffix_regex(),
+                data.suffix_name_regex(),
+                data.hex_regex(),
+                data.oct_regex(),
+                data.binary_regex(),
+                data.numeral_regex(),
+            ]
+            .iter()
+            .map(|p| compile(&format!("^{p}$")))
+            .collect(),
+            rep_commas: compile(data.rep_commas_regex()),
+            numeral: compile(data.numeral_regex()),
+            digit_suffix: compile(&format!(r"\d(^[eE',\d]|{})(?=>[\W\b])", data._suffixes())),
+            ordinal_numeral: whole(data.ordinal_numeral_regex()),
+            number_followed_by_suffix: whole(data.number_followed_by_suffix_regex()),
+            suffix: compile(data.number_followed_by_suffix_regex()),
+            ordinal: compile(data.ordinal_numeral_regex()),
+            binary: whole(data.binary_regex()),
+            hex: whole(data.hex_regex()),
+            oct: whole(data.oct_regex()),
+            complex_split: compile(data.complex_split_regex()),
+        }
+    }
+}
+
+/// The vocabularies and regexes of the number parser.
+///
+/// Number words come from the [`LanguagePack`] picked by `Config::language`,
+/// eg: `Data::new(Config { language: Some("fr".to_string()), ..Config::default() })`.
+///
+/// The tables are built from `config` and the language once, when the `Data` is made,
+/// and the regexes are compiled once, when first used. Both are shared by the clones of
+/// the `Data`, so it is cheap to clone and can be read from many threads. The config is
+/// read-only for the same reason, make a new `Data` to read numbers another way.
 #[derive(Clone)]
 pub struct Data {
-    pub config: Config,
+    config: Config,
     pub flags: HashMap<String, String>,
+    pub language: Arc<dyn LanguagePack>,
+    tables: Arc<Tables>,
+    regexes: Arc<OnceLock<CompiledRegexes>>,
 }
 
 impl Data {
     pub fn new(config: Config) -> Self {
-        let data = Self {
+        let language = language_pack(config.language.as_deref().unwrap_or("en"));
+        Self::build(config, language)
+    }
+
+    /// Reads numbers with `language` instead of the built-in pack of `Config::language`,
+    /// eg: a [`Vocabulary`](crate::parsers::number::Vocabulary) loaded from a file.
+    pub fn with_language<L: LanguagePack + 'static>(config: Config, language: L) -> Self {
+        Self::build(config, Arc::new(language))
+    }
+
+    fn build(config: Config, language: Arc<dyn LanguagePack>) -> Self {
+        let builder = Builder { config: &config, language: language.as_ref() };
+        let tables = Arc::new(Tables::build(&builder));
+        Self {
             config,
             flags: HashMap::new(),
-        };
-        data
+            language,
+            tables,
+            regexes: Arc::new(OnceLock::new()),
+        }
     }
 
-    pub fn default_re_flags(&mut self) -> String {
+    /// The config the tables and regexes were built from.
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    pub fn default_re_flags(&self) -> String {
         DEFAULT_RE_FLAGS.to_string()
     }
 
-    pub fn a(&mut self) -> Vec<String> {
-        vec!["a".to_string()]
+    /// The compiled regexes, see [`CompiledRegexes`].
+    pub fn regexes(&self) -> &CompiledRegexes {
+        self.regexes.get_or_init(|| CompiledRegexes::build(self))
     }
 
-    pub fn ands(&mut self) -> Vec<String> {
-        vec!["and".to_string()]
+    /// A word written as one split into number words, keeping its case: `Einundzwanzig` -> [Ein, und, zwanzig].
+    pub fn split_compound(&self, word: &str) -> Vec<String> {
+        let lower = word.to_lowercase();
+        let parts = split_compound(&lower, self.compound_vocabulary(), self.ands());
+        if parts.len() < 2 || lower.chars().count() != word.chars().count() {
+            return if parts.len() < 2 { vec![word.to_string()] } else { parts };
+        }
+        let mut chars = word.chars();
+        parts.iter().map(|p| chars.by_ref().take(p.chars().count()).collect()).collect()
     }
 
-    pub fn points(&mut self) -> Vec<String> {
-        vec!["point".to_string(), ".".to_string()]
+    /// Whether a numeral ending in `number` takes `suffix`: `1st`, `22nd`, `13th`, `101st`.
+    pub fn is_ordinal_suffix(&self, number: u64, suffix: &str) -> bool {
+        self.language.is_ordinal_suffix(number, suffix)
     }
 
-    pub fn negatives(&mut self) -> Vec<String> {
-        vec!["negative".to_string(), "neg".to_string(), "minus".to_string()]
+    pub fn get_suffix_value(&self, suffix: &str) -> Option<f64> {
+        self.suffixes_by_name().get(suffix).or_else(|| self.suffixes().get(suffix)).copied()
     }
+}
+
+/// A separator escaped for the patterns, which are extended and would drop a space.
+fn escape(separator: char) -> String {
+    format!(r"\x{{{:X}}}", separator as u32)
+}
 
-    pub fn zeros(&mut self) -> Vec<String> {
-        vec!["zero".to_string(), "0".to_string()]
+/// The digits of a numeral grouped with `separator`: `1,234,567`, `12,34,567`.
+fn group_pattern(separator: char, grouping: Grouping) -> String {
+    let sep = escape(separator);
+    match grouping {
+        Grouping::Thousands => format!(r"\d{{1,3}}(?:{sep}\d{{3}})+"),
+        Grouping::Indian => format!(r"\d{{1,2}}(?:{sep}\d{{2}})*{sep}\d{{3}}"),
     }
+}
 
-    pub fn ones(&mut self) -> HashMap<String, f64> {
-        let mut ones = HashMap::new();
-        ones.insert("zero".to_string(), 0.0);
-        ones.insert("one".to_string(), 1.0);
-        ones.insert("two".to_string(), 2.0);
-        ones.insert("three".to_string(), 3.0);
-        ones.insert("four".to_string(), 4.0);
-        ones.insert("five".to_string(), 5.0);
-        ones.insert("six".to_string(), 6.0);
-        ones.insert("seven".to_string(), 7.0);
-        ones.insert("eight".to_string(), 8.0);
-        ones.insert("nine".to_string(), 9.0);
+/// Computes the tables of a [`Data`] from its config and language.
+struct Builder<'a> {
+    config: &'a Config,
+    language: &'a dyn LanguagePack,
+}
+
+impl Builder<'_> {
+    fn a(&self) -> Vec<String> {
+        self.language.a()
+    }
+
+    fn ands(&self) -> Vec<String> {
+        self.language.ands()
+    }
+
+    fn points(&self) -> Vec<String> {
+        let mut points = self.language.points();
+        points.push(".".to_string());
+        points
+    }
+
+    fn negatives(&self) -> Vec<String> {
+        self.language.negatives()
+    }
+
+    fn imaginary_units(&self) -> Vec<String> {
+        vec!["i".to_string(), "j".to_string()]
+    }
+
+    fn complex_signs(&self) -> HashMap<String, f64> {
+        let mut complex_signs = self.language.complex_signs();
+        complex_signs.insert("+".to_string(), 1.0);
+        complex_signs.insert("-".to_string(), -1.0);
+        complex_signs
+    }
+
+    fn year_zero(&self) -> String {
+        match self.language.year_zero() {
+            Some(year_zero) => year_zero,
+            None => self.zeros()[0].clone(),
+        }
+    }
+
+    fn zeros(&self) -> Vec<String> {
+        let mut zeros: Vec<String> = self.language.ones().into_iter().filter(|(_, v)| *v == 0.0).map(|(k, _)| k).collect();
+        zeros.sort();
+        zeros.push("0".to_string());
+        zeros
+    }
+
+    fn ones(&self) -> HashMap<String, f64> {
+        let mut ones = self.language.ones();
         ones.extend(self.ordinal_ones());
         ones
     }
 
-    pub fn ordinal_ones(&mut self) -> HashMap<String, f64> {
-        let mut ordinal_ones = HashMap::new();
-        ordinal_ones.insert("first".to_string(), 1.0);
-        ordinal_ones.insert("second".to_string(), 2.0);
-        ordinal_ones.insert("third".to_string(), 3.0);
-        ordinal_ones.insert("fourth".to_string(), 4.0);
-        ordinal_ones.insert("fifth".to_string(), 5.0);
-        ordinal_ones.insert("sixth".to_string(), 6.0);
-        ordinal_ones.insert("seventh".to_string(), 7.0);
-        ordinal_ones.insert("eighth".to_string(), 8.0);
-        ordinal_ones.insert("ninth".to_string(), 9.0);
-        ordinal_ones
+    fn ordinal_ones(&self) -> HashMap<String, f64> {
+        self.language.ordinal_ones()
     }
-    
-    pub fn multiples(&mut self) -> HashMap<String, f64> {
-        let mut multiples = HashMap::new();
-        multiples.insert("hundred".to_string(), 100.0);
-        multiples.insert("thousand".to_string(), 1000.0);
-        multiples.insert("million".to_string(), 1000000.0);
-        multiples.insert("billion".to_string(), 1000000000.0);
-        multiples.insert("trillion".to_string(), 1000000000000.0);
-        multiples.insert("quadrillion".to_string(), 1000000000000000.0);
-        multiples.insert("quintillion".to_string(), 1e+18);
-        multiples.insert("sextillion".to_string(), 1e+21);
-        multiples.insert("septillion".to_string(), 1e+24);
-        multiples.insert("octillion".to_string(), 1e+27);
-        multiples.insert("nonillion".to_string(), 1e+30);
-        multiples.insert("decillion".to_string(), 1e+33);
-        multiples.insert("undecillion".to_string(), 1e+36);
-        multiples.insert("duodecillion".to_string(), 1e+39);
-        multiples.insert("tredecillion".to_string(), 1e+42);
-        multiples.insert("quattuordecillion".to_string(), 1e+45);
-        multiples.insert("quinquadecillion".to_string(), 1e+48);
-        multiples.insert("sedecillion".to_string(), 1e+51);
-        multiples.insert("septendecillion".to_string(), 1e+54);
-        multiples.insert("octodecillion".to_string(), 1e+57);
-        multiples.insert("novendecillion".to_string(), 1e+60);
-        multiples.insert("vigintillion".to_string(), 1e+63);
-        multiples.insert("unvigintillion".to_string(), 1e+66);
-        multiples.insert("uuovigintillion".to_string(), 1e+69);
-        multiples.insert("tresvigintillion".to_string(), 1e+72);
-        multiples.insert("quattuorvigintillion".to_string(), 1e+75);
-        multiples.insert("quinquavigintillion".to_string(), 1e+78);
-        multiples.insert("qesvigintillion".to_string(), 1e+81);
-        multiples.insert("septemvigintillion".to_string(), 1e+84);
-        multiples.insert("octovigintillion".to_string(), 1e+87);
-        multiples.insert("novemvigintillion".to_string(), 1e+90);
-        multiples.insert("trigintillion".to_string(), 1e+93);
-        multiples.insert("untrigintillion".to_string(), 1e+96);
-        multiples.insert("duotrigintillion".to_string(), 1e+99);
-        multiples.insert("trestrigintillion".to_string(), 1e+102);
-        multiples.insert("quattuortrigintillion".to_string(), 1e+105);
-        multiples.insert("quinquatrigintillion".to_string(), 1e+108);
-        multiples.insert("sestrigintillion".to_string(), 1e+111);
-        multiples.insert("septentrigintillion".to_string(), 1e+114);
-        multiples.insert("octotrigintillion".to_string(), 1e+117);
-        multiples.insert("noventrigintillion".to_string(), 1e+120);
-        multiples.insert("quadragintillion".to_string(), 1e+123);
+
+    fn hundreds(&self) -> HashMap<String, f64> {
+        self.language.hundreds()
+    }
+
+    fn indian_multiples(&self) -> HashMap<String, f64> {
+        let mut indian_multiples = HashMap::new();
+        indian_multiples.insert("lakh".to_string(), 1e5);
+        indian_multiples.insert("lakhs".to_string(), 1e5);
+        indian_multiples.insert("lac".to_string(), 1e5);
+        indian_multiples.insert("lacs".to_string(), 1e5);
+        indian_multiples.insert("crore".to_string(), 1e7);
+        indian_multiples.insert("crores".to_string(), 1e7);
+        indian_multiples.insert("cr".to_string(), 1e7);
+        indian_multiples
+    }
+
+    /// `Config::number_scale`, the scale of the language when it isn't set.
+    fn number_scale(&self) -> NumberScale {
+        self.config.number_scale.unwrap_or_else(|| self.language_scale())
+    }
+
+    fn language_scale(&self) -> NumberScale {
+        if self.language.long_scale() {
+            NumberScale::Long
+        } else {
+            NumberScale::Short
+        }
+    }
+
+    fn multiples(&self) -> HashMap<String, f64> {
+        let mut multiples = self.hundreds();
+        multiples.extend(rescale(self.language.multiples(), self.language_scale(), self.number_scale()));
+        multiples.extend(self.indian_multiples());
         multiples.extend(self.ordinal_multiples());
         multiples
     }
-    
-    pub fn teens_and_ten(&mut self) -> HashMap<String, f64> {
-        let mut teens_and_tens = HashMap::new();
-        teens_and_tens.insert("ten".to_string(), 10.0);
-        teens_and_tens.insert("eleven".to_string(), 11.0);
-        teens_and_tens.insert("twelve".to_string(), 12.0);
-        teens_and_tens.insert("thirteen".to_string(), 13.0);
-        teens_and_tens.insert("fourteen".to_string(), 14.0);
-        teens_and_tens.insert("fifteen".to_string(), 15.0);
-        teens_and_tens.insert("sixteen".to_string(), 16.0);
-        teens_and_tens.insert("seventeen".to_string(), 17.0);
-        teens_and_tens.insert("eighteen".to_string(), 18.0);
-        teens_and_tens.insert("nineteen".to_string(), 19.0);
+
+    fn teens_and_ten(&self) -> HashMap<String, f64> {
+        let mut teens_and_tens = self.language.teens_and_ten();
         teens_and_tens.extend(self.ordinal_teens_and_ten());
         teens_and_tens
     }
-    
-    pub fn ordinal_teens_and_ten(&mut self) -> HashMap<String, f64> {
-        let mut ordinal_teens_and_ten = HashMap::new();
-        ordinal_teens_and_ten.insert("tenth".to_string(), 10.0);
-        ordinal_teens_and_ten.insert("eleventh".to_string(), 11.0);
-        ordinal_teens_and_ten.insert("twelfth".to_string(), 12.0);
-        ordinal_teens_and_ten.insert("thirteenth".to_string(), 13.0);
-        ordinal_teens_and_ten.insert("fourteenth".to_string(), 14.0);
-        ordinal_teens_and_ten.insert("fifteenth".to_string(), 15.0);
-        ordinal_teens_and_ten.insert("sixteenth".to_string(), 16.0);
-        ordinal_teens_and_ten.insert("seventeenth".to_string(), 17.0);
-        ordinal_teens_and_ten.insert("eighteenth".to_string(), 18.0);
-        ordinal_teens_and_ten.insert("nineteenth".to_string(), 19.0);
-        ordinal_teens_and_ten
+
+    fn ordinal_teens_and_ten(&self) -> HashMap<String, f64> {
+        self.language.ordinal_teens_and_ten()
     }
-    
-    pub fn ordinal_tens(&mut self) -> HashMap<String, f64> {
-        let mut ordinal_tens = HashMap::new();
-        ordinal_tens.insert("twentieth".to_string(), 20.0);
-        ordinal_tens.insert("thirtieth".to_string(), 30.0);
-        ordinal_tens.insert("fortieth".to_string(), 40.0);
-        ordinal_tens.insert("fiftieth".to_string(), 50.0);
-        ordinal_tens.insert("sixtieth".to_string(), 60.0);
-        ordinal_tens.insert("seventieth".to_string(), 70.0);
-        ordinal_tens.insert("eightieth".to_string(), 80.0);
-        ordinal_tens.insert("ninetieth".to_string(), 90.0);
-        ordinal_tens
+
+    fn ordinal_tens(&self) -> HashMap<String, f64> {
+        self.language.ordinal_tens()
     }
-    
-    pub fn tens(&mut self) -> HashMap<String, f64> {
-        let mut tens = HashMap::new();
-        tens.insert("twenty".to_string(), 20.0);
-        tens.insert("thirty".to_string(), 30.0);
-        tens.insert("forty".to_string(), 40.0);
-        tens.insert("fifty".to_string(), 50.0);
-        tens.insert("sixty".to_string(), 60.0);
-        tens.insert("seventy".to_string(), 70.0);
-        tens.insert("eighty".to_string(), 80.0);
-        tens.insert("ninety".to_string(), 90.0);
+
+    fn tens(&self) -> HashMap<String, f64> {
+        let mut tens = self.language.tens();
         tens.extend(self.ordinal_tens());
         tens
     }
-    
-    pub fn ordinal_multiples(&mut self) -> HashMap<String, f64> {
-        let mut ordinal_multiples = HashMap::new();
-        ordinal_multiples.insert("hundredth".to_string(), 100.0);
-        ordinal_multiples.insert("thousandth".to_string(), 1000.0);
-        ordinal_multiples.insert("millionth".to_string(), 1_000_000.0);
-        ordinal_multiples.insert("billionth".to_string(), 1_000_000_000.0);
-        ordinal_multiples.insert("trillionth".to_string(), 1_000_000_000_000.0);
-        ordinal_multiples.insert("quadrillionth".to_string(), 1e24);
-        ordinal_multiples.insert("quintillionth".to_string(), 1e30);
-        ordinal_multiples.insert("sextillionth".to_string(), 1e36);
-        ordinal_multiples.insert("septillionth".to_string(), 1e42);
-        ordinal_multiples.insert("octillionth".to_string(), 1e48);
-        ordinal_multiples.insert("nonillionth".to_string(), 1e54);
-        ordinal_multiples.insert("decillionth".to_string(), 1e60);
+
+    fn scaled_words(&self) -> HashMap<String, f64> {
+        let mut scaled: HashMap<String, f64> = self.multiples().into_iter().filter(|(word, value)| is_scaled(word, *value)).collect();
+        scaled.extend(self.suffixes().into_iter().filter(|(suffix, _)| suffix == "B" || suffix == "bn"));
+        scaled
+    }
+
+    fn ordinal_multiples(&self) -> HashMap<String, f64> {
+        let language = self.language.ordinal_multiples();
+        let mut ordinal_multiples = rescale(language, self.language_scale(), self.number_scale());
         ordinal_multiples.extend(self.suffixes_by_name());
         ordinal_multiples
     }
-    
-    pub fn suffixes(&mut self) -> HashMap<String, f64> {
+
+    fn compound_vocabulary(&self) -> Vec<String> {
+        if !self.language.splits_compounds() {
+            return Vec::new();
+        }
+        let language = self.language;
+        let mut vocabulary: Vec<String> = Vec::new();
+        for part in [
+            language.ones(),
+            language.teens_and_ten(),
+            language.tens(),
+            language.hundreds(),
+            language.multiples(),
+            language.ordinal_ones(),
+            language.ordinal_teens_and_ten(),
+            language.ordinal_tens(),
+            language.ordinal_multiples(),
+        ] {
+            vocabulary.extend(part.into_keys());
+        }
+        vocabulary.extend(language.ands());
+        vocabulary
+    }
+
+    fn suffixes(&self) -> HashMap<String, f64> {
         let mut suffixes = HashMap::new();
         suffixes.insert("y".to_string(), 1e-24); //  Yocto
         suffixes.insert("z".to_string(), 1e-21); //  Zepto
@@ -219,15 +473,21 @@ impl Data {
         suffixes.insert("k".to_string(), 1000.0); //  Kilo
         suffixes.insert("M".to_string(), 1_000_000.0); //  Mega
         suffixes.insert("G".to_string(), 1_000_000_000.0); //  Giga
-        suffixes.insert("B".to_string(), 1_000_000_000.0); //  Billion
+        let billion = match self.number_scale() {
+            NumberScale::Short => 1e9,
+            NumberScale::Long => 1e12,
+        };
+        suffixes.insert("B".to_string(), billion); //  Billion
+        suffixes.insert("bn".to_string(), billion); //  Billion
+        suffixes.insert("cr".to_string(), 10_000_000.0); //  Crore
         suffixes.insert("T".to_string(), 1_000_000_000_000.0); //  Tera
         suffixes.insert("P".to_string(), 1e15); //  Peta
         //  suffixes.insert("E".to_string(), 1e18); //  Exa 
         suffixes.insert("Z".to_string(), 1e21); //  Zera
         suffixes.insert("Y".to_string(), 1e24); //  Yotta
-        let exclude_suffixes = self.config.exclude_suffixes.clone().unwrap_or_else(Vec::new);
+        let exclude_suffixes = self.config.exclude_suffixes.clone().unwrap_or_default();
         if !exclude_suffixes.is_empty() {
-            if (exclude_suffixes.len() == 1) && (exclude_suffixes[0] == "all".to_string()) {
+            if (exclude_suffixes.len() == 1) && (exclude_suffixes[0] == "all") {
                 return HashMap::new();
             }
             for suffix in &exclude_suffixes {
@@ -237,7 +497,7 @@ impl Data {
         suffixes
     }
     
-    pub fn suffixes_by_name(&mut self) -> HashMap<String, f64> {
+    fn suffixes_by_name(&self) -> HashMap<String, f64> {
         let mut suffixes_by_name = HashMap::new();
         suffixes_by_name.insert("yocto".to_string(),1e-24); //  y
         suffixes_by_name.insert("zepto".to_string(), 1e-21); //  z
@@ -262,29 +522,15 @@ impl Data {
         suffixes_by_name
     }
     
-    pub fn informal_exact(&mut self) -> HashMap<String, f64> {
-        let mut informal_exact = HashMap::new();
-        //  informal_exact.insert("single".to_string(), 1.0);
-        //  informal_exact.insert("couple".to_string(), 2.0);
-        informal_exact.insert("half".to_string(), 0.5);
-        informal_exact.insert("quarter".to_string(), 0.25);
-        //  informal_exact.insert("pair".to_string(), 2.0);
-        //  informal_exact.insert("few".to_string(), 3.0);
-        informal_exact.insert("dozen".to_string(), 12.0);
-        informal_exact
+    fn informal_exact(&self) -> HashMap<String, f64> {
+        self.language.informal_exact()
     }
-    
-    pub fn informals_multiplyable(&mut self) -> HashMap<String, f64> {
-        let mut infomals_multiplyable = HashMap::new();
-        infomals_multiplyable.insert("couples".to_string(), 2.0);
-        infomals_multiplyable.insert("pairs".to_string(), 2.0);
-        infomals_multiplyable.insert("dozens".to_string(), 12.0);
-        infomals_multiplyable.insert("quarters".to_string(), 0.25);
-        infomals_multiplyable.insert("halves".to_string(), 0.5);
-        infomals_multiplyable
+
+    fn informals_multiplyable(&self) -> HashMap<String, f64> {
+        self.language.informals_multiplyable()
     }
-    
-    pub fn superscript_ones(&mut self) -> HashMap<char, f64> {
+
+    fn superscript_ones(&self) -> HashMap<char, f64> {
         let mut superscript_ones = HashMap::new();
         superscript_ones.insert('⁰', 0.0);
         superscript_ones.insert('¹', 1.0);
@@ -299,11 +545,11 @@ impl Data {
         superscript_ones
     }
     
-    pub fn superscript_ones_regex(&mut self) -> String {
-        format!("(?:[{}])+", self.superscript_ones().iter().map(|(k, _)| k.to_string()).collect::<Vec<_>>().join(""))
+    fn superscript_ones_regex(&self) -> String {
+        format!("(?:[{}])+", self.superscript_ones().keys().map(|k| k.to_string()).collect::<Vec<_>>().join(""))
     }
     
-    pub fn subscript_ones(&mut self) -> HashMap<char, f64> {
+    fn subscript_ones(&self) -> HashMap<char, f64> {
         let mut subscript_ones = HashMap::new();
         subscript_ones.insert('₀', 0.0);
         subscript_ones.insert('₁', 1.0);
@@ -318,11 +564,11 @@ impl Data {
         subscript_ones
     }
     
-    pub fn subscript_ones_regex(&mut self) -> String {
-        format!("(?:[{}])+", self.subscript_ones().iter().map(|(k, _)| k.to_string()).collect::<Vec<_>>().join(""))
+    fn subscript_ones_regex(&self) -> String {
+        format!("(?:[{}])+", self.subscript_ones().keys().map(|k| k.to_string()).collect::<Vec<_>>().join(""))
     }
     
-    pub fn superscript_fractions(&mut self) -> HashMap<char, f64> {
+    fn superscript_fractions(&self) -> HashMap<char, f64> {
         let mut superscript_fractions = HashMap::new();
         superscript_fractions.insert('½', 0.5);
         superscript_fractions.insert('⅓', 1.0 / 3.0);
@@ -345,15 +591,90 @@ impl Data {
         superscript_fractions
     }
     
-    pub fn superscript_fractions_regex(&mut self) -> String {
-        format!("(?:[{}])", self.superscript_fractions().iter().map(|(k, _)| k.to_string()).collect::<Vec<_>>().join(""))
+    fn myriads(&self) -> HashMap<char, f64> {
+        let mut myriads = HashMap::new();
+        for (chars, value) in [("万萬만", 1e4), ("億亿억", 1e8), ("兆조", 1e12), ("京경", 1e16)] {
+            for c in chars.chars() {
+                myriads.insert(c, value);
+            }
+        }
+        myriads
+    }
+
+    fn myriad_regex(&self) -> String {
+        let myriads: String = self.myriads().keys().collect();
+        format!(r"(?<![\d.,])(?:\d+(?:,\d{{3}})*(?:\.\d+)?[{myriads}])+\d*")
+    }
+
+    fn cjk_digits(&self) -> HashMap<char, f64> {
+        let mut cjk_digits = HashMap::new();
+        let digits = [
+            "〇零", "一壹壱弌", "二貳贰弐弍两兩", "三參叁参弎", "四肆", "五伍", "六陸陆", "七柒", "八捌", "九玖",
+        ];
+        for (value, chars) in digits.iter().enumerate() {
+            for c in chars.chars() {
+                cjk_digits.insert(c, value as f64);
+            }
+        }
+        cjk_digits
+    }
+
+    fn cjk_units(&self) -> HashMap<char, f64> {
+        let mut cjk_units = HashMap::new();
+        for (chars, value) in [("十拾", 10.0), ("百佰", 100.0), ("千仟", 1000.0)] {
+            for c in chars.chars() {
+                cjk_units.insert(c, value);
+            }
+        }
+        cjk_units
+    }
+
+    fn cjk_numeral_regex(&self) -> String {
+        let numerals: String = self.cjk_digits().keys().chain(self.cjk_units().keys()).collect();
+        let myriads: String = self.myriads().keys().collect();
+        let numeral = r"\d+(?:,\d{3})*(?:\.\d+)?";
+        // at least one CJK digit or unit, `3万` is left to `myriad_regex`
+        format!(r"(?<![\d.,])(?=[\d.,{myriads}]*[{numerals}])(?:[{numerals}{myriads}]|{numeral}|(?<=[{numerals}])[点點](?=[{numerals}]))+")
+    }
+
+    fn fraction_denominators(&self) -> HashMap<String, f64> {
+        // `third` and `thirds` -> 3, `first` and `second` aren't denominators
+        let mut fraction_denominators = HashMap::new();
+        let mut ordinals = self.ordinal_ones();
+        ordinals.extend(self.ordinal_teens_and_ten());
+        ordinals.extend(self.ordinal_tens());
+        for (k, v) in ordinals.into_iter().filter(|(_, v)| *v > 2.0) {
+            fraction_denominators.insert(format!("{k}s"), v);
+            fraction_denominators.insert(k, v);
+        }
+        // `half`, `halves`, `quarter`, `quarters`
+        let mut informals = self.informal_exact();
+        informals.extend(self.informals_multiplyable());
+        for (k, v) in informals.into_iter().filter(|(_, v)| *v < 1.0) {
+            fraction_denominators.insert(k, (1.0 / v).round());
+        }
+        fraction_denominators
+    }
+
+    fn fraction_regex(&self) -> String {
+        // `3/4`, `1 1/2` and `1½` but not the dates `12/31/2024`, the whole part of a mixed
+        // number is short and on the same line: `in 2023 1/3 of` is a year and a fraction
+        let sign = if self.config.signs_allowed.unwrap() { r"[\-\+]?" } else { "" };
+        format!(
+            r"(?:(?<![\d/.,])(?:{sign}\d{{1,3}}[^\S\r\n]+)?{sign}\d+/\d+(?![\d/])|(?<![\d.,])(?:{sign}\d+[^\S\r\n]?)?{})",
+            self.superscript_fractions_regex(),
+        )
+    }
+
+    fn superscript_fractions_regex(&self) -> String {
+        format!("(?:[{}])", self.superscript_fractions().keys().map(|k| k.to_string()).collect::<Vec<_>>().join(""))
     }
     
-    pub fn ordinal_suffixes(&mut self) -> Vec<String> {
-        vec!["st".to_string(),"nd".to_string(), "rd".to_string(), "th".to_string()]
+    fn ordinal_suffixes(&self) -> Vec<String> {
+        self.language.ordinal_suffixes()
     }
     
-    pub fn ordinals(&mut self) -> HashMap<String, f64> {
+    fn ordinals(&self) -> HashMap<String, f64> {
         let mut ordinals = HashMap::new();
         ordinals.extend(self.ordinal_ones());
         ordinals.extend(self.ordinal_teens_and_ten());
@@ -362,18 +683,18 @@ impl Data {
         ordinals
     }
     
-    pub fn informal_all(&mut self) -> HashMap<String, f64> {
+    fn informal_all(&self) -> HashMap<String, f64> {
         let mut informal_all = HashMap::new();
         informal_all.extend(self.informal_exact());
         informal_all.extend(self.informals_multiplyable());
         let copy = informal_all.clone();
-        for (_, v) in &copy {
+        for v in copy.values() {
             informal_all.insert(v.to_string(), *v);
         }
         informal_all
     }
     
-    pub fn all_nums(&mut self) -> HashMap<String, f64> {
+    fn all_nums(&self) -> HashMap<String, f64> {
         let mut res = HashMap::new();
         res.extend(self.ones());
         res.extend(self.teens_and_ten());
@@ -383,7 +704,7 @@ impl Data {
         res
     }
         
-    pub fn all_valid(&mut self) -> HashMap<String, f64> {
+    fn all_valid(&self) -> HashMap<String, f64> {
         let mut res = HashMap::new();
         res.extend(self.ones());
         res.extend(self.teens_and_ten());
@@ -391,7 +712,7 @@ impl Data {
         res.extend(self.multiples());
         res.extend(self.informal_all());
         let copy = res.clone();
-        for (_, v) in &copy {
+        for v in copy.values() {
             res.insert(v.to_string(), *v);
         }
         let mut other: Vec<String> = Vec::new();
@@ -399,33 +720,32 @@ impl Data {
         other.extend(self.ands());
         other.extend(self.points());
         other.extend(self.negatives());
-        for (i, &ref item) in other.iter().enumerate() {
+        for (i, item) in other.iter().enumerate() {
             res.insert(item.clone(), i as f64);
         }
         res.extend(self.ordinals());
+        res.extend(self.fraction_denominators());
         res
     }
     
-    pub fn _tens(&mut self) -> String {
+    fn _tens(&self) -> String {
         let mut processor = RegexProcessor::new();
         processor
         .join(
-            self.tens()
-            .iter()
-            .map(|(k, _)| k.to_string())
+            self.tens().keys().map(|k| k.to_string())
             .collect::<Vec<String>>(),
             None
         )
     }
     
-    pub fn _ones(&mut self) -> String {
+    fn _ones(&self) -> String {
         let mut processor = RegexProcessor::new();
         processor
         .join(
             self.ones()
             .iter()
-            .filter(|(k, _)| {
-                **k != "zero".to_string()
+            .filter(|(_, v)| {
+                **v != 0.0
             })
             .map(|(k, _)| k.to_string())
             .collect::<Vec<String>>(),
@@ -433,26 +753,24 @@ impl Data {
         )
     }
     
-    pub fn _ordinal_ones(&mut self) -> String {
+    fn _ordinal_ones(&self) -> String {
         let mut processor = RegexProcessor::new();
         processor
         .join(
-            self.ordinal_ones()
-            .iter()
-            .map(|(k, _)| k.to_string())
+            self.ordinal_ones().keys().map(|k| k.to_string())
             .collect::<Vec<String>>(),
             None
         )
     }
     
-    pub fn _teens(&mut self) -> String {
+    fn _teens(&self) -> String {
         let mut processor = RegexProcessor::new();
         processor
         .join(
             self.teens_and_ten()
             .iter()
-            .filter(|(k, _)| {
-                **k != "ten".to_string()
+            .filter(|(_, v)| {
+                **v != 10.0
             })
             .map(|(k, _)| k.to_string())
             .collect::<Vec<String>>(),
@@ -460,31 +778,27 @@ impl Data {
         )
     }
     
-    pub fn _multiples(&mut self) -> String {
+    fn _multiples(&self) -> String {
         let mut processor = RegexProcessor::new();
         processor
         .join(
-            self.multiples()
-            .iter()
-            .map(|(k, _)| k.to_string())
+            self.multiples().keys().map(|k| k.to_string())
             .collect::<Vec<String>>(),
             None
         )
     }
     
-    pub fn _suffixes(&mut self) -> String {
+    fn _suffixes(&self) -> String {
         let mut processor = RegexProcessor::new();
         processor
         .join(
-            self.suffixes()
-            .iter()
-            .map(|(k, _)| k.to_string())
+            self.suffixes().keys().map(|k| k.to_string())
             .collect::<Vec<String>>(),
             None
         )
     }
     
-    pub fn _negs(&mut self) -> String {
+    fn _negs(&self) -> String {
         let mut processor = RegexProcessor::new();
         processor
         .join(
@@ -496,7 +810,7 @@ impl Data {
         )
     }
     
-    pub fn _points(&mut self) -> String {
+    fn _points(&self) -> String {
         let mut processor = RegexProcessor::new();
         processor
         .join(
@@ -508,11 +822,11 @@ impl Data {
         )
     }
     
-    pub fn hyphen(&mut self) -> String {
+    fn hyphen(&self) -> String {
         format!(r#"
             {EXTENDED}{IGNORECASE}\b(
             ({tens})-({ones}|{ordinal_ones}|{multiples})
-            |({ones})-({multiples}|hundred)
+            |({ones})-({multiples})
             |({teens})-({multiples})
             )\b
         "#,
@@ -523,23 +837,30 @@ impl Data {
         )
     }
     
-    pub fn integer_regex(&mut self) -> String {
-        let mut seps = vec![".to_string(),".to_string(), "_".to_string(), "'".to_string(), " ".to_string()].to_vec(); // space Seperators doesn't work!
-        let mut exclude_separators = self.config.exclude_separators.clone().unwrap_or_else(Vec::new);
-        exclude_separators.dedup();
-        if !exclude_separators.is_empty() {
-            for separator in exclude_separators.iter() {
-                let index = seps
-                    .iter()
-                    .position(|x| x == separator)
-                    .unwrap();
-                seps.remove(index);
-            }
-        }
-        let mut patterns: Vec<String> = Vec::new();
-        for sep in seps {
-            patterns.push(format!(r"\d{{,3}}(?:{sep}\d{{3}})+"));
-        }
+    fn number_locale(&self) -> NumberLocale {
+        self.config.number_locale.clone().unwrap_or_default()
+    }
+
+    /// The digit groups of `locale` and its decimal mark, leaving out `Config::exclude_separators`.
+    fn numeral_parts(&self, locale: &NumberLocale) -> (Vec<String>, String) {
+        let excluded = self.config.exclude_separators.clone().unwrap_or_default();
+        let groups = locale
+            .grouping()
+            .into_iter()
+            .filter(|(c, _)| !excluded.contains(&c.to_string()))
+            .map(|(c, grouping)| group_pattern(c, grouping))
+            .collect();
+        let marks: String = locale.decimals().into_iter().map(escape).collect();
+        let point = match locale {
+            // `3,5` but not the `1,2` of `1,2,3`
+            NumberLocale::Auto => format!(r"[{marks}](?!\d+[.,]\d)"),
+            NumberLocale::Fixed(_) => format!("[{marks}]"),
+        };
+        (groups, point)
+    }
+
+    fn integer_pattern(&self, locale: &NumberLocale) -> String {
+        let (mut patterns, _) = self.numeral_parts(locale);
         patterns.push(r"\d+".to_string());
         let mut pattern: String = patterns.join("|");
         pattern = format!("(?:{})", pattern);
@@ -551,44 +872,127 @@ impl Data {
         }
         pattern
     }
-    
-    pub fn float_regex(&mut self) -> String {
-        let mut intre = self.integer_regex();
+
+    fn float_pattern(&self, locale: &NumberLocale) -> String {
+        let mut intre = self.integer_pattern(locale);
+        let (_, point) = self.numeral_parts(locale);
         if self.config.bounded_numbers.unwrap() {
-            intre = intre.trim_start_matches(|c: char| c.to_string() == "\\b".to_string()).to_string();
+            intre = intre.trim_start_matches(|c: char| c.to_string() == "\\b").to_string();
 
         }
-        let mut pattern = format!(r#"{EXTENDED}{intre}(?:\.\d+(?:[eE][\-\+]?\d+)?)|{intre}(?:(?:\.\d+)?[eE][\-\+]?\d+)|{intre}(?:\.\d+(?:[eE][\-\+]?\d+)?)|{intre}(?:(?:\.\d+)?[eE][\-\+]?\d+)|{intre}(?:\.\d+(?:[eE][\-\+]?\d+)?)|{intre}(?:(?:\.\d+)?[eE][\-\+]?\d+)|{intre}(?:\.\d+)|(?:\.\d+)(?:[eE][\-\+]?\d+)?"#);
+        let mut pattern = format!(r#"{EXTENDED}{intre}(?:{point}\d+(?:[eE][\-\+]?\d+)?)|{intre}(?:(?:{point}\d+)?[eE][\-\+]?\d+)|{intre}(?:{point}\d+(?:[eE][\-\+]?\d+)?)|{intre}(?:(?:{point}\d+)?[eE][\-\+]?\d+)|{intre}(?:{point}\d+(?:[eE][\-\+]?\d+)?)|{intre}(?:(?:{point}\d+)?[eE][\-\+]?\d+)|{intre}(?:{point}\d+)|(?:{point}\d+)(?:[eE][\-\+]?\d+)?"#);
         pattern = format!("(?:{})", pattern);
         if self.config.bounded_numbers.unwrap() {
             pattern = format!("{}{}", B_LEFT, pattern);
         }
         pattern
     }
-    
-    pub fn any_number_regex(&mut self) -> String {
+
+    fn integer_regex(&self) -> String {
+        self.integer_pattern(&NumberLocale::default())
+    }
+
+    fn float_regex(&self) -> String {
+        self.float_pattern(&NumberLocale::default())
+    }
+
+    fn any_number_regex(&self) -> String {
         format!("(?:{}|{})", self.float_regex(), self.integer_regex())
     }
+
+    fn numeral_regex(&self) -> String {
+        let locale = self.number_locale();
+        let pattern = format!("(?:{}|{})", self.float_pattern(&locale), self.integer_pattern(&locale));
+        match locale {
+            // `1,2,3` and `1.2.3` are read as integers
+            NumberLocale::Auto => format!(r"(?:(?<!\d)(?<!\d[.,]){pattern}(?![.,]\d)|\d+)"),
+            NumberLocale::Fixed(_) => pattern,
+        }
+    }
+
+    fn spaced_numeral_regex(&self) -> String {
+        // taken whole before the words are split at the spaces
+        let locale = self.number_locale();
+        let excluded = self.config.exclude_separators.clone().unwrap_or_default();
+        let groups: Vec<String> = locale
+            .grouping()
+            .into_iter()
+            .filter(|(c, _)| c.is_whitespace() && !excluded.contains(&c.to_string()))
+            .map(|(c, grouping)| group_pattern(c, grouping))
+            .collect();
+        if groups.is_empty() {
+            return String::new();
+        }
+        let (_, point) = self.numeral_parts(&locale);
+        let sign = if self.config.signs_allowed.unwrap() { r"[\-\+]?" } else { "" };
+        format!(
+            r"(?<![\d.,]){sign}(?:{})(?:{point}\d+)?(?:[eE][\-\+]?\d+)?(?![.,]?\d)",
+            groups.join("|"),
+        )
+    }
     
-    pub fn complex_number_regex(&mut self) -> String {
+    fn complex_number_regex(&self) -> String {
+        // `3+4i`, `5 - 2i`, `-2.5j`, `3+i`, the unit is lowercase so `I` stays a pronoun
         let mut processor = RegexProcessor::new();
-        processor
-            .bound(format!(r"{}[ij]\b", self.any_number_regex()), None)
+        let number = self.any_number_regex();
+        let units = processor.join(self.imaginary_units(), None);
+        processor.bound(
+            format!(r"(?:[-+]?{number}\s*[-+]\s*(?:{number})?|[-+]?{number})(?-i:{units})"),
+            None,
+        )
+    }
+
+    fn spoken_complex_number_regex(&self) -> String {
+        // `two plus three i`, `minus one minus i`, `four j`
+        let mut processor = RegexProcessor::new();
+        let numbers: Vec<String> = self.all_nums().into_keys().collect();
+        let mut words = numbers.clone();
+        words.extend(self.ands());
+        words.push(self.points()[0].clone());
+        let numbers = processor.join(numbers, None);
+        let words = processor.join(words, None);
+        let negatives = processor.join(self.negatives(), None);
+        let signs = processor.join(
+            self.complex_signs().into_keys().filter(|k| k.chars().all(char::is_alphabetic)).collect(),
+            None,
+        );
+        let units = processor.join(self.imaginary_units(), None);
+        let spoken = format!(r"(?:{negatives}\s+)?{numbers}(?:(?:\s+|-){words})*");
+        processor.bound(
+            format!(r"{spoken}\s+(?:{signs}\s+(?:{spoken}\s+)?)?(?-i:{units})"),
+            None,
+        )
     }
     
-    pub fn binary_regex(&mut self) -> String {
-        format!("{}{}{}", B_LEFT, r"0[bB][01]+", B_RIGHT)
+    fn binary_regex(&self) -> String {
+        format!("{}0[bB]_?[01](?:_?[01])*_?{}?{}", B_LEFT, integer_suffix_pattern(), B_RIGHT)
     }
     
-    pub fn hex_regex(&mut self) -> String {
-        format!("{}{}{}", B_LEFT, r"0[xX][0-9a-fA-F]+", B_RIGHT)
+    fn hex_regex(&self) -> String {
+        format!("{}0[xX]_?[0-9a-fA-F](?:_?[0-9a-fA-F])*_?{}?{}", B_LEFT, integer_suffix_pattern(), B_RIGHT)
     }
     
-    pub fn oct_regex(&mut self) -> String {
-        format!("{}{}{}", B_LEFT, r"0[oO][0-7]+", B_RIGHT)
+    fn oct_regex(&self) -> String {
+        let suffix = integer_suffix_pattern();
+        let prefixed = format!("0[oO]_?[0-7](?:_?[0-7])*_?{suffix}?");
+        if !self.config.code_literals.unwrap_or(false) {
+            return format!("{B_LEFT}{prefixed}{B_RIGHT}");
+        }
+        // `0755` but not `0.75`, `0789` nor the `05` of `2024-01-05`
+        let c_style = format!(r"(?<![\d.,\-])0[0-7]+{suffix}?(?![\d.])");
+        format!("{B_LEFT}(?:{prefixed}|{c_style}){B_RIGHT}")
+    }
+
+    fn typed_literal_regex(&self) -> String {
+        if !self.config.code_literals.unwrap_or(false) {
+            return String::new();
+        }
+        let digits = r"\d(?:_?\d)*";
+        let float = format!(r"{digits}(?:\.{digits})?(?:[eE][-+]?\d+)?{}", float_suffix_pattern());
+        format!(r"(?<![\w.]){digits}{}(?!\w)|(?<![\w.]){float}(?!\w)", integer_suffix_pattern())
     }
     
-    pub fn _all_ones(&mut self) -> Vec<String> {
+    fn _all_ones(&self) -> Vec<String> {
         let mut all_ones = Vec::new();
         for (k, v) in self.ones() {
             all_ones.push(k.to_string());
@@ -597,21 +1001,19 @@ impl Data {
         all_ones
     }
     
-    pub fn number_followed_by_power_regex(&mut self) -> String {
+    fn number_followed_by_power_regex(&self) -> String {
         format!(r"{DEFAULT_RE_FLAGS}(?P<number>{_any_number})\s*(?P<power>{_power_names}){b_right}", 
-            _any_number =self.any_number_regex(),
+            _any_number =self.numeral_regex(),
             _power_names=self._multiples(),
             b_right=B_RIGHT,
         )
     }
     
-    pub fn suffix_name_regex(&mut self) -> String {
+    fn suffix_name_regex(&self) -> String {
         let mut processor = RegexProcessor::new();
         let suffix_names = processor
         .join(
-            self.suffixes_by_name()
-                .iter()
-                .map(|(k, _)| k.to_string())
+            self.suffixes_by_name().keys().map(|k| k.to_string())
                 .collect::<Vec<String>>(),
             None
         );
@@ -619,39 +1021,40 @@ impl Data {
         format!("{IGNORECASE}{EXTENDED}{pattern}")
     }
         
-    pub fn number_followed_by_suffix_regex(&mut self) -> String {
+    fn number_followed_by_suffix_regex(&self) -> String {
+        self.number_followed_by_suffix_pattern(&self.any_number_regex())
+    }
+
+    fn number_followed_by_suffix_pattern(&self, any_number: &str) -> String {
         // any Number followed by a multiple suffix
         let mut processor = RegexProcessor::new();
         let mut _suffixes_by_name = processor
         .join(
-            self.suffixes_by_name()
-            .iter()
-            .map(|(k, _)| {
+            self.suffixes_by_name().keys().map(|k| {
                 k.to_string()
             })
             .collect::<Vec<String>>(),
             None
         );
         let pattern = format!(r"{EXTENDED}(?P<number>{_any_number})(?P<suffix>\s*(?:{_suffixes_by_name})|(?:{_suffixes}))\b", 
-            _any_number=self.any_number_regex(),
+            _any_number=any_number,
             _suffixes=self._suffixes(),
             _suffixes_by_name=_suffixes_by_name,
         );
         pattern
     }
 
-    pub fn informals_exact_regex(&mut self) -> String {
+    fn informals_exact_regex(&self) -> String {
         // infomals couple, pair, dozen...
         let mut processor = RegexProcessor::new();
         let mut _informal = processor.
             join(
-                self.informal_exact()
-                .iter()
-                .map(|(k, _)| k.to_string())
+                self.informal_exact().keys().map(|k| k.to_string())
                 .collect::<Vec<String>>(),
                 None
             );
-        let ones = vec!["1".to_string(), "0".to_string(), "one".to_string(), "zero".to_string()].to_vec();
+        let mut ones: Vec<String> = self.language.ones().into_iter().filter(|(_, v)| *v <= 1.0).map(|(k, _)| k).collect();
+        ones.extend(["1".to_string(), "0".to_string()]);
         let mut _small = processor
             .join(
                 ones,
@@ -661,25 +1064,27 @@ impl Data {
         pattern
     }
 
-    pub fn informals_multiplyable_regex(&mut self) -> String {
+    fn informals_multiplyable_regex(&self) -> String {
         let mut processor = RegexProcessor::new();
         let mut _informals_multiplyable = processor
             .join(
-                self.informals_multiplyable()
-                .iter()
-                .map(|(k, _)| k.to_string())
+                self.informals_multiplyable().keys().map(|k| k.to_string())
                 .collect::<Vec<String>>(),
                 None
             );
         let mut pattern = format!(r"{DEFAULT_RE_FLAGS}(?:{_any_number})\s+(?:{_informals_multiplyable})",
-            _any_number=self.any_number_regex(),
+            _any_number=self.numeral_regex(),
             _informals_multiplyable=_informals_multiplyable,
         );
         pattern = processor.bound(pattern, None);
         pattern
     }
 
-    pub fn ordinal_numeral_regex(&mut self) -> String {
+    fn ordinal_numeral_regex(&self) -> String {
+        self.ordinal_numeral_pattern(&self.integer_regex())
+    }
+
+    fn ordinal_numeral_pattern(&self, integer: &str) -> String {
         let mut processor = RegexProcessor::new();
         let mut _ordinal_suffixes = processor.retrie(
             self.ordinal_suffixes()
@@ -688,41 +1093,75 @@ impl Data {
             .collect::<Vec<String>>()
         );
         let mut pattern = format!(r"{EXTENDED}(?P<number>{integer_regex})(?P<ordinal>{_ordinal_suffixes})",
-            integer_regex=self.integer_regex(),
+            integer_regex=integer,
             _ordinal_suffixes=_ordinal_suffixes,
         );
         pattern = processor.bound(pattern, None);
         pattern
     }
 
-    pub fn first_extraction_regexes(&mut self) -> Vec<String> {
-        let mut regexes = vec![
-            self.number_followed_by_suffix_regex(), // 0
-            self.superscript_ones_regex(), // 1
-            self.subscript_ones_regex(),
-            self.superscript_fractions_regex(), // 2
-            self.hex_regex(), // 3
-            self.oct_regex(), // 4
-            self.binary_regex(), // 5
-            self.ordinal_numeral_regex(), // 6
-            self.number_followed_by_power_regex(), // 7
-            self.informals_multiplyable_regex(), // 8
-        ].to_vec();
+    fn first_extraction_regexes(&self) -> Vec<String> {
+        // prefixed literals go first so `0x1F` isn't read as `0` and `1F` (femto)
+        let locale = self.number_locale();
+        let mut regexes = vec![self.hex_regex(), self.oct_regex(), self.binary_regex()];
+        // `10u32`, `1.5f`, before `f` is read as femto
+        let typed = self.typed_literal_regex();
+        if !typed.is_empty() {
+            regexes.push(typed);
+        }
         if self.config.parse_complex.unwrap() {
-            regexes.insert(6, self.complex_number_regex());
+            regexes.push(self.complex_number_regex());
+            regexes.push(self.spoken_complex_number_regex());
+        }
+        regexes.extend([
+            self.cjk_numeral_regex(),
+            self.myriad_regex(),
+            self.fraction_regex(),
+            self.number_followed_by_suffix_pattern(&self.numeral_regex()),
+            self.superscript_ones_regex(),
+            self.subscript_ones_regex(),
+            self.superscript_fractions_regex(),
+            self.ordinal_numeral_pattern(&self.integer_pattern(&locale)),
+            self.number_followed_by_power_regex(),
+            self.informals_multiplyable_regex(),
+        ]);
+        // `1 234 567,5`
+        let spaced = self.spaced_numeral_regex();
+        if !spaced.is_empty() {
+            regexes.push(spaced);
         }
         regexes
     }
-         
-    pub fn last_extraction_regexes(&mut self) -> Vec<String> {
-        vec![self.any_number_regex()].to_vec()
+
+    fn last_extraction_regexes(&self) -> Vec<String> {
+        [self.numeral_regex()].to_vec()
     }
-    
-    pub fn get_suffix_value(&mut self, suffix: &str) -> Option<f64> {
-        let mut map = HashMap::<String, f64>::new();
-        map.extend(self.suffixes());
-        map.extend(self.suffixes_by_name());
-        map.get(suffix).copied()
+
+    fn rep_commas_regex(&self) -> String {
+        // only a multiple of 1000 can have a comma after it
+        let ordinal_multiples = self.ordinal_multiples();
+        let suffixes_by_name = self.suffixes_by_name();
+        let hundreds = self.hundreds();
+        let multiples: String = self
+            .multiples()
+            .into_keys()
+            .filter(|k| {
+                !ordinal_multiples.contains_key(k) && (
+                    !suffixes_by_name.contains_key(k) ||
+                    !hundreds.contains_key(k)
+                )
+            })
+            .collect::<Vec<String>>()
+            .join("|");
+        format!(r"(?m)({multiples})\s?,")
     }
-}
 
+    fn complex_split_regex(&self) -> String {
+        // the sign between the parts, not a leading sign, an exponent or a hyphen: `-1.5e-3+2i`, `twenty-one plus i`
+        let words = self.complex_signs().into_keys().filter(|k| k.chars().all(char::is_alphabetic)).collect::<Vec<_>>();
+        format!(
+            r"(?i)^(?P<real>.*?[^\s+\-])(?<!\d[eE])\s*(?P<sign>(?<![a-zA-Z])[+\-]|\b(?:{})\b)\s*(?P<imag>[^+\-]*)$",
+            words.join("|"),
+        )
+    }
+}
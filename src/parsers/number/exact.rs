@@ -0,0 +1,196 @@
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::{One, ToPrimitive, Zero};
+
+use crate::parsers::number::Data;
+use crate::parsers::number::constants::WORD_HYPHEN;
+use crate::parsers::number::value::ten_pow;
+
+/// Exponents beyond this aren't computed exactly, `1e999999999` is left to the float conversion
+/// (and isn't a number in exact mode) and `10^5000` to separate numbers.
+pub(crate) const MAX_EXPONENT: u32 = 1000;
+
+/// Converts a number to an exact fraction without going through `f64`.
+///
+/// Understands what `Config::exact` promises to keep exact: numerals (`1,287.05`, `1e-35`,
+/// `3.5k`, `21st`) and spoken numbers built from the `ones`, `tens`, `multiples`, ordinal
+/// and informal tables, eg: `nine quintillion and one`, `one point one`, `two and a half`,
+/// `two point five million`.
+/// Anything else returns `None` and is left to the float conversion.
+pub fn words2exact(text: &str, data: &Data) -> Option<BigRational> {
+    let words = data.all_nums();
+    let ordinals = data.ordinals();
+    let informals = data.informal_all();
+    let hundreds = data.hundreds();
+    let negatives = data.negatives();
+    let (ands, points, a) = (data.ands(), data.points(), data.a());
+    let suffixed = &data.regexes().number_followed_by_suffix;
+    let ordinal_numeral = &data.regexes().ordinal_numeral;
+
+    // `twenty-one` -> `twenty one`
+    let text = WORD_HYPHEN.replace_all(text, " ").to_string();
+    // `einundzwanzig` -> `ein und zwanzig`
+    let tokens: Vec<String> = text
+        .split_whitespace()
+        .map(|t| t.trim_end_matches([',', '.']))
+        .filter(|t| !t.is_empty())
+        .flat_map(|t| data.split_compound(t))
+        .collect();
+    if tokens.is_empty() {
+        return None;
+    }
+
+    let mut negative = false;
+    let mut total = BigRational::zero();
+    let mut current = BigRational::zero();
+    let mut decimals: Option<String> = None;
+    // the multiples after the decimals: `two point five million`
+    let mut multiple: Option<BigRational> = None;
+    let mut after_and = false;
+    for (i, original) in tokens.iter().enumerate() {
+        let token = original.to_lowercase();
+        if i == 0 && negatives.contains(&token) {
+            negative = true;
+            continue;
+        }
+        if ands.contains(&token) {
+            after_and = true;
+            continue;
+        }
+        if a.contains(&token) {
+            continue;
+        }
+        if points.contains(&token) {
+            decimals = Some(String::new());
+            continue;
+        }
+        if let Some(digits) = decimals.as_mut() {
+            // `point one five` and `point 15`, then `million`
+            match words.get(&token) {
+                Some(v) if multiple.is_none() && *v < 10.0 && v.fract() == 0.0 => digits.push_str(&v.to_string()),
+                _ if multiple.is_none() && token.chars().all(|c| c.is_ascii_digit()) => digits.push_str(&token),
+                Some(v) if !digits.is_empty() && v.fract() == 0.0 && *v >= 1000.0 => {
+                    multiple = Some(multiple.unwrap_or_else(BigRational::one) * table_value(*v)?);
+                }
+                _ => return None,
+            }
+            continue;
+        }
+        let value = if let Some(value) = parse_numeral(&token) {
+            value
+        } else if let Some(caps) = ordinal_numeral.captures(&token).ok().flatten() {
+            parse_numeral(&caps.name("number")?.as_str().replace([',', '\'', '_'], ""))?
+        } else if let Some(caps) = suffixed.captures(original).ok().flatten() {
+            // suffixes are case sensitive, `M` is mega and `m` is milli
+            let number = parse_numeral(caps.name("number")?.as_str())?;
+            number * table_value(data.get_suffix_value(caps.name("suffix")?.as_str().trim())?)?
+        } else if let Some(informal) = informals.get(&token) {
+            let informal = table_value(*informal)?;
+            // `two and a half`, `a half`, `two halves`
+            if after_and {
+                current += informal;
+            } else if current.is_zero() {
+                current = informal;
+            } else {
+                current *= informal;
+            }
+            after_and = false;
+            continue;
+        } else if let Some(word) = words.get(&token).or_else(|| ordinals.get(&token)) {
+            let word = table_value(*word)?;
+            let as_int = word.to_integer().to_i64();
+            if hundreds.contains_key(&token) || as_int == Some(100) {
+                // multiplies what's below it: `two hundred`, `cent quatre vingts` -> 100 + 4 * 20
+                let below = if current.is_integer() { &current % &word } else { current.clone() };
+                current = if below.is_zero() { current + word } else { current - &below + below * word };
+            } else if word.is_integer() && as_int.is_none_or(|v| v >= 1000) {
+                // `million` closes the group before it: `two hundred five | thousand`
+                let group = if current.is_zero() { BigRational::from_integer(1.into()) } else { current };
+                total += group * word;
+                current = BigRational::zero();
+            } else {
+                current += word;
+            }
+            after_and = false;
+            continue;
+        } else {
+            return None;
+        };
+        current += value;
+        after_and = false;
+    }
+    let mut number = total + current;
+    if let Some(digits) = decimals.filter(|d| !d.is_empty()) {
+        let scale = u32::try_from(digits.len()).ok()?;
+        number += BigRational::new(digits.parse::<BigInt>().ok()?, ten_pow(scale));
+    }
+    if let Some(multiple) = multiple {
+        number *= multiple;
+    }
+    Some(if negative { -number } else { number })
+}
+
+/// Whether `text` is a numeral in e-notation with an exponent beyond [`MAX_EXPONENT`]: `1e999999999`.
+pub(crate) fn beyond_max_exponent(text: &str) -> bool {
+    let Some((mantissa, exponent)) = text.split_once(['e', 'E']) else {
+        return false;
+    };
+    let digits = exponent.strip_prefix(['-', '+']).unwrap_or(exponent);
+    parse_numeral(mantissa).is_some()
+        && !digits.is_empty()
+        && digits.bytes().all(|b| b.is_ascii_digit())
+        && digits.parse::<u32>().map_or(true, |e| e > MAX_EXPONENT)
+}
+
+/// Parses a plain decimal numeral exactly: `-1,287.05`, `1e-35`, `+13'689E-12`. `None` for
+/// exponents beyond a thousand, which are left to the float conversion.
+pub fn parse_numeral(text: &str) -> Option<BigRational> {
+    let cleaned = text.replace([',', '\'', '_'], "");
+    let (mantissa, exponent) = match cleaned.find(['e', 'E']) {
+        Some(i) => (&cleaned[..i], cleaned[i + 1..].parse::<i32>().ok()?),
+        None => (cleaned.as_str(), 0),
+    };
+    if exponent.unsigned_abs() > MAX_EXPONENT {
+        return None;
+    }
+    let (sign, unsigned) = match mantissa.strip_prefix('-') {
+        Some(rest) => (-1, rest),
+        None => (1, mantissa.strip_prefix('+').unwrap_or(mantissa)),
+    };
+    let (whole, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+    if whole.is_empty() && fraction.is_empty()
+        || !whole.chars().chain(fraction.chars()).all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let digits: BigInt = format!("{whole}{fraction}").parse().ok()?;
+    let scale = i32::try_from(fraction.len()).ok()?.checked_sub(exponent)?;
+    let value = if scale >= 0 {
+        BigRational::new(digits, ten_pow(scale as u32))
+    } else {
+        BigRational::from_integer(digits * ten_pow(scale.unsigned_abs()))
+    };
+    Some(value * BigRational::from_integer(sign.into()))
+}
+
+/// The exact value behind an entry of one of the `Data` tables.
+///
+/// The tables hold `f64`s, so `1e+24` and `0.001` are read back as the power of ten they stand for.
+pub fn table_value(value: f64) -> Option<BigRational> {
+    if value == 0.0 {
+        return Some(BigRational::zero());
+    }
+    if value.fract() == 0.0 && value.abs() < 2f64.powi(53) {
+        return Some(BigRational::from_integer(BigInt::from(value as i64)));
+    }
+    let exponent = value.abs().log10().round();
+    if (value.abs() / 10f64.powf(exponent) - 1.0).abs() < 1e-12 {
+        let power = ten_pow(exponent.abs() as u32);
+        let power = if exponent < 0.0 {
+            BigRational::new(1.into(), power)
+        } else {
+            BigRational::from_integer(power)
+        };
+        return Some(if value < 0.0 { -power } else { power });
+    }
+    BigRational::from_float(value)
+}
@@ -0,0 +1,100 @@
+use fancy_regex::Regex;
+use lazy_static::lazy_static;
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::{Signed, Zero};
+
+use crate::parsers::number::Data;
+use crate::parsers::number::constants::WORD_HYPHEN;
+use crate::parsers::number::exact::{parse_numeral, words2exact};
+
+lazy_static! {
+    static ref TYPED_FRACTION: Regex =
+        Regex::new(r"^(?:(?P<whole>[-+]?\d+)\s+)?(?P<numer>[-+]?\d+)\s*/\s*(?P<denom>\d+)$").unwrap();
+}
+
+/// Parses fractions and mixed numbers exactly.
+///
+/// Typed: `3/4`, `1 1/2`, `1½`. Spoken: `three quarters`, `two thirds`, `a fifth`,
+/// `one and three sixteenths`; the denominators come from `Data::fraction_denominators`.
+/// A singular denominator needs a numerator of one, `twenty third` is an ordinal.
+pub fn words2fraction(text: &str, data: &Data) -> Option<BigRational> {
+    let text = text.trim();
+    typed_fraction(text)
+        .or_else(|| glyph_fraction(text, data))
+        .or_else(|| spoken_fraction(text, data))
+}
+
+fn typed_fraction(text: &str) -> Option<BigRational> {
+    let caps = TYPED_FRACTION.captures(text).ok()??;
+    let numer: BigInt = caps.name("numer")?.as_str().trim_start_matches('+').parse().ok()?;
+    let denom: BigInt = caps.name("denom")?.as_str().parse().ok()?;
+    if denom.is_zero() {
+        return None;
+    }
+    let fraction = BigRational::new(numer, denom);
+    match caps.name("whole") {
+        Some(whole) => mixed(parse_numeral(whole.as_str())?, fraction),
+        None => Some(fraction),
+    }
+}
+
+fn glyph_fraction(text: &str, data: &Data) -> Option<BigRational> {
+    let glyph = text.chars().last()?;
+    let fraction = small_fraction(*data.superscript_fractions().get(&glyph)?)?;
+    let whole = text[..text.len() - glyph.len_utf8()].trim();
+    if whole.is_empty() {
+        return Some(fraction);
+    }
+    if !whole.trim_start_matches(['-', '+']).chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    mixed(parse_numeral(whole)?, fraction)
+}
+
+fn spoken_fraction(text: &str, data: &Data) -> Option<BigRational> {
+    let denominators = data.fraction_denominators();
+    let lower = WORD_HYPHEN.replace_all(&text.to_lowercase(), " ").to_string();
+    let tokens: Vec<&str> = lower.split_whitespace().collect();
+    let (last, rest) = tokens.split_last()?;
+    let denom = BigInt::from(*denominators.get(*last)? as i64);
+    let plural = last.ends_with('s');
+
+    let ands = data.ands();
+    let split = rest.iter().rposition(|t| ands.contains(&t.to_string()));
+    let (whole, numer) = match split {
+        Some(i) => (Some(&rest[..i]), &rest[i + 1..]),
+        None => (None, rest),
+    };
+    let numer = match numer {
+        [] => return None,
+        [a] if data.a().contains(&a.to_string()) || *a == "an" => BigRational::from_integer(1.into()),
+        words => words2exact(&words.join(" "), data).filter(|n| n.is_integer())?,
+    };
+    // `three quarter` isn't a fraction, `one quarter` is
+    if !plural && numer.abs() != BigRational::from_integer(1.into()) {
+        return None;
+    }
+    let fraction = numer / BigRational::from_integer(denom);
+    match whole {
+        Some(whole) => mixed(words2exact(&whole.join(" "), data).filter(|n| n.is_integer())?, fraction),
+        None => Some(fraction),
+    }
+}
+
+/// `-1 1/2` is `-(1 + 1/2)`
+fn mixed(whole: BigRational, fraction: BigRational) -> Option<BigRational> {
+    if fraction.is_negative() {
+        return None;
+    }
+    Some(if whole.is_negative() { whole - fraction } else { whole + fraction })
+}
+
+/// The glyph tables hold `f64`s, `0.333..` is read back as `1/3`.
+fn small_fraction(value: f64) -> Option<BigRational> {
+    (1..=16i64).find_map(|denom| {
+        let numer = (value * denom as f64).round();
+        ((numer / denom as f64 - value).abs() < 1e-9)
+            .then(|| BigRational::new(BigInt::from(numer as i64), BigInt::from(denom)))
+    })
+}
@@ -0,0 +1,159 @@
+use std::collections::HashMap;
+
+use super::{table, words, LanguagePack};
+
+pub struct German;
+
+impl LanguagePack for German {
+    fn code(&self) -> &str {
+        "de"
+    }
+
+    fn ones(&self) -> HashMap<String, f64> {
+        table(&[
+            ("null", 0.0),
+            ("ein", 1.0),
+            ("eins", 1.0),
+            ("eine", 1.0),
+            ("einen", 1.0),
+            ("einem", 1.0),
+            ("einer", 1.0),
+            ("zwei", 2.0),
+            ("zwo", 2.0),
+            ("drei", 3.0),
+            ("vier", 4.0),
+            ("fünf", 5.0),
+            ("fuenf", 5.0),
+            ("sechs", 6.0),
+            ("sieben", 7.0),
+            ("acht", 8.0),
+            ("neun", 9.0),
+        ])
+    }
+
+    fn teens_and_ten(&self) -> HashMap<String, f64> {
+        table(&[
+            ("zehn", 10.0),
+            ("elf", 11.0),
+            ("zwölf", 12.0),
+            ("zwoelf", 12.0),
+            ("dreizehn", 13.0),
+            ("vierzehn", 14.0),
+            ("fünfzehn", 15.0),
+            ("fuenfzehn", 15.0),
+            ("sechzehn", 16.0),
+            ("siebzehn", 17.0),
+            ("achtzehn", 18.0),
+            ("neunzehn", 19.0),
+        ])
+    }
+
+    fn tens(&self) -> HashMap<String, f64> {
+        table(&[
+            ("zwanzig", 20.0),
+            ("dreißig", 30.0),
+            ("dreissig", 30.0),
+            ("vierzig", 40.0),
+            ("fünfzig", 50.0),
+            ("fuenfzig", 50.0),
+            ("sechzig", 60.0),
+            ("siebzig", 70.0),
+            ("achtzig", 80.0),
+            ("neunzig", 90.0),
+        ])
+    }
+
+    fn hundreds(&self) -> HashMap<String, f64> {
+        table(&[("hundert", 100.0)])
+    }
+
+    fn multiples(&self) -> HashMap<String, f64> {
+        table(&[
+            ("tausend", 1000.0),
+            ("million", 1_000_000.0),
+            ("millionen", 1_000_000.0),
+            ("milliarde", 1_000_000_000.0),
+            ("milliarden", 1_000_000_000.0),
+            ("billion", 1_000_000_000_000.0),
+            ("billionen", 1_000_000_000_000.0),
+        ])
+    }
+
+    fn ordinal_ones(&self) -> HashMap<String, f64> {
+        table(&[
+            ("erste", 1.0),
+            ("zweite", 2.0),
+            ("dritte", 3.0),
+            ("vierte", 4.0),
+            ("fünfte", 5.0),
+            ("sechste", 6.0),
+            ("siebte", 7.0),
+            ("achte", 8.0),
+            ("neunte", 9.0),
+        ])
+    }
+
+    fn ordinal_teens_and_ten(&self) -> HashMap<String, f64> {
+        table(&[
+            ("zehnte", 10.0),
+            ("elfte", 11.0),
+            ("zwölfte", 12.0),
+            ("dreizehnte", 13.0),
+            ("vierzehnte", 14.0),
+            ("fünfzehnte", 15.0),
+            ("sechzehnte", 16.0),
+            ("siebzehnte", 17.0),
+            ("achtzehnte", 18.0),
+            ("neunzehnte", 19.0),
+        ])
+    }
+
+    fn ordinal_tens(&self) -> HashMap<String, f64> {
+        table(&[
+            ("zwanzigste", 20.0),
+            ("dreißigste", 30.0),
+            ("vierzigste", 40.0),
+            ("fünfzigste", 50.0),
+            ("sechzigste", 60.0),
+            ("siebzigste", 70.0),
+            ("achtzigste", 80.0),
+            ("neunzigste", 90.0),
+        ])
+    }
+
+    fn ordinal_multiples(&self) -> HashMap<String, f64> {
+        table(&[("hundertste", 100.0), ("tausendste", 1000.0)])
+    }
+
+    fn informal_exact(&self) -> HashMap<String, f64> {
+        table(&[("dutzend", 12.0)])
+    }
+
+    fn ands(&self) -> Vec<String> {
+        words(&["und"])
+    }
+
+    fn points(&self) -> Vec<String> {
+        words(&["komma", "punkt"])
+    }
+
+    fn negatives(&self) -> Vec<String> {
+        words(&["minus", "negativ"])
+    }
+
+    fn complex_signs(&self) -> HashMap<String, f64> {
+        table(&[("plus", 1.0), ("minus", -1.0)])
+    }
+
+    fn splits_compounds(&self) -> bool {
+        true
+    }
+
+    fn long_scale(&self) -> bool {
+        true
+    }
+
+    fn ones_before_tens(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,220 @@
+use std::collections::HashMap;
+
+use super::{table, words, LanguagePack};
+
+pub struct English;
+
+impl LanguagePack for English {
+    fn code(&self) -> &str {
+        "en"
+    }
+
+    fn ones(&self) -> HashMap<String, f64> {
+        table(&[
+            ("zero", 0.0),
+            ("one", 1.0),
+            ("two", 2.0),
+            ("three", 3.0),
+            ("four", 4.0),
+            ("five", 5.0),
+            ("six", 6.0),
+            ("seven", 7.0),
+            ("eight", 8.0),
+            ("nine", 9.0),
+        ])
+    }
+
+    fn teens_and_ten(&self) -> HashMap<String, f64> {
+        table(&[
+            ("ten", 10.0),
+            ("eleven", 11.0),
+            ("twelve", 12.0),
+            ("thirteen", 13.0),
+            ("fourteen", 14.0),
+            ("fifteen", 15.0),
+            ("sixteen", 16.0),
+            ("seventeen", 17.0),
+            ("eighteen", 18.0),
+            ("nineteen", 19.0),
+        ])
+    }
+
+    fn tens(&self) -> HashMap<String, f64> {
+        table(&[
+            ("twenty", 20.0),
+            ("thirty", 30.0),
+            ("forty", 40.0),
+            ("fifty", 50.0),
+            ("sixty", 60.0),
+            ("seventy", 70.0),
+            ("eighty", 80.0),
+            ("ninety", 90.0),
+        ])
+    }
+
+    fn hundreds(&self) -> HashMap<String, f64> {
+        table(&[("hundred", 100.0)])
+    }
+
+    fn multiples(&self) -> HashMap<String, f64> {
+        table(&[
+            ("thousand", 1000.0),
+            ("million", 1_000_000.0),
+            ("milliard", 1_000_000_000.0),
+            ("billion", 1_000_000_000.0),
+            ("trillion", 1_000_000_000_000.0),
+            ("quadrillion", 1e15),
+            ("quintillion", 1e18),
+            ("sextillion", 1e21),
+            ("septillion", 1e24),
+            ("octillion", 1e27),
+            ("nonillion", 1e30),
+            ("decillion", 1e33),
+            ("undecillion", 1e36),
+            ("duodecillion", 1e39),
+            ("tredecillion", 1e42),
+            ("quattuordecillion", 1e45),
+            ("quinquadecillion", 1e48),
+            ("sedecillion", 1e51),
+            ("septendecillion", 1e54),
+            ("octodecillion", 1e57),
+            ("novendecillion", 1e60),
+            ("vigintillion", 1e63),
+            ("unvigintillion", 1e66),
+            ("uuovigintillion", 1e69),
+            ("tresvigintillion", 1e72),
+            ("quattuorvigintillion", 1e75),
+            ("quinquavigintillion", 1e78),
+            ("qesvigintillion", 1e81),
+            ("septemvigintillion", 1e84),
+            ("octovigintillion", 1e87),
+            ("novemvigintillion", 1e90),
+            ("trigintillion", 1e93),
+            ("untrigintillion", 1e96),
+            ("duotrigintillion", 1e99),
+            ("trestrigintillion", 1e102),
+            ("quattuortrigintillion", 1e105),
+            ("quinquatrigintillion", 1e108),
+            ("sestrigintillion", 1e111),
+            ("septentrigintillion", 1e114),
+            ("octotrigintillion", 1e117),
+            ("noventrigintillion", 1e120),
+            ("quadragintillion", 1e123),
+        ])
+    }
+
+    fn ordinal_ones(&self) -> HashMap<String, f64> {
+        table(&[
+            ("zeroth", 0.0),
+            ("first", 1.0),
+            ("second", 2.0),
+            ("third", 3.0),
+            ("fourth", 4.0),
+            ("fifth", 5.0),
+            ("sixth", 6.0),
+            ("seventh", 7.0),
+            ("eighth", 8.0),
+            ("ninth", 9.0),
+        ])
+    }
+
+    fn ordinal_teens_and_ten(&self) -> HashMap<String, f64> {
+        table(&[
+            ("tenth", 10.0),
+            ("eleventh", 11.0),
+            ("twelfth", 12.0),
+            ("thirteenth", 13.0),
+            ("fourteenth", 14.0),
+            ("fifteenth", 15.0),
+            ("sixteenth", 16.0),
+            ("seventeenth", 17.0),
+            ("eighteenth", 18.0),
+            ("nineteenth", 19.0),
+        ])
+    }
+
+    fn ordinal_tens(&self) -> HashMap<String, f64> {
+        table(&[
+            ("twentieth", 20.0),
+            ("thirtieth", 30.0),
+            ("fortieth", 40.0),
+            ("fiftieth", 50.0),
+            ("sixtieth", 60.0),
+            ("seventieth", 70.0),
+            ("eightieth", 80.0),
+            ("ninetieth", 90.0),
+        ])
+    }
+
+    fn ordinal_multiples(&self) -> HashMap<String, f64> {
+        table(&[
+            ("hundredth", 100.0),
+            ("thousandth", 1000.0),
+            ("millionth", 1_000_000.0),
+            ("billionth", 1_000_000_000.0),
+            ("trillionth", 1_000_000_000_000.0),
+            ("quadrillionth", 1e15),
+            ("quintillionth", 1e18),
+            ("sextillionth", 1e21),
+            ("septillionth", 1e24),
+            ("octillionth", 1e27),
+            ("nonillionth", 1e30),
+            ("decillionth", 1e33),
+        ])
+    }
+
+    fn informal_exact(&self) -> HashMap<String, f64> {
+        //  single 1, couple 2, pair 2 and few 3 are too vague
+        table(&[("half", 0.5), ("quarter", 0.25), ("dozen", 12.0)])
+    }
+
+    fn informals_multiplyable(&self) -> HashMap<String, f64> {
+        table(&[
+            ("couples", 2.0),
+            ("pairs", 2.0),
+            ("dozens", 12.0),
+            ("quarters", 0.25),
+            ("halves", 0.5),
+        ])
+    }
+
+    fn a(&self) -> Vec<String> {
+        words(&["a"])
+    }
+
+    fn ands(&self) -> Vec<String> {
+        words(&["and"])
+    }
+
+    fn points(&self) -> Vec<String> {
+        words(&["point"])
+    }
+
+    fn negatives(&self) -> Vec<String> {
+        words(&["negative", "neg", "minus"])
+    }
+
+    fn complex_signs(&self) -> HashMap<String, f64> {
+        table(&[("plus", 1.0), ("minus", -1.0)])
+    }
+
+    fn year_zero(&self) -> Option<String> {
+        Some("oh".to_string())
+    }
+
+    fn ordinal_suffixes(&self) -> Vec<String> {
+        words(&["st", "nd", "rd", "th"])
+    }
+
+    /// `1st`, `22nd`, `13th`, `101st`.
+    fn is_ordinal_suffix(&self, number: u64, suffix: &str) -> bool {
+        let expected = match (number % 100, number % 10) {
+            (11..=13, _) => "th",
+            (_, 1) => "st",
+            (_, 2) => "nd",
+            (_, 3) => "rd",
+            _ => "th",
+        };
+        suffix == expected
+    }
+}
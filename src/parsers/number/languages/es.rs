@@ -0,0 +1,184 @@
+use std::collections::HashMap;
+
+use super::{table, words, LanguagePack};
+
+pub struct Spanish;
+
+impl LanguagePack for Spanish {
+    fn code(&self) -> &str {
+        "es"
+    }
+
+    fn ones(&self) -> HashMap<String, f64> {
+        table(&[
+            ("cero", 0.0),
+            ("uno", 1.0),
+            ("un", 1.0),
+            ("una", 1.0),
+            ("dos", 2.0),
+            ("tres", 3.0),
+            ("cuatro", 4.0),
+            ("cinco", 5.0),
+            ("seis", 6.0),
+            ("siete", 7.0),
+            ("ocho", 8.0),
+            ("nueve", 9.0),
+        ])
+    }
+
+    fn teens_and_ten(&self) -> HashMap<String, f64> {
+        table(&[
+            ("diez", 10.0),
+            ("once", 11.0),
+            ("doce", 12.0),
+            ("trece", 13.0),
+            ("catorce", 14.0),
+            ("quince", 15.0),
+            ("dieciséis", 16.0),
+            ("dieciseis", 16.0),
+            ("diecisiete", 17.0),
+            ("dieciocho", 18.0),
+            ("diecinueve", 19.0),
+        ])
+    }
+
+    /// `veintiuno` to `veintinueve` are written as one word.
+    fn tens(&self) -> HashMap<String, f64> {
+        table(&[
+            ("veinte", 20.0),
+            ("veintiuno", 21.0),
+            ("veintiún", 21.0),
+            ("veintiun", 21.0),
+            ("veintiuna", 21.0),
+            ("veintidós", 22.0),
+            ("veintidos", 22.0),
+            ("veintitrés", 23.0),
+            ("veintitres", 23.0),
+            ("veinticuatro", 24.0),
+            ("veinticinco", 25.0),
+            ("veintiséis", 26.0),
+            ("veintiseis", 26.0),
+            ("veintisiete", 27.0),
+            ("veintiocho", 28.0),
+            ("veintinueve", 29.0),
+            ("treinta", 30.0),
+            ("cuarenta", 40.0),
+            ("cincuenta", 50.0),
+            ("sesenta", 60.0),
+            ("setenta", 70.0),
+            ("ochenta", 80.0),
+            ("noventa", 90.0),
+        ])
+    }
+
+    fn hundreds(&self) -> HashMap<String, f64> {
+        table(&[
+            ("cien", 100.0),
+            ("ciento", 100.0),
+            ("doscientos", 200.0),
+            ("doscientas", 200.0),
+            ("trescientos", 300.0),
+            ("trescientas", 300.0),
+            ("cuatrocientos", 400.0),
+            ("cuatrocientas", 400.0),
+            ("quinientos", 500.0),
+            ("quinientas", 500.0),
+            ("seiscientos", 600.0),
+            ("seiscientas", 600.0),
+            ("setecientos", 700.0),
+            ("setecientas", 700.0),
+            ("ochocientos", 800.0),
+            ("ochocientas", 800.0),
+            ("novecientos", 900.0),
+            ("novecientas", 900.0),
+        ])
+    }
+
+    fn multiples(&self) -> HashMap<String, f64> {
+        table(&[
+            ("mil", 1000.0),
+            ("millón", 1_000_000.0),
+            ("millon", 1_000_000.0),
+            ("millones", 1_000_000.0),
+            ("millardo", 1_000_000_000.0),
+            ("millardos", 1_000_000_000.0),
+            ("billón", 1_000_000_000_000.0),
+            ("billon", 1_000_000_000_000.0),
+            ("billones", 1_000_000_000_000.0),
+        ])
+    }
+
+    fn ordinal_ones(&self) -> HashMap<String, f64> {
+        table(&[
+            ("primero", 1.0),
+            ("primer", 1.0),
+            ("primera", 1.0),
+            ("segundo", 2.0),
+            ("segunda", 2.0),
+            ("tercero", 3.0),
+            ("tercer", 3.0),
+            ("tercera", 3.0),
+            ("cuarto", 4.0),
+            ("cuarta", 4.0),
+            ("quinto", 5.0),
+            ("quinta", 5.0),
+            ("sexto", 6.0),
+            ("sexta", 6.0),
+            ("séptimo", 7.0),
+            ("séptima", 7.0),
+            ("octavo", 8.0),
+            ("octava", 8.0),
+            ("noveno", 9.0),
+            ("novena", 9.0),
+        ])
+    }
+
+    fn ordinal_teens_and_ten(&self) -> HashMap<String, f64> {
+        table(&[("décimo", 10.0), ("décima", 10.0)])
+    }
+
+    fn ordinal_tens(&self) -> HashMap<String, f64> {
+        table(&[("vigésimo", 20.0), ("vigésima", 20.0), ("trigésimo", 30.0), ("trigésima", 30.0)])
+    }
+
+    fn ordinal_multiples(&self) -> HashMap<String, f64> {
+        table(&[("centésimo", 100.0), ("centésima", 100.0), ("milésimo", 1000.0), ("milésima", 1000.0)])
+    }
+
+    fn informal_exact(&self) -> HashMap<String, f64> {
+        table(&[("medio", 0.5), ("docena", 12.0)])
+    }
+
+    fn informals_multiplyable(&self) -> HashMap<String, f64> {
+        table(&[("docenas", 12.0)])
+    }
+
+    fn ands(&self) -> Vec<String> {
+        words(&["y"])
+    }
+
+    fn points(&self) -> Vec<String> {
+        words(&["coma", "punto"])
+    }
+
+    fn negatives(&self) -> Vec<String> {
+        words(&["menos", "negativo"])
+    }
+
+    fn complex_signs(&self) -> HashMap<String, f64> {
+        table(&[("más", 1.0), ("mas", 1.0), ("menos", -1.0)])
+    }
+
+    /// `1º`, `1ª`
+    fn ordinal_suffixes(&self) -> Vec<String> {
+        words(&["º", "ª"])
+    }
+
+    fn and_after_tens(&self) -> bool {
+        true
+    }
+
+    fn long_scale(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,169 @@
+use std::collections::HashMap;
+
+use super::{table, words, LanguagePack};
+
+pub struct French;
+
+impl LanguagePack for French {
+    fn code(&self) -> &str {
+        "fr"
+    }
+
+    fn ones(&self) -> HashMap<String, f64> {
+        table(&[
+            ("zéro", 0.0),
+            ("zero", 0.0),
+            ("un", 1.0),
+            ("une", 1.0),
+            ("deux", 2.0),
+            ("trois", 3.0),
+            ("quatre", 4.0),
+            ("cinq", 5.0),
+            ("six", 6.0),
+            ("sept", 7.0),
+            ("huit", 8.0),
+            ("neuf", 9.0),
+        ])
+    }
+
+    /// `dix-sept` to `dix-neuf` are `dix` followed by the ones.
+    fn teens_and_ten(&self) -> HashMap<String, f64> {
+        table(&[
+            ("dix", 10.0),
+            ("onze", 11.0),
+            ("douze", 12.0),
+            ("treize", 13.0),
+            ("quatorze", 14.0),
+            ("quinze", 15.0),
+            ("seize", 16.0),
+        ])
+    }
+
+    /// `septante`, `huitante` and `nonante` are Belgian and Swiss.
+    fn tens(&self) -> HashMap<String, f64> {
+        table(&[
+            ("vingt", 20.0),
+            ("trente", 30.0),
+            ("quarante", 40.0),
+            ("cinquante", 50.0),
+            ("soixante", 60.0),
+            ("septante", 70.0),
+            ("huitante", 80.0),
+            ("octante", 80.0),
+            ("nonante", 90.0),
+        ])
+    }
+
+    /// `vingt` multiplies like `cent`: `quatre-vingts`, `cent quatre-vingt-dix`.
+    fn hundreds(&self) -> HashMap<String, f64> {
+        table(&[("cent", 100.0), ("cents", 100.0), ("vingt", 20.0), ("vingts", 20.0)])
+    }
+
+    fn multiples(&self) -> HashMap<String, f64> {
+        table(&[
+            ("mille", 1000.0),
+            ("million", 1_000_000.0),
+            ("millions", 1_000_000.0),
+            ("milliard", 1_000_000_000.0),
+            ("milliards", 1_000_000_000.0),
+            ("billion", 1_000_000_000_000.0),
+            ("billions", 1_000_000_000_000.0),
+        ])
+    }
+
+    fn ordinal_ones(&self) -> HashMap<String, f64> {
+        table(&[
+            ("premier", 1.0),
+            ("première", 1.0),
+            ("deuxième", 2.0),
+            ("second", 2.0),
+            ("seconde", 2.0),
+            ("troisième", 3.0),
+            ("quatrième", 4.0),
+            ("cinquième", 5.0),
+            ("sixième", 6.0),
+            ("septième", 7.0),
+            ("huitième", 8.0),
+            ("neuvième", 9.0),
+        ])
+    }
+
+    fn ordinal_teens_and_ten(&self) -> HashMap<String, f64> {
+        table(&[
+            ("dixième", 10.0),
+            ("onzième", 11.0),
+            ("douzième", 12.0),
+            ("treizième", 13.0),
+            ("quatorzième", 14.0),
+            ("quinzième", 15.0),
+            ("seizième", 16.0),
+        ])
+    }
+
+    fn ordinal_tens(&self) -> HashMap<String, f64> {
+        table(&[
+            ("vingtième", 20.0),
+            ("trentième", 30.0),
+            ("quarantième", 40.0),
+            ("cinquantième", 50.0),
+            ("soixantième", 60.0),
+        ])
+    }
+
+    fn ordinal_multiples(&self) -> HashMap<String, f64> {
+        table(&[
+            ("centième", 100.0),
+            ("millième", 1000.0),
+            ("millionième", 1_000_000.0),
+            ("milliardième", 1_000_000_000.0),
+        ])
+    }
+
+    fn informal_exact(&self) -> HashMap<String, f64> {
+        table(&[("demi", 0.5), ("quart", 0.25), ("douzaine", 12.0)])
+    }
+
+    fn informals_multiplyable(&self) -> HashMap<String, f64> {
+        table(&[("quarts", 0.25), ("douzaines", 12.0)])
+    }
+
+    fn ands(&self) -> Vec<String> {
+        words(&["et"])
+    }
+
+    fn points(&self) -> Vec<String> {
+        words(&["virgule", "point"])
+    }
+
+    fn negatives(&self) -> Vec<String> {
+        words(&["moins", "négatif", "negatif"])
+    }
+
+    fn complex_signs(&self) -> HashMap<String, f64> {
+        table(&[("plus", 1.0), ("moins", -1.0)])
+    }
+
+    fn ordinal_suffixes(&self) -> Vec<String> {
+        words(&["er", "re", "ère", "e", "ème", "eme", "ième"])
+    }
+
+    /// `1er`, `1re` and `2e`, `21e`, `3ème`.
+    fn is_ordinal_suffix(&self, number: u64, suffix: &str) -> bool {
+        match number {
+            1 => ["er", "re", "ère"].contains(&suffix),
+            _ => ["e", "ème", "eme", "ième"].contains(&suffix),
+        }
+    }
+
+    fn and_after_tens(&self) -> bool {
+        true
+    }
+
+    fn additive_teens(&self) -> bool {
+        true
+    }
+
+    fn long_scale(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,175 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+mod en;
+mod es;
+mod fr;
+mod de;
+mod vocabulary;
+
+pub use en::English;
+pub use es::Spanish;
+pub use fr::French;
+pub use de::German;
+pub use vocabulary::{Vocabulary, VocabularyError};
+
+/// The vocabulary and compounding rules of one language.
+///
+/// `Data` reads every number word through the pack picked by `Config::language`,
+/// the tables hold lowercase words. Numerals, SI prefixes and the `.` point are
+/// shared by all languages and stay in `Data`.
+pub trait LanguagePack: Send + Sync {
+    /// ISO 639-1 code, eg: `en`.
+    fn code(&self) -> &str;
+
+    /// `zero` to `nine`, with their gendered and inflected forms: `un`, `une`, `eins`.
+    fn ones(&self) -> HashMap<String, f64>;
+
+    fn teens_and_ten(&self) -> HashMap<String, f64>;
+
+    fn tens(&self) -> HashMap<String, f64>;
+
+    /// Words multiplying the smaller number before them: `two hundred`, `quatre vingts`.
+    /// Written hundreds like `doscientos` go here too.
+    fn hundreds(&self) -> HashMap<String, f64>;
+
+    /// `thousand` and up, each closes the group of words before it.
+    fn multiples(&self) -> HashMap<String, f64>;
+
+    fn ordinal_ones(&self) -> HashMap<String, f64> {
+        HashMap::new()
+    }
+
+    fn ordinal_teens_and_ten(&self) -> HashMap<String, f64> {
+        HashMap::new()
+    }
+
+    fn ordinal_tens(&self) -> HashMap<String, f64> {
+        HashMap::new()
+    }
+
+    fn ordinal_multiples(&self) -> HashMap<String, f64> {
+        HashMap::new()
+    }
+
+    fn informal_exact(&self) -> HashMap<String, f64> {
+        HashMap::new()
+    }
+
+    fn informals_multiplyable(&self) -> HashMap<String, f64> {
+        HashMap::new()
+    }
+
+    /// The article read as one: `a hundred`.
+    fn a(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    fn ands(&self) -> Vec<String>;
+
+    /// The decimal point words, the first one is used for spelling.
+    fn points(&self) -> Vec<String>;
+
+    /// The negative words, the first one is used for spelling.
+    fn negatives(&self) -> Vec<String>;
+
+    /// The words between the parts of a complex number, eg: `plus` -> 1.
+    fn complex_signs(&self) -> HashMap<String, f64> {
+        HashMap::new()
+    }
+
+    /// The zero of years like `nineteen oh five`.
+    fn year_zero(&self) -> Option<String> {
+        None
+    }
+
+    fn ordinal_suffixes(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Whether a numeral ending in `number` takes `suffix`, eg: `1st` but not `1th`.
+    fn is_ordinal_suffix(&self, _number: u64, suffix: &str) -> bool {
+        self.ordinal_suffixes().iter().any(|s| s == suffix)
+    }
+
+    /// Whether numbers are written as one word: `einundzwanzig`.
+    fn splits_compounds(&self) -> bool {
+        false
+    }
+
+    /// Tens joined to the ones by an `and`: `treinta y dos`, `vingt et un`.
+    fn and_after_tens(&self) -> bool {
+        false
+    }
+
+    /// Ones before the tens: `ein und zwanzig`.
+    fn ones_before_tens(&self) -> bool {
+        false
+    }
+
+    /// Teens and ones added to the tens before them: `soixante dix`, `dix sept`.
+    fn additive_teens(&self) -> bool {
+        false
+    }
+
+    /// `billion` and up are written in the long scale: `billion` is 10^12, `milliard` 10^9.
+    fn long_scale(&self) -> bool {
+        false
+    }
+}
+
+/// The pack for an ISO 639-1 code, English for codes without one.
+pub fn language_pack(code: &str) -> Arc<dyn LanguagePack> {
+    builtin_pack(code).unwrap_or_else(|| {
+        log::warn!("no number vocabulary for `{code}`, falling back to English");
+        Arc::new(English)
+    })
+}
+
+/// The pack shipped with the crate for an ISO 639-1 code.
+pub fn builtin_pack(code: &str) -> Option<Arc<dyn LanguagePack>> {
+    match code.to_lowercase().as_str() {
+        "en" => Some(Arc::new(English)),
+        "es" => Some(Arc::new(Spanish)),
+        "fr" => Some(Arc::new(French)),
+        "de" => Some(Arc::new(German)),
+        _ => None,
+    }
+}
+
+fn table(entries: &[(&str, f64)]) -> HashMap<String, f64> {
+    entries.iter().map(|(k, v)| (k.to_string(), *v)).collect()
+}
+
+fn words(entries: &[&str]) -> Vec<String> {
+    entries.iter().map(|w| w.to_string()).collect()
+}
+
+/// Splits a number written as one word into the words of `vocabulary`:
+/// `zweihundertdreiundvierzig` -> [zwei, hundert, drei, und, vierzig].
+pub(crate) fn split_compound(word: &str, vocabulary: &[String], ands: &[String]) -> Vec<String> {
+    if vocabulary.iter().any(|v| v == word) {
+        return vec![word.to_string()];
+    }
+    match segment(word, vocabulary) {
+        // `und` alone or at either end isn't a number
+        Some(parts) if parts.len() > 1 && !ands.contains(&parts[0]) && !ands.contains(&parts[parts.len() - 1]) => parts,
+        _ => vec![word.to_string()],
+    }
+}
+
+/// Splits `word` into words of `vocabulary`, longest first, `None` if it isn't made of them.
+fn segment(word: &str, vocabulary: &[String]) -> Option<Vec<String>> {
+    if word.is_empty() {
+        return Some(Vec::new());
+    }
+    let mut candidates: Vec<&String> = vocabulary.iter().filter(|v| word.starts_with(v.as_str())).collect();
+    candidates.sort_by_key(|v| std::cmp::Reverse(v.len()));
+    for candidate in candidates {
+        if let Some(mut rest) = segment(&word[candidate.len()..], vocabulary) {
+            rest.insert(0, candidate.clone());
+            return Some(rest);
+        }
+    }
+    None
+}
@@ -0,0 +1,413 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
+use std::path::Path;
+
+use serde::de::{Deserializer, MapAccess, Visitor};
+use serde::{Deserialize, Serialize};
+
+use super::{builtin_pack, LanguagePack};
+
+/// Why a vocabulary file was rejected.
+#[derive(Debug, thiserror::Error)]
+pub enum VocabularyError {
+    #[error("could not read the vocabulary: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("malformed vocabulary: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("no built-in vocabulary `{0}` to extend")]
+    UnknownLanguage(String),
+    #[error("`{word}` appears twice in `{table}`")]
+    Duplicate { table: String, word: String },
+    #[error("`{word}` means different things in `{first}` and `{second}`")]
+    Conflict { word: String, first: String, second: String },
+    #[error("`{0}` needs at least one word")]
+    Empty(String),
+}
+
+/// A [`LanguagePack`] read from JSON, so number words can be shipped without recompiling.
+///
+/// The file has the tables of the trait as objects of words to values and the word lists
+/// as arrays, every field is optional:
+///
+/// ```json
+/// {"extends": "en", "multiples": {"lakh": 100000, "crore": 10000000}}
+/// ```
+///
+/// With `extends` the entries are added to a built-in pack, an entry of the file replaces
+/// the same word of the same table. Files are validated: a word can't be listed twice in
+/// a table nor mean two things, eg: `one` in `ones` and in `tens`.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct Vocabulary {
+    pub code: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extends: Option<String>,
+    pub ones: BTreeMap<String, f64>,
+    pub teens_and_ten: BTreeMap<String, f64>,
+    pub tens: BTreeMap<String, f64>,
+    pub hundreds: BTreeMap<String, f64>,
+    pub multiples: BTreeMap<String, f64>,
+    pub ordinal_ones: BTreeMap<String, f64>,
+    pub ordinal_teens_and_ten: BTreeMap<String, f64>,
+    pub ordinal_tens: BTreeMap<String, f64>,
+    pub ordinal_multiples: BTreeMap<String, f64>,
+    pub informal_exact: BTreeMap<String, f64>,
+    pub informals_multiplyable: BTreeMap<String, f64>,
+    pub complex_signs: BTreeMap<String, f64>,
+    pub a: Vec<String>,
+    pub ands: Vec<String>,
+    pub points: Vec<String>,
+    pub negatives: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub year_zero: Option<String>,
+    pub ordinal_suffixes: Vec<String>,
+    pub and_after_tens: bool,
+    pub ones_before_tens: bool,
+    pub additive_teens: bool,
+    pub splits_compounds: bool,
+    pub long_scale: bool,
+}
+
+impl Vocabulary {
+    /// Copies the tables of a pack, eg: to write them out as a template with [`Vocabulary::to_json`].
+    pub fn from_pack(pack: &dyn LanguagePack) -> Self {
+        let sorted = |table: HashMap<String, f64>| table.into_iter().collect::<BTreeMap<_, _>>();
+        Vocabulary {
+            code: pack.code().to_string(),
+            extends: None,
+            ones: sorted(pack.ones()),
+            teens_and_ten: sorted(pack.teens_and_ten()),
+            tens: sorted(pack.tens()),
+            hundreds: sorted(pack.hundreds()),
+            multiples: sorted(pack.multiples()),
+            ordinal_ones: sorted(pack.ordinal_ones()),
+            ordinal_teens_and_ten: sorted(pack.ordinal_teens_and_ten()),
+            ordinal_tens: sorted(pack.ordinal_tens()),
+            ordinal_multiples: sorted(pack.ordinal_multiples()),
+            informal_exact: sorted(pack.informal_exact()),
+            informals_multiplyable: sorted(pack.informals_multiplyable()),
+            complex_signs: sorted(pack.complex_signs()),
+            a: pack.a(),
+            ands: pack.ands(),
+            points: pack.points(),
+            negatives: pack.negatives(),
+            year_zero: pack.year_zero(),
+            ordinal_suffixes: pack.ordinal_suffixes(),
+            and_after_tens: pack.and_after_tens(),
+            ones_before_tens: pack.ones_before_tens(),
+            additive_teens: pack.additive_teens(),
+            splits_compounds: pack.splits_compounds(),
+            long_scale: pack.long_scale(),
+        }
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, VocabularyError> {
+        let raw: RawVocabulary = serde_json::from_str(json)?;
+        let mut vocabulary = match &raw.extends {
+            Some(code) => {
+                let base = builtin_pack(code).ok_or_else(|| VocabularyError::UnknownLanguage(code.clone()))?;
+                Vocabulary::from_pack(base.as_ref())
+            }
+            None => Vocabulary::default(),
+        };
+        vocabulary.extends = raw.extends;
+        if let Some(code) = raw.code {
+            vocabulary.code = code;
+        }
+
+        let tables = [
+            ("ones", raw.ones, &mut vocabulary.ones),
+            ("teens_and_ten", raw.teens_and_ten, &mut vocabulary.teens_and_ten),
+            ("tens", raw.tens, &mut vocabulary.tens),
+            ("hundreds", raw.hundreds, &mut vocabulary.hundreds),
+            ("multiples", raw.multiples, &mut vocabulary.multiples),
+            ("ordinal_ones", raw.ordinal_ones, &mut vocabulary.ordinal_ones),
+            ("ordinal_teens_and_ten", raw.ordinal_teens_and_ten, &mut vocabulary.ordinal_teens_and_ten),
+            ("ordinal_tens", raw.ordinal_tens, &mut vocabulary.ordinal_tens),
+            ("ordinal_multiples", raw.ordinal_multiples, &mut vocabulary.ordinal_multiples),
+            ("informal_exact", raw.informal_exact, &mut vocabulary.informal_exact),
+            ("informals_multiplyable", raw.informals_multiplyable, &mut vocabulary.informals_multiplyable),
+            ("complex_signs", raw.complex_signs, &mut vocabulary.complex_signs),
+        ];
+        for (name, entries, table) in tables {
+            let mut seen = HashSet::new();
+            for (word, value) in entries.0 {
+                let word = word.to_lowercase();
+                if !seen.insert(word.clone()) {
+                    return Err(VocabularyError::Duplicate { table: name.to_string(), word });
+                }
+                table.insert(word, value);
+            }
+        }
+
+        let lists = [
+            ("a", raw.a, &mut vocabulary.a),
+            ("ands", raw.ands, &mut vocabulary.ands),
+            ("points", raw.points, &mut vocabulary.points),
+            ("negatives", raw.negatives, &mut vocabulary.negatives),
+            ("ordinal_suffixes", raw.ordinal_suffixes, &mut vocabulary.ordinal_suffixes),
+        ];
+        for (name, words, list) in lists {
+            let mut seen = HashSet::new();
+            for word in words {
+                let word = word.to_lowercase();
+                if !seen.insert(word.clone()) {
+                    return Err(VocabularyError::Duplicate { table: name.to_string(), word });
+                }
+                if !list.contains(&word) {
+                    list.push(word);
+                }
+            }
+        }
+
+        vocabulary.year_zero = raw.year_zero.or(vocabulary.year_zero);
+        let flags = [
+            (raw.and_after_tens, &mut vocabulary.and_after_tens),
+            (raw.ones_before_tens, &mut vocabulary.ones_before_tens),
+            (raw.additive_teens, &mut vocabulary.additive_teens),
+            (raw.splits_compounds, &mut vocabulary.splits_compounds),
+            (raw.long_scale, &mut vocabulary.long_scale),
+        ];
+        for (flag, value) in flags {
+            if let Some(flag) = flag {
+                *value = flag;
+            }
+        }
+        vocabulary.validate()?;
+        Ok(vocabulary)
+    }
+
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, VocabularyError> {
+        Vocabulary::from_json(&std::fs::read_to_string(path)?)
+    }
+
+    pub fn to_json(&self) -> Result<String, VocabularyError> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Checks that the words needed for spelling are there and that no word means two things.
+    pub fn validate(&self) -> Result<(), VocabularyError> {
+        if self.ones.is_empty() {
+            return Err(VocabularyError::Empty("ones".to_string()));
+        }
+        for (name, list) in [("ands", &self.ands), ("negatives", &self.negatives)] {
+            if list.is_empty() {
+                return Err(VocabularyError::Empty(name.to_string()));
+            }
+        }
+
+        // the table each word was first seen in, with its value
+        let mut meanings: HashMap<&str, (&str, Option<f64>)> = HashMap::new();
+        for (name, table) in self.value_tables() {
+            for (word, value) in table {
+                match meanings.get(word.as_str()) {
+                    Some((first, Some(seen))) if seen != value => {
+                        return Err(VocabularyError::Conflict {
+                            word: word.clone(),
+                            first: first.to_string(),
+                            second: name.to_string(),
+                        });
+                    }
+                    Some(_) => {}
+                    None => {
+                        meanings.insert(word, (name, Some(*value)));
+                    }
+                }
+            }
+        }
+        // `and` and the other connecting words aren't numbers, nor each other
+        for (name, list) in [("a", &self.a), ("ands", &self.ands), ("points", &self.points), ("negatives", &self.negatives)] {
+            for word in list {
+                if let Some((first, _)) = meanings.get(word.as_str()) {
+                    return Err(VocabularyError::Conflict {
+                        word: word.clone(),
+                        first: first.to_string(),
+                        second: name.to_string(),
+                    });
+                }
+                meanings.insert(word, (name, None));
+            }
+        }
+        Ok(())
+    }
+
+    fn value_tables(&self) -> [(&'static str, &BTreeMap<String, f64>); 11] {
+        [
+            ("ones", &self.ones),
+            ("teens_and_ten", &self.teens_and_ten),
+            ("tens", &self.tens),
+            ("hundreds", &self.hundreds),
+            ("multiples", &self.multiples),
+            ("ordinal_ones", &self.ordinal_ones),
+            ("ordinal_teens_and_ten", &self.ordinal_teens_and_ten),
+            ("ordinal_tens", &self.ordinal_tens),
+            ("ordinal_multiples", &self.ordinal_multiples),
+            ("informal_exact", &self.informal_exact),
+            ("informals_multiplyable", &self.informals_multiplyable),
+        ]
+    }
+}
+
+impl LanguagePack for Vocabulary {
+    fn code(&self) -> &str {
+        &self.code
+    }
+
+    fn ones(&self) -> HashMap<String, f64> {
+        self.ones.clone().into_iter().collect()
+    }
+
+    fn teens_and_ten(&self) -> HashMap<String, f64> {
+        self.teens_and_ten.clone().into_iter().collect()
+    }
+
+    fn tens(&self) -> HashMap<String, f64> {
+        self.tens.clone().into_iter().collect()
+    }
+
+    fn hundreds(&self) -> HashMap<String, f64> {
+        self.hundreds.clone().into_iter().collect()
+    }
+
+    fn multiples(&self) -> HashMap<String, f64> {
+        self.multiples.clone().into_iter().collect()
+    }
+
+    fn ordinal_ones(&self) -> HashMap<String, f64> {
+        self.ordinal_ones.clone().into_iter().collect()
+    }
+
+    fn ordinal_teens_and_ten(&self) -> HashMap<String, f64> {
+        self.ordinal_teens_and_ten.clone().into_iter().collect()
+    }
+
+    fn ordinal_tens(&self) -> HashMap<String, f64> {
+        self.ordinal_tens.clone().into_iter().collect()
+    }
+
+    fn ordinal_multiples(&self) -> HashMap<String, f64> {
+        self.ordinal_multiples.clone().into_iter().collect()
+    }
+
+    fn informal_exact(&self) -> HashMap<String, f64> {
+        self.informal_exact.clone().into_iter().collect()
+    }
+
+    fn informals_multiplyable(&self) -> HashMap<String, f64> {
+        self.informals_multiplyable.clone().into_iter().collect()
+    }
+
+    fn a(&self) -> Vec<String> {
+        self.a.clone()
+    }
+
+    fn ands(&self) -> Vec<String> {
+        self.ands.clone()
+    }
+
+    fn points(&self) -> Vec<String> {
+        self.points.clone()
+    }
+
+    fn negatives(&self) -> Vec<String> {
+        self.negatives.clone()
+    }
+
+    fn complex_signs(&self) -> HashMap<String, f64> {
+        self.complex_signs.clone().into_iter().collect()
+    }
+
+    fn year_zero(&self) -> Option<String> {
+        self.year_zero.clone()
+    }
+
+    fn ordinal_suffixes(&self) -> Vec<String> {
+        self.ordinal_suffixes.clone()
+    }
+
+    /// The rules of the built-in pack with the same code, any listed suffix otherwise.
+    fn is_ordinal_suffix(&self, number: u64, suffix: &str) -> bool {
+        match builtin_pack(&self.code) {
+            Some(base) if base.ordinal_suffixes().iter().any(|s| s == suffix) => base.is_ordinal_suffix(number, suffix),
+            _ => self.ordinal_suffixes.iter().any(|s| s == suffix),
+        }
+    }
+
+    fn splits_compounds(&self) -> bool {
+        self.splits_compounds
+    }
+
+    fn and_after_tens(&self) -> bool {
+        self.and_after_tens
+    }
+
+    fn ones_before_tens(&self) -> bool {
+        self.ones_before_tens
+    }
+
+    fn additive_teens(&self) -> bool {
+        self.additive_teens
+    }
+
+    fn long_scale(&self) -> bool {
+        self.long_scale
+    }
+}
+
+/// A vocabulary as written, before it's merged into its base and validated.
+#[derive(Deserialize, Default)]
+#[serde(default, deny_unknown_fields)]
+struct RawVocabulary {
+    code: Option<String>,
+    extends: Option<String>,
+    ones: Entries,
+    teens_and_ten: Entries,
+    tens: Entries,
+    hundreds: Entries,
+    multiples: Entries,
+    ordinal_ones: Entries,
+    ordinal_teens_and_ten: Entries,
+    ordinal_tens: Entries,
+    ordinal_multiples: Entries,
+    informal_exact: Entries,
+    informals_multiplyable: Entries,
+    complex_signs: Entries,
+    a: Vec<String>,
+    ands: Vec<String>,
+    points: Vec<String>,
+    negatives: Vec<String>,
+    year_zero: Option<String>,
+    ordinal_suffixes: Vec<String>,
+    and_after_tens: Option<bool>,
+    ones_before_tens: Option<bool>,
+    additive_teens: Option<bool>,
+    splits_compounds: Option<bool>,
+    long_scale: Option<bool>,
+}
+
+/// The entries of a JSON object in order, a map would drop repeated words silently.
+#[derive(Default)]
+struct Entries(Vec<(String, f64)>);
+
+impl<'de> Deserialize<'de> for Entries {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct EntriesVisitor;
+
+        impl<'de> Visitor<'de> for EntriesVisitor {
+            type Value = Entries;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("an object of words to numbers")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Entries, A::Error> {
+                let mut entries = Vec::new();
+                while let Some(entry) = map.next_entry::<String, f64>()? {
+                    entries.push(entry);
+                }
+                Ok(Entries(entries))
+            }
+        }
+
+        deserializer.deserialize_map(EntriesVisitor)
+    }
+}
@@ -0,0 +1,78 @@
+use crate::parsers::number::{parse_number, NumberValue};
+use crate::parsers::number::utils::string_to_num;
+
+/// Type suffixes of integer literals in Rust and C: `10u32`, `0xFFu8`, `100UL`, `7LL`.
+pub const INTEGER_SUFFIXES: [&str; 19] = [
+    "u128", "i128", "usize", "isize", "u64", "i64", "u32", "i32", "u16", "i16", "u8", "i8",
+    "ull", "llu", "ul", "lu", "ll", "u", "l",
+];
+
+/// Type suffixes of float literals in Rust, C and Java: `1.5f`, `2.0f64`, `3d`.
+pub const FLOAT_SUFFIXES: [&str; 4] = ["f32", "f64", "f", "d"];
+
+/// The pattern of the integer suffixes in any case, with the `_` Rust allows before them.
+pub(crate) fn integer_suffix_pattern() -> String {
+    format!("(?:_?(?i:{}))", INTEGER_SUFFIXES.join("|"))
+}
+
+/// The pattern of the float suffixes in any case.
+pub(crate) fn float_suffix_pattern() -> String {
+    format!("(?:_?(?i:{}))", FLOAT_SUFFIXES.join("|"))
+}
+
+/// `text` without its type suffix, and the suffix: `10u32` -> (`10`, `u32`), `0xFF_u8` ->
+/// (`0xFF`, `u8`). Float suffixes are only taken from decimal literals, `0x1f` is 31.
+pub fn strip_type_suffix(text: &str) -> (&str, Option<&str>) {
+    let hex = text.get(..2).is_some_and(|prefix| prefix.eq_ignore_ascii_case("0x"));
+    let floats = if hex { &[][..] } else { &FLOAT_SUFFIXES[..] };
+    let lower = text.to_lowercase();
+    let suffix = INTEGER_SUFFIXES
+        .iter()
+        .chain(floats)
+        .filter(|suffix| lower.ends_with(*suffix))
+        .max_by_key(|suffix| suffix.len());
+    match suffix {
+        Some(suffix) => {
+            let (digits, suffix) = text.split_at(text.len() - suffix.len());
+            let digits = digits.strip_suffix('_').unwrap_or(digits);
+            if digits.ends_with(|c: char| c.is_ascii_hexdigit() || c == '.') {
+                (digits, Some(suffix))
+            } else {
+                (text, None)
+            }
+        }
+        None => (text, None),
+    }
+}
+
+/// Reads a literal of source code: `0xFF_FF`, `0B1010`, `0755` (C octal), `10u32`, `1.5f`,
+/// `1_000_000`, `0xFFFF_FFFF_FFFF_FFFF` (beyond `i64`).
+///
+/// Prefixed literals and C octals keep their radix, see [`parse_number`]. `None` when `text`
+/// isn't a literal: `09` isn't an octal, `0x1G` isn't hexadecimal.
+pub fn parse_literal(text: &str) -> Option<NumberValue> {
+    let (digits, suffix) = strip_type_suffix(text.trim());
+    let digits = digits.replace('_', "");
+    if !digits.starts_with(|c: char| c.is_ascii_digit()) {
+        return None;
+    }
+    let float = suffix.is_some_and(|suffix| FLOAT_SUFFIXES.contains(&suffix.to_lowercase().as_str()));
+    // `0755`, not `0` nor `0.5`
+    if !float && digits.len() > 1 && digits.starts_with('0') && digits.chars().all(|c| c.is_ascii_digit()) {
+        return parse_number(&format!("0o{}", &digits[1..])).ok();
+    }
+    if float {
+        let value: f64 = digits.parse().ok()?;
+        return Some(NumberValue::from(value));
+    }
+    parse_number(&digits).ok().or_else(|| string_to_num(digits))
+}
+
+/// Whether the number at `span` of `text` is a part of a date or a time, like the `05` of
+/// `2024-01-05` or `12:05`, which stays decimal with `Config::code_literals`.
+pub(crate) fn in_date_or_time(text: &str, span: (usize, usize)) -> bool {
+    let separator = |c: char| matches!(c, '-' | '/' | ':' | '.');
+    let before = text.get(..span.0).and_then(|before| before.chars().next_back());
+    let mut after = text.get(span.1..).unwrap_or_default().chars();
+    before.is_some_and(separator) || (after.next().is_some_and(separator) && after.next().is_some_and(|c| c.is_ascii_digit()))
+}
@@ -0,0 +1,159 @@
+use crate::parsers::number::Data;
+use crate::parsers::number::scripts::ascii_digits;
+
+const NBSP: char = '\u{A0}';
+const NARROW_NBSP: char = '\u{202F}';
+const THIN_SPACE: char = '\u{2009}';
+
+/// Grouping characters read by [`NumberLocale::Auto`]. A plain space is left out, `5 100`
+/// is as often two numbers as one.
+const AUTO_GROUPING: [char; 8] = [',', '.', '\'', '’', '_', NBSP, NARROW_NBSP, THIN_SPACE];
+
+/// How the digits left of the decimal mark are grouped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Grouping {
+    /// `1,234,567`
+    Thousands,
+    /// `12,34,567`: the last three digits, then pairs, as in `1,00,000` (one lakh).
+    Indian,
+}
+
+/// The decimal mark and the digit grouping of numerals written the way of one locale.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Separators {
+    /// `.` in `3.14`, `,` in `3,14`.
+    pub decimal: char,
+    /// Any of these may group the digits, one per numeral: `1,234` or `1'234`, not `1,234'567`.
+    pub grouping: Vec<char>,
+    pub pattern: Grouping,
+}
+
+impl Separators {
+    /// `1,234,567.89`, also grouped with `'` or `_`. The default.
+    pub fn english() -> Self {
+        Separators { decimal: '.', grouping: vec![',', '\'', '_'], pattern: Grouping::Thousands }
+    }
+
+    /// `1.234.567,89`, as in German, Spanish or Italian.
+    pub fn german() -> Self {
+        Separators { decimal: ',', grouping: vec!['.', NBSP, NARROW_NBSP], pattern: Grouping::Thousands }
+    }
+
+    /// `1 234 567,89` with a space, a no-break space or a thin space, as in French or Russian.
+    pub fn french() -> Self {
+        Separators { decimal: ',', grouping: vec![' ', NBSP, NARROW_NBSP, THIN_SPACE], pattern: Grouping::Thousands }
+    }
+
+    /// `1'234'567.89`, as in Switzerland.
+    pub fn swiss() -> Self {
+        Separators { decimal: '.', grouping: vec!['\'', '’'], pattern: Grouping::Thousands }
+    }
+
+    /// `12,34,567.89`, as in India.
+    pub fn indian() -> Self {
+        Separators { decimal: '.', grouping: vec![','], pattern: Grouping::Indian }
+    }
+}
+
+impl Default for Separators {
+    fn default() -> Self {
+        Separators::english()
+    }
+}
+
+/// How numerals written with digits mark decimals and group digits, see `Config::number_locale`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum NumberLocale {
+    /// Every numeral follows the same conventions, others aren't read: with
+    /// [`Separators::german`], `1.234,56` is 1234.56 and `3.5` is two numbers.
+    Fixed(Separators),
+    /// Each numeral is read by its own shape: `1.234,56` and `1,234.56` are both 1234.56,
+    /// `1.234.567` is 1234567 and `3,5` is 3.5. A lone `.` or `,` before exactly three
+    /// digits is read the English way: `1.234` is 1.234 and `1,234` is 1234.
+    Auto,
+}
+
+impl Default for NumberLocale {
+    fn default() -> Self {
+        NumberLocale::Fixed(Separators::default())
+    }
+}
+
+impl NumberLocale {
+    /// The characters that may group digits and the pattern of the groups.
+    pub fn grouping(&self) -> Vec<(char, Grouping)> {
+        match self {
+            NumberLocale::Fixed(separators) => separators.grouping.iter().map(|&c| (c, separators.pattern)).collect(),
+            NumberLocale::Auto => {
+                let mut grouping: Vec<_> = AUTO_GROUPING.iter().map(|&c| (c, Grouping::Thousands)).collect();
+                grouping.push((',', Grouping::Indian));
+                grouping
+            }
+        }
+    }
+
+    /// The characters that may mark decimals.
+    pub fn decimals(&self) -> Vec<char> {
+        match self {
+            NumberLocale::Fixed(separators) => vec![separators.decimal],
+            NumberLocale::Auto => vec!['.', ','],
+        }
+    }
+
+    /// The decimal mark of `numeral`, a numeral written the way of this locale.
+    fn decimal_of(&self, numeral: &str) -> Option<char> {
+        let NumberLocale::Auto = self else {
+            return self.decimals().first().copied();
+        };
+        let mantissa = numeral.split(['e', 'E']).next().unwrap_or_default();
+        let marks: Vec<(usize, char)> = mantissa.char_indices().filter(|(_, c)| matches!(c, '.' | ',')).collect();
+        let &(i, last) = marks.last()?;
+        // `1.234.567`, `1,00,000`
+        if marks.iter().filter(|(_, c)| *c == last).count() > 1 {
+            return None;
+        }
+        // `1.234,56`
+        if marks.len() > 1 {
+            return Some(last);
+        }
+        let before = mantissa[..i].trim_start_matches(['-', '+']);
+        let after = mantissa[i + 1..].chars().take_while(char::is_ascii_digit).count();
+        // `3,5`, `0,125`, `1 234,567`, then `1.234` but not `1,234`
+        let decimal = after != 3
+            || matches!(before, "" | "0")
+            || !before.chars().all(|c| c.is_ascii_digit())
+            || last == '.';
+        decimal.then_some(last)
+    }
+
+    /// `numeral` written with no grouping and `.` as the decimal mark: `1.234,56` -> `1234.56`.
+    pub fn canonical(&self, numeral: &str) -> String {
+        let decimal = self.decimal_of(numeral);
+        numeral
+            .chars()
+            .filter_map(|c| match c {
+                c if Some(c) == decimal => Some('.'),
+                c if c.is_ascii_digit() || matches!(c, 'e' | 'E' | '-' | '+') => Some(c),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+/// `text` with the numerals written the way of `Config::number_locale` made canonical, see
+/// [`NumberLocale::canonical`]. The conversions read numerals the English way, so `1,5 million`
+/// is read as `1.5 million` with [`Separators::german`]. Digits of other scripts become ASCII.
+pub fn canonical_numerals(text: &str, data: &Data) -> String {
+    let text = &ascii_digits(text);
+    let default = NumberLocale::default();
+    let locale = data.config().number_locale.as_ref().unwrap_or(&default);
+    let mut canonical = String::with_capacity(text.len());
+    let mut last = 0;
+    for numeral in data.regexes().numeral.find_iter(text).filter_map(|m| m.ok()) {
+        canonical.push_str(&text[last..numeral.start()]);
+        canonical.push_str(&locale.canonical(numeral.as_str()));
+        last = numeral.end();
+    }
+    canonical.push_str(&text[last..]);
+    canonical
+}
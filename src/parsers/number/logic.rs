@@ -1,292 +1,285 @@
-# coding: utf-8
-# cython: embedsignature=True
-# cython: language_level=3
-# cython: profile=False
+use crate::parsers::number::{Data, DataHolder};
+use crate::parsers::number::classes::CompStr;
 
-cimport cython
-from functools import lru_cache
-from more_itertools import stagger
-from .classes import (
-    CompStr,
-    ModFloat,
-    ModInt,
-)
-from .data import Data
+/// Decides where one spoken number ends and the next begins.
+///
+/// Every token gets a boolean: `true` when the number carries on into the
+/// next token, `false` when the token ends a number (or isn't part of one),
+/// eg: `two hundred five thousand three` -> one number but
+/// `five three` -> [five, three].
+pub struct Logic {
+    numbers: Vec<String>,
+    data: Data,
+    bool_container: Vec<bool>,
+    prev_multiple: CompStr,
+    prev_point: bool,
+    beginning: bool,
+}
 
+impl Logic {
 
-cdef class Logic:
-    
-    def __cinit__(self, numbers, data):
-        self.numbers = numbers
-        self.config = data.config
-        self.data = data
-        self.bool_container = []
-        self.idx_container = []
-        self.prev_multiple = CompStr("always-big")
-        self.prev_multiple.set_data(self.data)
-        self.prev_point = False
-        self.and_sep = False
-        self.prev_false_truth = True
-        self.beginning = True
-        self.index = 0
-   
-    @cython.wraparound(False) 
-    cdef get_num(self, num_, str string):
-        if int(num_) == num_:
-            modint = ModInt(int(num_))
-            modint.set_data(self.data)
-            modint.string(string)
-            return modint
-        elif isinstance(num_, float):
-            modfloat = ModFloat(num_)
-            modfloat.set_data(self.data)
-            modfloat.string(string)
-            return modfloat
-        raise TypeError("Expected an integer or float got: %s" % num_)
-    
-    @cython.wraparound(False)
-    cdef make_order(self, val):
-        num_ = self.data.ALL_NUMS.get(val)
-        if num_ is None:
-            comp = CompStr(val)
-            comp.set_data(self.data)
-            return comp
-        else:
-            return self.get_num(num_, val)
-            
-    @cython.wraparound(False)
-    cdef add_to_con(self, bint truth, object idx = None):
-        if truth:
-            self.prev_false_truth = False
-            self.beginning = False
-        else:
-            self.prev_multiple = CompStr("always-big")
-            self.prev_multiple.set_data(self.data)
-            self.prev_point = False
-            self.and_sep = False
-            self.prev_false_truth = True
-            self.beginning =True
-        self.bool_container.append(truth)
-        self.idx_container.append((self.index, idx))
-        self.index = idx
-    
-    @cython.wraparound(False)
-    @cython.boundscheck(False)
-    cpdef apply_sequence_logic(self):
-        fillvalue = CompStr("false")
-        fillvalue.set_data(self.data)
-        iterator = stagger(
-            self.numbers,
-            offsets=(-2, -1, 0, 1, 2),
-            longest=True,
-            fillvalue=fillvalue,
-        )
-        cdef int i
-        for i, item in enumerate(iterator):
-            # [pp, p, num, n, nn]
-            if isinstance(item[2], CompStr):
-                # Item is not a number
-                continue
-            next_num = self.make_order(item[3])
-            nnext_num = self.make_order(item[4])
-            prev_num = self.make_order(item[1])
-            pprev = self.make_order(item[0])
-            num = self.make_order(item[2])
-            
-            if not num.is_num_word:
-                # Is num a word (non number)
-                self.add_to_con(False, i)
-                
-            elif str(num) in self.data.ALL_NUMS and \
-                not self.prev_point and \
-                (
-                    (
-                        next_num.is_and and \
-                        nnext_num.informal_exact
-                    ) or \
-                    (
-                        next_num.informal_exact and \
-                        num in (0, 1) or \
-                        next_num.informal_multiplyable and num > 1
-                    )
-                ):
-                self.add_to_con(True)
-            # nothing can come after an ordinal or suffix
-            # eg: twenty third million -> [twenty third, million]
-            elif num.is_ordinal: # or num.is_suffix:
-                self.add_to_con(False, i)
-            # nothing can come after an informal
-            # eg: two halves million -> [two halves, million]
-            elif num.informal_exact or num.informal_multiplyable:
-                
-                self.add_to_con(False, i)
-        
-            elif not self.prev_point and next_num == 0:
-                # if the previous token is not a point and
-                # the next number is zero
-                # eg: two million zero -> [two million, zero]
-                self.add_to_con(False, i)
-            
-            elif num.is_a:
-                # `a` followed by informal exact
-                # a quarter -> [a quarter]
-                if next_num.informal_exact:
-                    self.add_to_con(True)
-                else:
-                    self.add_to_con(False, i)
-            # if the next char is a point and what follows after the point isn't a ones
-            # eg: twenty three point million ->
-            # [twenty three point, million]
-            # the `point` is stripped out in this case when
-            # normalizing
-            elif next_num.is_point and not nnext_num.ones:
-                self.add_to_con(False, i)
-        
-            elif num in self.data.NEGATIVES:
-                # if num is a word that represents negative
-                # eg: negative, minus, neg
-                if (next_num in self.data.ALL_NUMS.values() or next_num.informal_exact) and self.beginning:
-                    self.add_to_con(True)
-                else:
-                    self.add_to_con(False)
-        
-            elif prev_num.hundred and next_num.hundred and not num.multiple:
-                # hundred any hundred ->
-                # [hundred, any, hundred]
-                self.add_to_con(False, i)
-        
-            elif num.multiple and next_num >= num:
-                # thousand thousand ->
-                # [thousand, thousand]
-                # thousand hundred ->
-                # [thousand, hundred]
-                self.add_to_con(False, i)
-        
-            elif num.is_and:
-                self.and_sep = True
-                if (
-                    not prev_num.is_num_word or \
-                    prev_num.is_and or \
-                    prev_num.is_point or \
-                    prev_num.informal_exact
-                    ):
-                    self.add_to_con(False, i)
-                elif (
-                     not self.prev_point and next_num.informal_exact or \
-                     next_num.ones or \
-                     next_num.teens or \
-                     next_num.tens
-                     ):
-                    self.add_to_con(True)
-                elif not self.prev_point and next_num.is_a and nnext_num.informal_exact:
-                    self.add_to_con(True)
-                else:
-                    self.add_to_con(False, i)
-                    self.and_sep = False
+    pub fn new(numbers: Vec<String>, data: Data) -> Self {
+        let prev_multiple = CompStr::always_big(data.clone());
+        Logic {
+            numbers,
+            data,
+            bool_container: Vec::new(),
+            prev_multiple,
+            prev_point: false,
+            beginning: true,
+        }
+    }
 
-            elif num.is_point:
-                # if the num represents a point and the
-                # preceding tokens are ones (0, 1, 3, ..., 9)
-                if next_num.ones:
-                    self.add_to_con(True)
-                    self.prev_point = True
-                else:
-                    self.add_to_con(False, i)
-        
-            elif num >= self.prev_multiple or next_num >= self.prev_multiple:
-                # A number is constructed with decreasing multiple eg: billion -> million -> thousand
-                # if a number in the chain is greater than
-                # the previous multiple in the chain cut the chain
-                # eg: billion million billion ->
-                # [billion million, billion]
-                self.add_to_con(False, i)
-            
-            elif num.ones:
-                if next_num.teens:
-                    self.add_to_con(False, i)
-                elif self.prev_point:
-                    if next_num.ones:
-                        self.add_to_con(True)
-                    elif next_num.multiple:
-                        self.add_to_con(True)
-                    else:
-                        self.add_to_con(False, i)
-                elif pprev.hundred and prev_num.is_and and next_num.hundred:
-                    self.add_to_con(False)
-                elif not self.prev_point and next_num.is_point:
-                    if nnext_num.ones:
-                        self.add_to_con(True)
-                    else:
-                        self.add_to_con(False, i)
-                elif not self.prev_point and next_num.informal_exact and num in (0,1):
-                    self.add_to_con(True)
-                elif not self.prev_point and next_num.informal_multiplyable and num>1:
-                    self.add_to_con(True)
-                elif num == 0:
-                    if next_num.ones or self.prev_point:
-                        self.add_to_con(True)
-                    else:
-                        self.add_to_con(False, i)
-                elif prev_num.tens and next_num.hundred:
-                    self.add_to_con(False)
-                elif next_num.hundred:
-                    self.add_to_con(True)
-                elif next_num.multiple:
-                    self.add_to_con(True)
-                elif next_num.is_and:
-                    self.add_to_con(False, i)
-                else:
-                    self.add_to_con(False, i)
-                
-            elif num.teens:
-                if (
-                    next_num.multiple or
-                    next_num.is_point and nnext_num.ones or \
-                    next_num.informal_multiplyable
-                    ):
-                    self.add_to_con(True)
-                else:
-                    self.add_to_con(False, i)
-                
-            elif num.tens:
-                if (
-                    next_num.ones or \
-                    self.data.ORDINAL_ONES.get(next_num) is not None or \
-                    next_num.multiple or \
-                    next_num.is_point and nnext_num.ones or \
-                    next_num.informal_multiplyable
-                    ):
-                    self.add_to_con(True)
-                else:
-                    self.add_to_con(False, i)
-              
-            elif num.multiple:
-                self.prev_multiple = num
-                if self.prev_point:
-                    self.add_to_con(False, i)
-                elif next_num.is_point:
-                    if nnext_num.ones:
-                        self.add_to_con(True)
-                    else:
-                        self.add_to_con(False, i)
-                elif next_num.is_and or num > next_num:
-                    self.add_to_con(True)
-                elif next_num.informal_multiplyable:
-                    self.add_to_con(True)
-                else:
-                    self.add_to_con(False, i)
-                
-            elif num.hundred:
-                if (
-                    next_num.is_and or \
-                    next_num.is_point or \
-                    next_num.ones or \
-                    next_num.teens or \
-                    next_num.tens or \
-                    next_num.multiple or \
-                    next_num.informal_multiplyable
-                    ):
-                    self.add_to_con(True)
-                else:
-                    self.add_to_con(False, i)
-        return self.bool_container
\ No newline at end of file
+    fn make_order(&self, val: Option<&String>) -> CompStr {
+        let string = val.map_or_else(|| "false".to_string(), |v| v.to_lowercase());
+        let mut comp = CompStr::new(DataHolder {
+            type_: "text",
+            text: Some(string.clone()),
+            int: None,
+            float: None,
+        }, self.data.clone());
+        comp.string(string);
+        comp
+    }
+
+    fn add_to_con(&mut self, truth: bool) {
+        if truth {
+            self.beginning = false;
+        } else {
+            self.prev_multiple = CompStr::always_big(self.data.clone());
+            self.prev_point = false;
+            self.beginning = true;
+        }
+        self.bool_container.push(truth);
+    }
+
+    pub fn apply_sequence_logic(&mut self) -> Vec<bool> {
+        let orders: Vec<CompStr> = (0..self.numbers.len())
+            .map(|i| self.make_order(self.numbers.get(i)))
+            .collect();
+        let fillvalue = self.make_order(None);
+        let get = |i: isize| -> &CompStr {
+            if i < 0 {
+                return &fillvalue;
+            }
+            orders.get(i as usize).unwrap_or(&fillvalue)
+        };
+        for i in 0..orders.len() as isize {
+            // [pp, p, num, n, nn, nnn]
+            let window = [get(i - 2), get(i - 1), get(i), get(i + 1), get(i + 2), get(i + 3)]
+                .map(CompStr::clone);
+            let truth = self.sequence_truth(window);
+            self.add_to_con(truth);
+        }
+        std::mem::take(&mut self.bool_container)
+    }
+
+    fn sequence_truth(&mut self, window: [CompStr; 6]) -> bool {
+        let [mut pprev, mut prev_num, mut num, mut next_num, mut nnext_num, nnnext_num] = window;
+
+        if !num.is_num_word() {
+            // Is num a word (non number)
+            return false;
+        }
+        // numerals and words are joined by the `Merger` flags
+        // eg: 3 point 2 -> [3, 2], twenty 5 -> [twenty, 5]
+        let config = self.data.config();
+        let merge = config.merge.unwrap_or(true);
+        let (merge_points, merge_implied) = (merge && config.merge_points.unwrap_or(false), merge && config.merge_implied.unwrap_or(false));
+        if !merge_points {
+            let point_between = (num.is_point() && (is_numeral(&prev_num) || is_numeral(&next_num)))
+                || (next_num.is_point() && (is_numeral(&num) || is_numeral(&nnext_num)))
+                || (self.prev_point && (is_numeral(&num) || is_numeral(&next_num)));
+            if point_between {
+                return false;
+            }
+        }
+        if !(merge_implied || num.is_point() || self.prev_point) {
+            let word = num.ones() || num.teens() || num.tens() || num.hundred() || is_multiple(&mut num) || num.is_and();
+            let added = (word && !is_numeral(&num) && is_numeral(&next_num))
+                || (is_numeral(&num) && (next_num.ones() || next_num.teens() || next_num.tens()));
+            if added {
+                return false;
+            }
+        }
+        if num.value().is_some() && !self.prev_point && (
+            // one and a half -> [one and a half]
+            (next_num.is_and() && (nnext_num.informal_exact() || nnext_num.is_a()))
+            // one and three sixteenths -> [one and three sixteenths]
+            || (next_num.is_and() && nnext_num.value().is_some() && nnnext_num.is_plural_denominator())
+            // five eighths, one eighth -> [five eighths], [one eighth]
+            || (next_num.is_plural_denominator() || (next_num.is_denominator() && num == 1.0))
+            || (next_num.informal_exact() && (num == 0.0 || num == 1.0))
+            || (next_num.informal_multiplyable() && num > 1.0)
+        ) {
+            return true;
+        }
+        // nothing can come after an ordinal or suffix
+        // eg: twenty third million -> [twenty third, million]
+        if num.is_ordinal() {
+            return false;
+        }
+        // nothing can come after an informal
+        // eg: two halves million -> [two halves, million]
+        if num.informal_exact() || num.informal_multiplyable() {
+            return false;
+        }
+        if !self.prev_point && next_num == 0.0 {
+            // if the previous token is not a point and
+            // the next number is zero
+            // eg: two million zero -> [two million, zero]
+            return false;
+        }
+        if num.is_a() {
+            // `a` followed by informal exact or a denominator
+            // a quarter -> [a quarter], a fifth -> [a fifth]
+            return next_num.informal_exact() || (next_num.is_denominator() && !next_num.is_plural_denominator());
+        }
+        // if the next char is a point and what follows after the point isn't a ones
+        // eg: twenty three point million ->
+        // [twenty three point, million]
+        // the `point` is stripped out in this case when
+        // normalizing
+        if next_num.is_point() && !nnext_num.ones() {
+            return false;
+        }
+        if self.data.negatives().contains(&num.val.text.clone().unwrap_or_default()) {
+            // if num is a word that represents negative
+            // eg: negative, minus, neg
+            return (next_num.value().is_some() || next_num.informal_exact()) && self.beginning;
+        }
+        if prev_num.hundred() && next_num.hundred() && !is_multiple(&mut num) && next_num >= prev_num {
+            // hundred any hundred ->
+            // [hundred, any, hundred]
+            // but cent quatre vingts -> [cent quatre vingts]
+            return false;
+        }
+        if is_multiple(&mut num) && next_num >= num {
+            // thousand thousand ->
+            // [thousand, thousand]
+            // thousand hundred ->
+            // [thousand, hundred]
+            return false;
+        }
+        if num.is_and() {
+            if !prev_num.is_num_word()
+                || prev_num.is_and()
+                || prev_num.is_point()
+                || prev_num.informal_exact() {
+                return false;
+            }
+            if (!self.prev_point && next_num.informal_exact())
+                || next_num.ones()
+                || next_num.teens()
+                || next_num.tens() {
+                return true;
+            }
+            // one and a third -> [one and a third]
+            return !self.prev_point
+                && next_num.is_a()
+                && (nnext_num.informal_exact() || (nnext_num.is_denominator() && !nnext_num.is_plural_denominator()));
+        }
+        if num.is_point() {
+            // if the num represents a point and the
+            // preceding tokens are ones (0, 1, 3, ..., 9)
+            if next_num.ones() {
+                self.prev_point = true;
+                return true;
+            }
+            return false;
+        }
+        if num >= self.prev_multiple || next_num >= self.prev_multiple {
+            // A number is constructed with decreasing multiple eg: billion -> million -> thousand
+            // if a number in the chain is greater than
+            // the previous multiple in the chain cut the chain
+            // eg: billion million billion ->
+            // [billion million, billion]
+            return false;
+        }
+        if num.ones() {
+            if next_num.teens() {
+                return false;
+            }
+            if self.prev_point {
+                return next_num.ones() || is_multiple(&mut next_num);
+            }
+            // ein und zwanzig -> [ein und zwanzig]
+            if self.data.language.ones_before_tens() && next_num.is_and() && nnext_num.tens() {
+                return true;
+            }
+            if pprev.hundred() && prev_num.is_and() && next_num.hundred() {
+                return false;
+            }
+            if next_num.is_point() {
+                return nnext_num.ones();
+            }
+            if next_num.informal_exact() && (num == 0.0 || num == 1.0) {
+                return true;
+            }
+            if next_num.informal_multiplyable() && num > 1.0 {
+                return true;
+            }
+            if num == 0.0 {
+                return next_num.ones();
+            }
+            if prev_num.tens() && next_num.hundred() {
+                return false;
+            }
+            return next_num.hundred() || is_multiple(&mut next_num);
+        }
+        if num.teens() {
+            // fifteen hundred -> [fifteen hundred]
+            return next_num.hundred()
+                || is_multiple(&mut next_num)
+                || (next_num.is_point() && nnext_num.ones())
+                || next_num.informal_multiplyable()
+                // dix sept -> [dix sept]
+                || (self.data.language.additive_teens() && next_num.ones());
+        }
+        if num.tens() {
+            let next_text = next_num.val.text.clone().unwrap_or_default();
+            return next_num.ones()
+                || self.data.ordinal_ones().contains_key(&next_text)
+                || is_multiple(&mut next_num)
+                || (next_num.is_point() && nnext_num.ones())
+                || next_num.informal_multiplyable()
+                // soixante dix -> [soixante dix]
+                || (self.data.language.additive_teens() && next_num.teens())
+                // treinta y dos, soixante et onze -> [treinta y dos], [soixante et onze]
+                || (self.data.language.and_after_tens()
+                    && next_num.is_and()
+                    && (nnext_num.ones() || nnext_num.teens()));
+        }
+        if is_multiple(&mut num) {
+            self.prev_multiple = num.clone();
+            if self.prev_point {
+                return false;
+            }
+            if next_num.is_point() {
+                return nnext_num.ones();
+            }
+            return next_num.is_and() || num > next_num || next_num.informal_multiplyable();
+        }
+        if num.hundred() {
+            return next_num.is_and()
+                || next_num.is_point()
+                || next_num.ones()
+                || next_num.teens()
+                || next_num.tens()
+                || is_multiple(&mut next_num)
+                || next_num.informal_multiplyable();
+        }
+        false
+    }
+}
+
+/// A number written with digits: `5`, `25`.
+fn is_numeral(comp: &CompStr) -> bool {
+    comp.val.text.as_deref().is_some_and(|t| !t.is_empty() && t.bytes().all(|b| b.is_ascii_digit()))
+}
+
+/// `hundred` is in the multiples table but follows its own rules,
+/// eg: two hundred five thousand -> one number.
+fn is_multiple(comp: &mut CompStr) -> bool {
+    comp.multiples() && !comp.hundred()
+}
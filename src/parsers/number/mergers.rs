@@ -1,131 +1,181 @@
-from __future__ import annotations
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::{One, Signed, ToPrimitive, Zero};
 
-from math import log10
-from typing import Iterator, List, Optional
+use crate::parsers::number::{merge_powers, Data, NumberForm, NumberMatch, NumberValue};
+use crate::parsers::number::exact::{parse_numeral, table_value};
+use crate::parsers::number::classes::NumberInfo;
+use crate::parsers::number::constants::{ORDINAL, ROMAN, SPOKEN};
+use crate::parsers::number::scales::ambiguous_scale;
+use crate::parsers::number::systems::number_system;
 
-from more_itertools import windowed, padded, chunked
+/// Combines adjacent numbers that extraction found separately.
+///
+/// Each merge is switched on by its own flag in `Config`:
+/// - `merge_points`: `3 point 25` -> 3.25
+/// - `merge_multiples`: `¾ million` -> 750000
+/// - `merge_informals`: `1.5 dozen` -> 18, `5.5 and a quarter` -> 5.75
+/// - `merge_implied`: `2 thousand 5 hundred` -> 2500
+/// - `merge_powers`: `5 × 10^3` -> 5000, `two squared` -> 4, see [`merge_powers`]
+///
+/// and `Config::merge` turns them all off at once. The points and implied sums between
+/// numerals and words (`3 point 2`, `twenty 5`) are left apart by [`Logic`](crate::parsers::number::Logic)
+/// under the same flags.
+pub struct Merger {
+    data: Data,
+}
 
-from nlp.tokens import Token
-from nlp.constants import NUMBER
-from .core import info_gen
-from .ejtoken import tokenize
-from .normalize import Pipe
+impl Merger {
 
+    pub fn new(data: Data) -> Self {
+        Merger { data }
+    }
 
-def _tok(text, data):
-    return tokenize(Pipe(data)(text))
+    pub fn merge(&mut self, numbers: Vec<NumberMatch>, text: &str) -> Vec<NumberMatch> {
+        let config = self.data.config().clone();
+        if !config.merge.unwrap_or(true) {
+            return numbers;
+        }
+        let mut numbers = numbers;
+        // before the others, `two squared` is a single number
+        if config.merge_powers.unwrap_or(false) {
+            numbers = merge_powers(numbers, text, &self.data);
+        }
+        if numbers.len() <= 1 {
+            return numbers;
+        }
+        if config.merge_points.unwrap_or(false) {
+            numbers = self.merge_with(numbers, text, Self::merge_points);
+        }
+        if config.merge_multiples.unwrap_or(false) {
+            numbers = self.merge_with(numbers, text, Self::merge_multiples);
+        }
+        if config.merge_informals.unwrap_or(false) {
+            numbers = self.merge_with(numbers, text, Self::merge_informals);
+        }
+        if config.merge_implied.unwrap_or(false) {
+            numbers = self.merge_with(numbers, text, Self::merge_implied);
+        }
+        numbers
+    }
 
+    /// `3 point 25` -> 3.25, the digits after the point are read as decimals.
+    fn merge_points(&mut self, first: &NumberMatch, second: &NumberMatch, between: &str) -> Option<BigRational> {
+        let first_value = first.value.to_ratio()?;
+        if !self.data.points().contains(&between.to_string())
+            || !first_value.is_integer()
+            || !second.text.chars().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+        let decimals = parse_numeral(&format!("0.{}", second.text))?;
+        Some(if first_value.is_negative() { first_value - decimals } else { first_value + decimals })
+    }
 
-class Merger:
-    
-    def __init__(
-        self,
-        data,
-        exceptions: List[str] = None,
-        **kwargs,
-    ) -> None:
-        self.data = data
-        self.config = data.config
-        if exceptions is None:
-            exceptions = ("hundred",)
-        self.exceptions = set(exceptions)
-    
-    def merge_multiples(self, tokens: List[Token], og_text: str) -> Iterator[Token]:
-        # num < 1000 -> multiple thousand, million, billion, ...
-        
-        multiples = filter(
-            lambda x: x not in self.exceptions,
-            self.data.MULTIPLES.keys()
-        )
-        iterator = list(windowed(tokens, 2))
-        i = 0
-        last_start = None
-        while True:
-            try:
-                t1, t2 = iterator[i]
-                last_start = t2.span[0]
-                value1 = t1.metadata["value"]
-                value2 = t2.metadata["value"]
-            except (IndexError, KeyError):
-                break
-            if t2 is None:
-                if t1.span[0] != last_start:
-                    yield t1
-                break
-            if _tok(t2.text.lower(), self.data)[0] not in multiples: #or value1 > 1000:
-                if t1.span[0] != last_start:
-                    
-                    yield t1
-                if (i + 1) == len(iterator) and t2 is not None:
-                    yield t2
-            else:
-                span = (t1.span[0], t2.span[1])
-                text = og_text[span[0]: span[1]]
-                exponent = int(log10(value2))
-                value = (value1 * pow(10, exponent + 1)) + (value2 - pow(10, exponent))
-                info = info_gen(text, value, span, self.data)
-                info.pop("span", None)
-                yield Token(text, span=span, entity=NUMBER, metadata=info)
-            i +=1
-        
-            
-    def merge_points(self, tokens: List[Token], og_text: str) -> Iterator[Token]:
-        return tokens
-    
-    def merge_informals(self, tokens: List[Token], og_text: str) -> Iterator[Token]:
-        """
-        5 and a quarter
-        """
+    /// `¾ million` -> 750000, a number followed by a lone multiple of a thousand.
+    fn merge_multiples(&mut self, first: &NumberMatch, second: &NumberMatch, between: &str) -> Option<BigRational> {
+        let word = second.text.to_lowercase();
+        // `hundred` has already been joined wherever it makes sense
+        if !between.is_empty()
+            || self.data.hundreds().contains_key(&word)
+            || self.data.ordinal_multiples().contains_key(&word) {
+            return None;
+        }
+        let multiple = self.data.multiples().get(&word).copied()?;
+        Some(first.value.to_ratio()? * table_value(multiple)?)
+    }
 
-        i = 0
-        iterator = chunked(tokens, 2)
-        iterator = list(map(lambda x: padded(x, n=2), iterator))
-        iterator = [tuple(x) for x in iterator]
-        while True:
-            try:
-                yielded = False
-                t1, t2 = iterator[i]
-            except Exception:
-                break
-            if t2 is None:
-                yield t1
-                return
-            t_and = og_text[t1.span[1]: t2.span[0]].strip().lower()
-            _and = _tok(t2.text.lower(), self.data)[0] == "and" and t_and == "" or t_and == "and"
-            if _and:
-                toks = _tok(t2.text, self.data)
-                last = toks[-1].lower()
-                    
-                if last in [*self.data.INFORMAL_EXACT, *self.data.INFORMALS_MULTIPLYABLE]:
-                    span = t1.span[0], t2.span[1]
-                    text = og_text[span[0]: span[1]]
-                    value = t1.metadata["value"] + t2.metadata["value"]
-                    metadata = {
-                        "value": value,
-                        "number_type": "spoken",
-                        "value_type": "integer" if isinstance(value, int) else "float"
-                    }
-                    yielded = True
-                    yield Token(text, span=span, metadata=metadata, entity=NUMBER)
-            if not yielded:
-                yield t1
-                if t2 is not None:
-                    yield t2
-                else:
-                    break
-                    
-            i += 1
-        
-    
-    def merge(self, tokens: List[Token], og_text: str) -> List[Token]:
-        if len(tokens) <= 1:
-            return tokens
-        new_tokens = tokens
-        if self.config.merge_multiples:
-            new_tokens = list(self.merge_multiples(tokens, og_text)) or new_tokens
-        if self.config.merge_points:
-            new_tokens = list(self.merge_points(new_tokens, og_text)) or new_tokens
-        if self.config.merge_informals:
-            new_tokens = list(self.merge_informals(new_tokens, og_text)) or new_tokens
-        return new_tokens
-        
\ No newline at end of file
+    /// `1.5 dozen` -> 18 and `5.5 and a quarter` -> 5.75
+    fn merge_informals(&mut self, first: &NumberMatch, second: &NumberMatch, between: &str) -> Option<BigRational> {
+        let (first_value, second_value) = (first.value.to_ratio()?, second.value.to_ratio()?);
+        let lower = second.text.to_lowercase();
+        let last = lower.split_whitespace().last()?;
+        let informals = self.data.informal_all();
+        // `a quarter and two halves` are two amounts
+        let first_lower = first.text.to_lowercase();
+        if first_lower.split_whitespace().last().is_some_and(|w| informals.contains_key(w)) {
+            return None;
+        }
+        // `5 and a quarter` but not `7 and one and a half`
+        if self.data.ands().contains(&between.to_string()) && second_value < BigRational::one() {
+            return informals.get(last).map(|_| first_value + second_value);
+        }
+        let multiplyable = self.data.informals_multiplyable().contains_key(last);
+        // `3 dozen` but not `3 half`
+        let exact = self.data.informal_exact().get(last).is_some_and(|v| *v >= 1.0);
+        if between.is_empty() && lower == last && (multiplyable || exact) {
+            return Some(first_value * second_value);
+        }
+        None
+    }
+
+    /// `2 thousand 5 hundred` -> 2500, a smaller number following a round one is added to it.
+    fn merge_implied(&mut self, first: &NumberMatch, second: &NumberMatch, between: &str) -> Option<BigRational> {
+        let (first_value, second_value) = (first.value.to_ratio()?, second.value.to_ratio()?);
+        if !(between.is_empty() || self.data.ands().contains(&between.to_string()))
+            // `1,000 300` is a list
+            || (first.number_type != SPOKEN && second.number_type != SPOKEN)
+            || !first_value.is_integer()
+            || first_value.is_zero()
+            || second_value.is_negative() {
+            return None;
+        }
+        let ten = BigInt::from(10);
+        let mut place = BigInt::one();
+        while (first_value.numer() % (&place * &ten)).is_zero() {
+            place *= &ten;
+        }
+        if second_value < BigRational::from_integer(place) {
+            Some(first_value + second_value)
+        } else {
+            None
+        }
+    }
+
+    fn merge_with(
+        &mut self,
+        numbers: Vec<NumberMatch>,
+        text: &str,
+        merge_pair: fn(&mut Self, &NumberMatch, &NumberMatch, &str) -> Option<BigRational>,
+    ) -> Vec<NumberMatch> {
+        let mut merged: Vec<NumberMatch> = Vec::new();
+        for number in numbers {
+            // spans that overlap are never one number
+            let between = merged.last().and_then(|prev| text.get(prev.span.1..number.span.0));
+            if let (Some(between), Some(prev)) = (between.map(|b| b.trim().to_lowercase()), merged.last_mut()) {
+                // `21st 3` and `XIV 2` are never one number
+                let value = if [prev.number_type, number.number_type].iter().any(|t| *t == ORDINAL || *t == ROMAN) {
+                    None
+                } else {
+                    merge_pair(self, prev, &number, &between).and_then(|value| {
+                        if self.data.config().exact.unwrap_or(false) {
+                            Some(NumberValue::from_ratio(value))
+                        } else {
+                            value.to_f64().map(NumberValue::from_float)
+                        }
+                    })
+                };
+                if let Some(value) = value {
+                    let span = (prev.span.0, number.span.1);
+                    let merged_text = &text[span.0..span.1];
+                    let form = NumberForm {
+                        spoken: prev.value.is_spoken() || number.value.is_spoken(),
+                        system: number_system(merged_text, &self.data),
+                        ambiguous_scale: ambiguous_scale(merged_text, &self.data),
+                        ..NumberForm::default()
+                    };
+                    *prev = NumberMatch {
+                        number_type: NumberInfo::new(self.data.clone()).number_type(merged_text),
+                        text: merged_text.to_string(),
+                        span,
+                        value: value.with_form(form),
+                        qualifier: None,
+                        power: None,
+                    };
+                    continue;
+                }
+            }
+            merged.push(number);
+        }
+        merged
+    }
+}
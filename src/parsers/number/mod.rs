@@ -6,13 +6,59 @@ pub mod normalize;
 pub mod utils;
 pub mod words2num;
 pub mod classes;
+pub mod core;
+pub mod logic;
+pub mod mergers;
+pub mod value;
+pub mod exact;
+pub mod fractions;
+pub mod complex;
+pub mod ordinals;
+pub mod roman;
+pub mod ranges;
+pub mod qualifiers;
+pub mod percentages;
+pub mod money;
+pub mod units;
+pub mod powers;
+pub mod locale;
+pub mod scales;
+pub mod scripts;
+pub mod systems;
+pub mod cjk;
+pub mod literals;
+pub mod num2words;
+pub mod languages;
 
 pub use config::Config;
-pub use data::Data;
+pub use data::{CompiledRegexes, Data};
 pub use tokenizer::tokenize;
 pub use normalize::{Pipe, normalize_and, check_valid, recover_real_indices_and_match};
 pub use words2num::words2num;
-pub use utils::DataHolder;
+pub use exact::words2exact;
+pub use fractions::words2fraction;
+pub use complex::words2complex;
+pub use ordinals::words2ordinal;
+pub use roman::{roman2int, words2roman};
+pub use ranges::{find_ranges, NumberRange};
+pub use qualifiers::{find_qualifier, Qualifier, QualifierKind};
+pub use percentages::{find_percentages, PercentUnit, Percentage};
+pub use money::{find_money, minor_unit_digits, Money};
+pub use powers::{e_notation, merge_powers, Power};
+pub use locale::{canonical_numerals, Grouping, NumberLocale, Separators};
+pub use scales::{ambiguous_scale, rescale, NumberScale};
+pub use scripts::{ascii_digits, digit_script, digit_value, DigitScript};
+pub use systems::{number_system, words2myriad, NumberSystem};
+pub use cjk::words2cjk;
+pub use literals::{parse_literal, strip_type_suffix, FLOAT_SUFFIXES, INTEGER_SUFFIXES};
+pub use units::{find_quantities, parse_unit, Dimension, Quantity, Unit};
+pub use num2words::{num2words, WordsConfig, WordsStyle};
+pub use languages::{builtin_pack, language_pack, LanguagePack, Vocabulary, VocabularyError};
+pub use utils::{DataHolder, parse_number, string_to_num};
+pub use value::{NumberForm, NumberValue};
+pub use logic::Logic;
+pub use mergers::Merger;
+pub use self::core::{Extraction, NumberEntityParser, NumberMatch, NumberParser};
 
 
 
@@ -22,7 +68,7 @@ mod tests {
     
     #[test]
     fn test_number_tokenizer() {
-        let mut text = "
+        let text = "
     1 -89 +799 588
     2.5 .577 1.2e2 23E3 -208.89 +13.7
     -2.7e2 +199.123E90 +5e-12 -.5
@@ -57,8 +103,8 @@ mod tests {
     
     #[test]
     fn test_valid_token() {
-        let mut config = Config::default();
-        let mut data = Data::new(config);
+        let config = Config::default();
+        let data = Data::new(config);
         let nums = vec![
             "one".to_string(),
             "two".to_string(),
@@ -71,7 +117,7 @@ mod tests {
             "nine".to_string(),
         ];
         for num in nums {
-            assert!(check_valid(num, &mut data));
+            assert!(check_valid(num, &data));
         }
     }
 }
\ No newline at end of file
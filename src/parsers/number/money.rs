@@ -0,0 +1,239 @@
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::{Signed, ToPrimitive};
+
+use crate::parsers::number::{words2num, Config, Data, DataHolder, NumberEntityParser, NumberMatch, NumberParser, NumberValue};
+use crate::parsers::number::constants::MONEY;
+use crate::parsers::number::exact::table_value;
+use crate::parsers::number::locale::canonical_numerals;
+use crate::parsers::number::value::ten_pow;
+
+/// ISO 4217 codes and the digits of their minor unit: cents for `USD`, none for `JPY`.
+const CURRENCIES: [(&str, u32); 39] = [
+    ("USD", 2), ("EUR", 2), ("GBP", 2), ("JPY", 0), ("CNY", 2), ("CHF", 2), ("CAD", 2),
+    ("AUD", 2), ("NZD", 2), ("HKD", 2), ("SGD", 2), ("INR", 2), ("KRW", 0), ("RUB", 2),
+    ("BRL", 2), ("MXN", 2), ("ZAR", 2), ("SEK", 2), ("NOK", 2), ("DKK", 2), ("PLN", 2),
+    ("CZK", 2), ("HUF", 2), ("TRY", 2), ("ILS", 2), ("AED", 2), ("SAR", 2), ("THB", 2),
+    ("IDR", 2), ("PHP", 2), ("TWD", 2), ("VND", 0), ("CLP", 0), ("ISK", 0), ("BHD", 3),
+    ("KWD", 3), ("OMR", 3), ("JOD", 3), ("TND", 3),
+];
+
+/// Signs before or after the amount. `$` and `¥` are the most common currencies using them.
+const SYMBOLS: [(&str, &str); 20] = [
+    ("US$", "USD"), ("CA$", "CAD"), ("C$", "CAD"), ("AU$", "AUD"), ("A$", "AUD"),
+    ("NZ$", "NZD"), ("HK$", "HKD"), ("S$", "SGD"), ("R$", "BRL"), ("$", "USD"),
+    ("€", "EUR"), ("£", "GBP"), ("¥", "JPY"), ("₹", "INR"), ("₩", "KRW"),
+    ("₽", "RUB"), ("₺", "TRY"), ("₪", "ILS"), ("₫", "VND"), ("zł", "PLN"),
+];
+
+/// Names after the amount. Plain `pounds` is left out, it is more often a weight.
+const NAMES: [(&str, &str); 22] = [
+    ("us dollars", "USD"), ("canadian dollars", "CAD"), ("australian dollars", "AUD"),
+    ("hong kong dollars", "HKD"), ("singapore dollars", "SGD"), ("dollars", "USD"),
+    ("dollar", "USD"), ("bucks", "USD"), ("euros", "EUR"), ("euro", "EUR"),
+    ("pounds sterling", "GBP"), ("pound sterling", "GBP"), ("quid", "GBP"), ("yen", "JPY"),
+    ("yuan", "CNY"), ("renminbi", "CNY"), ("rupees", "INR"), ("rupee", "INR"),
+    ("swiss francs", "CHF"), ("roubles", "RUB"), ("rubles", "RUB"), ("ruble", "RUB"),
+];
+
+/// Names of minor units, `None` when they go with any currency: `cents`.
+const MINOR_NAMES: [(&str, Option<&str>); 5] = [
+    ("cents", None), ("cent", None), ("¢", None), ("pence", Some("GBP")), ("penny", Some("GBP")),
+];
+
+/// Scales of money amounts besides the suffixes of [`Data`]: `$5mn`, `$5MM`.
+const SCALES: [(&str, i64); 3] = [("mn", 1_000_000), ("mm", 1_000_000), ("MM", 1_000_000)];
+
+/// Scales read after a space, `£5 bn`, `$3.5 M`. Not `m` or `k`, `$5 m` may be a length.
+const SPACED_SCALES: [&str; 5] = ["bn", "B", "M", "mn", "MM"];
+
+/// The digits of the minor unit of an ISO 4217 currency: 2 for `USD`, 0 for `JPY`.
+pub fn minor_unit_digits(code: &str) -> Option<u32> {
+    CURRENCIES.iter().find(|(c, _)| *c == code).map(|(_, digits)| *digits)
+}
+
+/// An amount of money: `$3.5M`, `€1,200.50`, `USD 40`, `five dollars and ten cents`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Money {
+    pub text: String,
+    pub span: (usize, usize),
+    pub amount: NumberValue,
+    /// The ISO 4217 code: `USD`, `EUR` ...
+    pub currency: &'static str,
+    /// The amount in the minor unit of the currency, `€1,200.50` -> 120050 cents. `None`
+    /// when it isn't whole or doesn't fit an `i64`.
+    pub minor_units: Option<i64>,
+}
+
+enum Unit {
+    Major(&'static str),
+    Minor(Option<&'static str>),
+}
+
+/// The money amounts among the `numbers` of `text`.
+///
+/// A currency symbol or ISO code comes before or after the amount (`$5`, `USD 40`, `40 USD`),
+/// a currency name after it (`twenty euros`). Minor units are added to the amount before
+/// them (`5 dollars and 10 cents`) or stand alone (`99 cents`, in dollars). Amounts take
+/// the suffixes of [`Data`] (`$3.5M`, `£2bn`, `£5 bn`) and `mn` or `MM` for a million.
+pub fn find_money(numbers: &[NumberMatch], text: &str, data: &Data) -> Vec<Money> {
+    let exact = data.config().exact.unwrap_or(false);
+    let mut found = Vec::new();
+    let mut i = 0;
+    while i < numbers.len() {
+        let number = &numbers[i];
+        i += 1;
+        let Some((mut amount, number_end)) = amount_of(number, text, data) else {
+            continue;
+        };
+        // `3 $10 each`: the `$` is the sign of `10`, not of `3`
+        let leads_next = |end: usize| {
+            text[end..].starts_with(|c: char| c.is_ascii_digit())
+                || numbers.get(i).is_some_and(|next| currency_before(text, next.span.0).is_some_and(|(_, start)| start < end))
+        };
+        let (start, mut end, currency) = match currency_before(text, number.span.0) {
+            Some((code, start)) => (start, number_end, code),
+            None => match unit_after(text, number_end).filter(|(_, end)| !leads_next(*end)) {
+                Some((Unit::Major(code), end)) => (number.span.0, end, code),
+                Some((Unit::Minor(code), end)) => {
+                    let code = code.unwrap_or("USD");
+                    let digits = minor_unit_digits(code).unwrap_or(2);
+                    amount /= BigRational::from_integer(ten_pow(digits));
+                    push(&mut found, money(text, (number.span.0, end), amount, code, exact));
+                    continue;
+                }
+                None => continue,
+            },
+        };
+        // `5 dollars and 10 cents`, `$5, 10 cents`
+        if let Some(next) = numbers.get(i) {
+            let joined = matches!(text[end..next.span.0].trim(), "" | "and" | ",");
+            if let Some((Unit::Minor(code), minor_end)) = joined.then(|| unit_after(text, next.span.1)).flatten() {
+                let digits = minor_unit_digits(currency).unwrap_or(2);
+                if code.is_none_or(|code| code == currency) && digits > 0 {
+                    if let Some(minor) = next.value.to_ratio() {
+                        amount += minor / BigRational::from_integer(ten_pow(digits));
+                        end = minor_end;
+                        i += 1;
+                    }
+                }
+            }
+        }
+        push(&mut found, money(text, (start, end), amount, currency, exact));
+    }
+    found
+}
+
+/// Adds `money` unless it overlaps the amount found before it.
+fn push(found: &mut Vec<Money>, money: Money) {
+    if found.last().is_none_or(|last| last.span.1 <= money.span.0) {
+        found.push(money);
+    }
+}
+
+fn money(text: &str, span: (usize, usize), amount: BigRational, currency: &'static str, exact: bool) -> Money {
+    let digits = minor_unit_digits(currency).unwrap_or(2);
+    let minor = &amount * BigRational::from_integer(ten_pow(digits));
+    let rounded = minor.round();
+    // floats like 19.99 are a hair off
+    let minor_units = ((&minor - &rounded).abs() < BigRational::new(1.into(), 1_000_000.into()))
+        .then(|| rounded.to_integer().to_i64())
+        .flatten();
+    let amount = if exact {
+        NumberValue::from_ratio(amount)
+    } else {
+        NumberValue::from_float(amount.to_f64().unwrap_or(f64::NAN))
+    };
+    Money { text: text[span.0..span.1].to_string(), span, amount, currency, minor_units }
+}
+
+/// The amount of `number` and where it ends, reading a money scale right after it: `5mn`.
+fn amount_of(number: &NumberMatch, text: &str, data: &Data) -> Option<(BigRational, usize)> {
+    let attached = text[number.span.1..].chars().take_while(|c| c.is_ascii_alphabetic()).count();
+    let end = number.span.1 + attached;
+    let word = &text[number.span.0..end];
+    let digits = word.trim_end_matches(|c: char| c.is_ascii_alphabetic());
+    let scale = SCALES.iter().find(|(scale, _)| *scale == &word[digits.len()..]);
+    match scale {
+        Some((_, scale)) if !digits.is_empty() => {
+            let holder = DataHolder { type_: "text", text: Some(canonical_numerals(digits, data)), int: None, float: None };
+            let base = words2num(holder, data)?.to_ratio()?;
+            Some((base * BigRational::from_integer(BigInt::from(*scale)), end))
+        }
+        _ => match spaced_scale(number, text, data) {
+            Some((scale, end)) => Some((number.value.to_ratio()? * scale, end)),
+            None => Some((number.value.to_ratio()?, number.span.1)),
+        },
+    }
+}
+
+/// The scale a space away from the digits of `number` and where it ends: `£5 bn`.
+fn spaced_scale(number: &NumberMatch, text: &str, data: &Data) -> Option<(BigRational, usize)> {
+    if number.value.is_spoken() || !number.text.ends_with(|c: char| c.is_ascii_digit()) {
+        return None;
+    }
+    let after = &text[number.span.1..];
+    let rest = after.trim_start_matches(' ');
+    let word = rest.split(|c: char| !c.is_ascii_alphabetic()).next()?;
+    if rest.len() == after.len() || !SPACED_SCALES.contains(&word) {
+        return None;
+    }
+    let scale = match SCALES.iter().find(|(scale, _)| *scale == word) {
+        Some((_, scale)) => BigRational::from_integer(BigInt::from(*scale)),
+        None => table_value(data.get_suffix_value(word)?)?,
+    };
+    Some((scale, number.span.1 + after.len() - rest.len() + word.len()))
+}
+
+fn currency_before(text: &str, start: usize) -> Option<(&'static str, usize)> {
+    let before = text[..start].trim_end();
+    let symbol = SYMBOLS.iter().map(|&(symbol, code)| (symbol, code));
+    let codes = CURRENCIES.iter().map(|&(code, _)| (code, code));
+    symbol
+        .chain(codes)
+        .filter(|(sign, _)| {
+            before.ends_with(sign)
+                && !(sign.starts_with(char::is_alphabetic)
+                    && before[..before.len() - sign.len()].ends_with(char::is_alphanumeric))
+        })
+        .max_by_key(|(sign, _)| sign.len())
+        .map(|(sign, code)| (code, before.len() - sign.len()))
+}
+
+fn unit_after(text: &str, end: usize) -> Option<(Unit, usize)> {
+    let after = &text[end..];
+    let rest = after.trim_start();
+    let start = end + after.len() - rest.len();
+    let ends_word = |len: usize| !rest[len..].starts_with(char::is_alphanumeric);
+    let starts_with = |word: &str| rest.get(..word.len()).is_some_and(|w| w.eq_ignore_ascii_case(word));
+    if let Some(&(symbol, code)) = SYMBOLS.iter().find(|(symbol, _)| rest.starts_with(symbol)) {
+        return Some((Unit::Major(code), start + symbol.len()));
+    }
+    if let Some(&(code, _)) = CURRENCIES.iter().find(|(code, _)| rest.starts_with(code) && ends_word(code.len())) {
+        return Some((Unit::Major(code), start + code.len()));
+    }
+    if let Some(&(name, code)) = NAMES.iter().find(|(name, _)| starts_with(name) && ends_word(name.len())) {
+        return Some((Unit::Major(code), start + name.len()));
+    }
+    MINOR_NAMES
+        .iter()
+        .find(|(name, _)| starts_with(name) && ends_word(name.len()))
+        .map(|&(name, code)| (Unit::Minor(code), start + name.len()))
+}
+
+impl NumberParser {
+    /// The money amounts of `text`, see [`find_money`].
+    pub fn extract_money(&mut self, text: &str) -> Vec<Money> {
+        let numbers = self.extract(text);
+        find_money(&numbers, text, self.data())
+    }
+}
+
+impl NumberEntityParser {
+    /// Finds money amounts as `money` entities, see [`find_money`].
+    pub fn money(config: Config) -> Self {
+        NumberEntityParser::new(config, MONEY, |parser, text| {
+            parser.extract_money(text).into_iter().map(|m| (m.text, m.span)).collect()
+        })
+    }
+}
@@ -1,76 +1,74 @@
-use std::collections::HashMap;
-use regex::escape;
 use fancy_regex::Regex;
+use lazy_static::lazy_static;
 
 use crate::parsers::number::{tokenize, Data};
 use crate::parsers::number::constants::_REPLACEMENT;
 
+lazy_static! {
+    static ref DIGIT_HYPHEN: Regex = Regex::new(r"(\d)\-(\d)").unwrap();
+    static ref LONG_SPACE: Regex = Regex::new(r"\s{4,}").unwrap();
+    static ref DOUBLE_COMMA: Regex = Regex::new(r",\s*,").unwrap();
+    static ref NON_DIGIT_HYPHEN: Regex = Regex::new(r"(\D)\-(\D)").unwrap();
+    static ref POINT_SPACE: Regex = Regex::new(r"\.(\s+)").unwrap();
+    static ref POINT_NON_DIGIT: Regex = Regex::new(r"\.(\D)").unwrap();
+    static ref LETTER_DIGIT: Regex = Regex::new(r"(?<=(\s))([^\-\+\.\d])(\d)").unwrap();
+    static ref PUNCTUATION_NON_DIGIT: Regex = Regex::new(r"([`',\.])(\D)").unwrap();
+    static ref LONG_GROUP: Regex = Regex::new(r"(\d)([',])(\d{4,})").unwrap();
+    static ref SIGN: Regex = Regex::new(r"(?<!\d[eE])([-+])").unwrap();
+    static ref SIGN_LETTER: Regex = Regex::new(r"([\-\+])([a-df-zA-DF-Z])").unwrap();
+}
+
 
-fn _normalize_and_inner(numbers: Vec<Vec<String>>, data: &mut Data) -> Vec<Vec<String>> {
+fn _normalize_and_inner(numbers: Vec<Vec<String>>, data: &Data) -> Vec<Vec<String>> {
     let mut lasts: Vec<String> = Vec::new();
-    lasts.extend(data.ands());
-    lasts.extend(data.points());
-    lasts.extend(data.negatives());
+    lasts.extend(data.ands().iter().cloned());
+    lasts.extend(data.points().iter().cloned());
+    lasts.extend(data.negatives().iter().cloned());
     let ands = data.ands();
     let zeros = data.zeros();
-    let mut n_len: usize;
-    let mut insertion: Vec<String>;
-    let mut nums = numbers.clone();
-    for i in 0..numbers.len() {
-        let n = &nums[i];
-        n_len = n.len();
+    let mut nums: Vec<Vec<String>> = Vec::new();
+    for mut n in numbers {
+        let n_len = n.len();
         if n_len > 1 {
-            let first = &n[0];
-            let last = &n[n_len - 1];
-            let second_last = &n[n_len - 2];
-            if lasts.contains(&last) {
-                insertion = [n[n_len - 1].clone()].to_vec();
-                nums.insert(i + 1, insertion);
-                nums[i].pop();
-            } else if ands.contains(&second_last) && zeros.contains(&last) {
-                insertion = [nums[i][n_len - 2].clone()].to_vec();
-                nums.insert(i + 1, insertion);
-                insertion = [nums[i][n_len - 1].clone()].to_vec();
-                nums.insert(i + 2, insertion);
-                nums[i].remove(n_len - 2);
-                nums[i].pop();
-            } else if ands.contains(&first) {
-                insertion = [nums[i][0].clone()].to_vec();
-                nums.insert(i, insertion);
-                nums[i].remove(0);
+            if lasts.contains(&n[n_len - 1]) {
+                // `twenty and` -> [twenty, and]
+                let last = n.pop().unwrap();
+                nums.push(n);
+                nums.push(vec![last]);
+                continue;
+            } else if ands.contains(&n[n_len - 2]) && zeros.contains(&n[n_len - 1]) {
+                // `five and zero` -> [five, and, zero]
+                let last = n.pop().unwrap();
+                let and = n.pop().unwrap();
+                nums.push(n);
+                nums.push(vec![and]);
+                nums.push(vec![last]);
+                continue;
+            } else if ands.contains(&n[0]) {
+                // `and five` -> [and, five]
+                let first = n.remove(0);
+                nums.push(vec![first]);
             }
         }
+        nums.push(n);
     }
-    numbers.to_vec()
+    nums
 }
 
-pub fn normalize_and(numbers: Vec<Vec<String>>, data: &mut Data) -> Vec<Vec<String>> {
+pub fn normalize_and(numbers: Vec<Vec<String>>, data: &Data) -> Vec<Vec<String>> {
     let nums = _normalize_and_inner(numbers, data);
     let mut final_: Vec<Vec<String>> = Vec::new();
     for n in nums {
-        if n.len() > 1 {
-            final_.push(n);
-        } else if check_valid(n[0].clone(), data) {
+        if n.len() > 1 || (n.len() == 1 && check_valid(n[0].clone(), data)) {
             final_.push(n);
         }
     }
     final_
 }
 
-pub fn check_valid(text: String, data: &mut Data) -> bool {
-    let regex_pipes = vec![
-        data.ordinal_numeral_regex(),
-        data.number_followed_by_suffix_regex(),
-        data.suffix_name_regex(),
-        data.hex_regex(),
-        data.oct_regex(),
-        data.binary_regex(),
-        data.any_number_regex(),
-    ];
-    for _pipe in regex_pipes {
-        let re = Regex::new(&format!("^{_pipe}$")).unwrap();
-        let result = re.is_match(&text);
-        if result.is_ok() {
+pub fn check_valid(text: String, data: &Data) -> bool {
+    for re in &data.regexes().check_valid {
+        if re.is_match(&text).unwrap_or(false) {
             return true;
         }
     }
@@ -78,52 +76,71 @@ pub fn check_valid(text: String, data: &mut Data) -> bool {
     valid
 }
 
+/// A matched number string and its byte span in the original text.
+pub type SpannedText = (String, (usize, usize));
+
 pub fn recover_real_indices_and_match(
     text: String,
     nums: Vec<Vec<String>>,
-    _data: Option<&mut Data>,
-) -> (Vec<(String, (usize, usize))>, String) {
+    _data: Option<&Data>,
+) -> (Vec<SpannedText>, String) {
     let mut last_start = 0;
-    let mut real: Vec<(String, (usize, usize))> = Vec::new();
+    let mut real: Vec<SpannedText> = Vec::new();
     let mut temp_text = text.clone();
     for n in nums.iter() {
-        let escaped: String = n
-            .iter()
-            .map(|s| escape(s).to_string())
-            .collect::<Vec<String>>()
-            .join(" ")
-            .replace(" ", r"\s*[,\-]?\s*");
-        let re = Regex::new(&escaped).unwrap();
-        for cap in re.captures_iter(&temp_text[last_start..temp_text.len()]) {
-            if let Some(mat) = cap.expect("No group").get(0) {
-                let mut start = mat.start();
-                let mut end = mat.end();
-                start = start + last_start;
-                end = end + last_start;
-                real.push((mat.as_str().to_string(), (start, end))); 
-                last_start = start;
-                temp_text.replace_range(start..end, &_REPLACEMENT.repeat(end - start));
-                break;
-            }
+        if let Some((start, end)) = find_words(&temp_text, last_start, n) {
+            real.push((temp_text[start..end].to_string(), (start, end)));
+            last_start = start;
+            temp_text.replace_range(start..end, &_REPLACEMENT.repeat(end - start));
         }
     }
     (real, temp_text)
 }
 
+/// The first span of `text` from `from` on where `words` follow each other, apart by spaces
+/// and a comma or a hyphen: `twenty-one`, `one thousand, two hundred`.
+fn find_words(text: &str, from: usize, words: &[String]) -> Option<(usize, usize)> {
+    // `one` must not be found inside `someone`
+    let boundary = |c: Option<char>| !c.is_some_and(|c| c.is_ascii_alphanumeric());
+    text[from..]
+        .char_indices()
+        .map(|(i, _)| from + i)
+        .filter(|&start| boundary(text[..start].chars().next_back()))
+        .find_map(|start| Some((start, words_end(text, start, words)?)))
+}
+
+/// Where `words` end when they start at `at`, trying a comma or a hyphen before spaces alone.
+fn words_end(text: &str, at: usize, words: &[String]) -> Option<usize> {
+    let Some((first, rest)) = words.split_first() else {
+        return (!text[at..].starts_with(|c: char| c.is_ascii_alphanumeric())).then_some(at);
+    };
+    if !text[at..].starts_with(first.as_str()) {
+        return None;
+    }
+    let end = at + first.len();
+    if rest.is_empty() {
+        return words_end(text, end, rest);
+    }
+    let skip_spaces = |at: usize| at + text[at..].len() - text[at..].trim_start().len();
+    let gap = skip_spaces(end);
+    let after_separator = text[gap..].starts_with([',', '-']).then(|| skip_spaces(gap + 1));
+    after_separator.into_iter().chain([gap]).find_map(|next| words_end(text, next, rest))
+}
+
 fn _detokenize(tokens: Vec<String>) -> String {
     tokens.join(" ")
 }
 
-fn _normalize_hyphen(text: String, data: &mut Data) -> String {
+fn _normalize_hyphen(text: String, data: &Data) -> String {
     /*
     Normalize numbers such as: "twenty-five" to "twenty five", "seventy-nine" to "seventy nine" not 
     "re-enroll", "up-front", "made-up"
     */
     let mut rtokens: Vec<String> = Vec::new();
     let tokens = tokenize(&text);
-    let hyphen_re: Regex = Regex::new(&data.hyphen()).unwrap();
+    let hyphen_re = &data.regexes().hyphen;
     for n in &tokens {
-        if hyphen_re.is_match(n).is_ok() {
+        if hyphen_re.is_match(n).unwrap_or(false) {
             let ts: Vec<String> = n.split("-").map(|s| s.to_string()).collect();
             rtokens.extend(ts);
         } else {
@@ -133,49 +150,29 @@ fn _normalize_hyphen(text: String, data: &mut Data) -> String {
     _detokenize(rtokens)
 }
 
-fn _rep_commas(text: String, data: &mut Data) -> String {
-    let binding = data.multiples();
-    let multiples_map: HashMap<&String, &f64> = binding
-        .iter()
-        .filter(|(k, _)| {
-            !data.ordinal_multiples().contains_key(*k) && (
-                !data.suffixes_by_name().contains_key(*k) ||
-                **k != "hundred".to_string()
-            )
-        })
-        .collect::<HashMap<_, _>>();
-   let multiples: String = multiples_map
-       .iter()
-       .map(|(&k, _)| k.to_string())
-       .collect::<Vec<String>>()
-       .join("|");
+fn _rep_commas(text: String, data: &Data) -> String {
     /* orig_text = text
      Can only have a comma after a
      multiple of 1000
      */
-    Regex::new(&format!(r"(?m)({multiples})\s?,"))
-        .unwrap()
+    data.regexes().rep_commas
         .replace_all(&text, "$1")
         .to_string()
 }
 
 fn _possible_range(text: String) -> String {
-    Regex::new(r"(\d)\-(\d)")
-        .unwrap()
+    DIGIT_HYPHEN
         .replace_all(&text, "$1  -   $2")
         .to_string()
 }
 
-fn _normalize(text: String, data: &mut Data) -> String {
+fn _normalize(text: String, data: &Data) -> String {
     let mut new_text = text.clone();
-    let suffixes: String = data._suffixes();
     //  `two    hundred` -> `two SPACE hundred` 
-    new_text = Regex::new(r"\s{4,}")
-        .unwrap()
+    new_text = LONG_SPACE
         .replace_all(&new_text, " SPACE ")
         .to_string();
-    new_text = Regex::new(r",\s*,")
-        .unwrap()
+    new_text = DOUBLE_COMMA
         .replace_all(&new_text, " COMMA ")
         .to_string();
     
@@ -195,8 +192,7 @@ fn _normalize(text: String, data: &mut Data) -> String {
     new_text = _normalize_hyphen(new_text, data);
     // two-two -> two two
     
-    new_text = Regex::new(r"(\D)\-(\D)")
-        .unwrap()
+    new_text = NON_DIGIT_HYPHEN
         .replace_all(&new_text, r"$1 $2")
         .to_string();
     /* 5-7 -> 5 7
@@ -205,48 +201,39 @@ fn _normalize(text: String, data: &mut Data) -> String {
      so we avoid interpreting
      this as a negative
      */
-    new_text = Regex::new(r"(\d)\-(\d)")
-        .unwrap()
+    new_text = DIGIT_HYPHEN
         .replace_all(&new_text, r"$1 - $2")
         .to_string();
     // `3.^w` -> `3  .  SPACE `
-    new_text = Regex::new(r"\.(\s+)")
-        .unwrap()
+    new_text = POINT_SPACE
         .replace_all(&new_text, "  .  SPACE ")
         .to_string();
     // `thousand.` -> `thousand .`
-    new_text = Regex::new(r"\.(\D)")
-        .unwrap()
+    new_text = POINT_NON_DIGIT
         .replace_all(&new_text, " .  $1")
         .to_string();
     // ` h7` -> ` h 5`
-    if !data.config.bounded_numbers.unwrap() {
-        new_text = Regex::new(r"(?<=(\s))([^\-\+\.\d])(\d)")
-            .unwrap()
+    if !data.config().bounded_numbers.unwrap() {
+        new_text = LETTER_DIGIT
             .replace_all(&new_text, "$1  $2   $3")
             .to_string();
     }
     new_text = _possible_range(new_text);
     // 5^10 -> 5 ^ 10
     // 5'272' -> 5'272 '
-    new_text = Regex::new(r"([`',\.])(\D)")
-        .unwrap()
+    new_text = PUNCTUATION_NON_DIGIT
         .replace_all(&new_text, r" $1$2")
         .to_string();
-    new_text = Regex::new(r"(\d)([',])(\d{4,})")
-         .unwrap()
+    new_text = LONG_GROUP
          .replace_all(&new_text, " $1 $2 $3")
          .to_string();
-    new_text = Regex::new(r"(?<!\d[eE])([-+])")
-        .unwrap()
+    new_text = SIGN
         .replace_all(&new_text, " SPACE  $1")
         .to_string();
-    new_text = Regex::new(&format!(r"\d(^[eE',\d]|{suffixes})(?=>[\W\b])"))
-        .unwrap()
+    new_text = data.regexes().digit_suffix
         .replace_all(&new_text, " SPACE $1")
         .to_string();
-    new_text = Regex::new(r"([\-\+])([a-df-zA-DF-Z])")
-        .unwrap()
+    new_text = SIGN_LETTER
         .replace_all(&new_text, "$1 $2")
         .to_string(); 
     new_text
@@ -257,12 +244,13 @@ pub struct Pipe;
 
 impl Pipe {
         
-    pub fn normalize(text: String, data: &mut Data) -> String {
+    pub fn normalize(text: String, data: &Data) -> String {
         let mut txt = _normalize(text, data);
+        // `einundzwanzig` -> `ein und zwanzig`
         txt = _detokenize(
             txt
             .split_whitespace()
-            .map(|s| s.to_string())
+            .flat_map(|s| data.split_compound(s))
             .collect::<Vec<String>>()
         );
         txt.trim().to_string()
@@ -0,0 +1,286 @@
+use std::collections::{BTreeMap, HashMap};
+
+use num_bigint::BigInt;
+use num_traits::{Signed, ToPrimitive, Zero};
+
+use crate::parsers::number::{Data, NumberValue};
+
+/// Which rendering `num2words` produces.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WordsStyle {
+    /// `one hundred twenty-three`, non-integers are spelled as decimals.
+    #[default]
+    Cardinal,
+    /// `one hundred twenty-third`
+    Ordinal,
+    /// `nineteen eighty-four`, `two thousand five`
+    Year,
+    /// `three point one four`
+    Decimal,
+}
+
+#[derive(Clone, Debug)]
+pub struct WordsConfig {
+    pub style: WordsStyle,
+    /// `one hundred and five` instead of `one hundred five`.
+    pub british_and: bool,
+    /// `twenty-one` instead of `twenty one`.
+    pub hyphenate: bool,
+}
+
+impl Default for WordsConfig {
+    fn default() -> Self {
+        WordsConfig {
+            style: WordsStyle::Cardinal,
+            british_and: false,
+            hyphenate: true,
+        }
+    }
+}
+
+/// Spells a number with the `Data` vocabularies, the inverse of [`words2num`](crate::parsers::number::words2num).
+///
+/// ```
+/// use nlp_rust::parsers::number::{num2words, Config, Data, NumberValue, WordsConfig, WordsStyle};
+///
+/// let data = Data::new(Config::default());
+/// let year = WordsConfig { style: WordsStyle::Year, ..WordsConfig::default() };
+/// assert_eq!(num2words(&NumberValue::from(1984), &year, &data).unwrap(), "nineteen eighty-four");
+/// ```
+///
+/// Cardinals, ordinals and decimals read back to the same value with `words2num`.
+/// `None` for complex numbers, fractions without a terminating decimal, non-integer
+/// ordinals and years, and numbers beyond the largest multiple.
+pub fn num2words(value: &NumberValue, config: &WordsConfig, data: &Data) -> Option<String> {
+    let ratio = value.to_ratio()?;
+    let speller = Speller::new(config, data);
+    match config.style {
+        WordsStyle::Cardinal if ratio.is_integer() => speller.cardinal(&ratio.to_integer()),
+        WordsStyle::Ordinal if ratio.is_integer() => speller.ordinal(&ratio.to_integer()),
+        WordsStyle::Year if ratio.is_integer() => speller.year(&ratio.to_integer()),
+        WordsStyle::Ordinal | WordsStyle::Year => None,
+        WordsStyle::Cardinal | WordsStyle::Decimal => speller.decimal(value),
+    }
+}
+
+struct Speller {
+    british_and: bool,
+    hyphenate: bool,
+    /// `zero` to `nineteen`
+    small: BTreeMap<u32, String>,
+    /// `twenty` to `ninety`
+    tens: BTreeMap<u32, String>,
+    hundred: String,
+    /// `thousand`, `million` ... by power of a thousand
+    scales: BTreeMap<u32, String>,
+    /// `twenty` -> `twentieth`
+    ordinals: HashMap<String, String>,
+    and: String,
+    point: String,
+    negative: String,
+    year_zero: String,
+}
+
+impl Speller {
+
+    fn new(config: &WordsConfig, data: &Data) -> Self {
+        let ordinal_tables = data.ordinals();
+        let mut small = by_value(data.ones(), ordinal_tables);
+        small.extend(by_value(data.teens_and_ten(), ordinal_tables));
+        let tens = by_value(data.tens(), ordinal_tables);
+
+        let mut hundred = String::new();
+        let mut scales = BTreeMap::new();
+        for (value, word) in by_exponent(data.multiples(), ordinal_tables) {
+            match value {
+                2 => hundred = word,
+                v if v % 3 == 0 => {
+                    scales.entry(v / 3).or_insert(word);
+                }
+                _ => {}
+            }
+        }
+
+        // every cardinal word with the ordinal of the same value, `one` -> `first`
+        let mut cardinals = by_value(data.ones(), ordinal_tables);
+        cardinals.extend(by_value(data.teens_and_ten(), ordinal_tables));
+        cardinals.extend(by_value(data.tens(), ordinal_tables));
+        // `deca` and `kilo` are in the same table as `tenth` and `thousandth`
+        let prefixes = data.suffixes_by_name();
+        let mut ordinals = HashMap::new();
+        for (value, ordinal) in by_value(data.ordinals(), prefixes) {
+            if let Some(cardinal) = cardinals.get(&value) {
+                ordinals.insert(cardinal.clone(), ordinal);
+            }
+        }
+        for (exponent, ordinal) in by_exponent(data.ordinal_multiples(), prefixes) {
+            let cardinal = if exponent == 2 { Some(&hundred) } else { scales.get(&(exponent / 3)) };
+            if let Some(cardinal) = cardinal {
+                ordinals.insert(cardinal.clone(), ordinal);
+            }
+        }
+
+        Speller {
+            british_and: config.british_and,
+            hyphenate: config.hyphenate,
+            small,
+            tens,
+            hundred,
+            scales,
+            ordinals,
+            and: data.ands()[0].clone(),
+            point: data.points()[0].clone(),
+            negative: data.negatives()[0].clone(),
+            year_zero: data.year_zero().clone(),
+        }
+    }
+
+    fn cardinal(&self, number: &BigInt) -> Option<String> {
+        if number.is_zero() {
+            return self.small.get(&0).cloned();
+        }
+        let mut groups = Vec::new();
+        let mut rest = number.abs();
+        let thousand = BigInt::from(1000);
+        while !rest.is_zero() {
+            groups.push((&rest % &thousand).to_u32()?);
+            rest /= &thousand;
+        }
+        let mut words = Vec::new();
+        for (scale, &group) in groups.iter().enumerate().rev() {
+            if group == 0 {
+                continue;
+            }
+            // `one thousand and five`
+            if self.british_and && scale == 0 && group < 100 && groups.len() > 1 {
+                words.push(self.and.clone());
+            }
+            words.push(self.below_thousand(group)?);
+            if scale > 0 {
+                words.push(self.scales.get(&(scale as u32))?.clone());
+            }
+        }
+        if number.is_negative() {
+            words.insert(0, self.negative.clone());
+        }
+        Some(words.join(" "))
+    }
+
+    fn below_thousand(&self, number: u32) -> Option<String> {
+        let (hundreds, rest) = (number / 100, number % 100);
+        let mut words = Vec::new();
+        if hundreds > 0 {
+            words.push(self.small.get(&hundreds)?.clone());
+            words.push(self.hundred.clone());
+            if rest > 0 && self.british_and {
+                words.push(self.and.clone());
+            }
+        }
+        if rest > 0 {
+            words.push(self.below_hundred(rest)?);
+        }
+        Some(words.join(" "))
+    }
+
+    fn below_hundred(&self, number: u32) -> Option<String> {
+        if number < 20 {
+            return self.small.get(&number).cloned();
+        }
+        let tens = self.tens.get(&(number / 10 * 10))?;
+        match number % 10 {
+            0 => Some(tens.clone()),
+            ones => {
+                let separator = if self.hyphenate { "-" } else { " " };
+                Some(format!("{tens}{separator}{}", self.small.get(&ones)?))
+            }
+        }
+    }
+
+    fn ordinal(&self, number: &BigInt) -> Option<String> {
+        let cardinal = self.cardinal(number)?;
+        // only the last word changes: `twenty-one` -> `twenty-first`
+        let split = cardinal.rfind([' ', '-']).map_or(0, |i| i + 1);
+        let (head, last) = cardinal.split_at(split);
+        let ordinal = match self.ordinals.get(last) {
+            Some(ordinal) => ordinal.clone(),
+            None => match last.strip_suffix('y') {
+                Some(stem) => format!("{stem}ieth"),
+                None => format!("{last}th"),
+            },
+        };
+        Some(format!("{head}{ordinal}"))
+    }
+
+    fn year(&self, number: &BigInt) -> Option<String> {
+        let year = number.to_u32().filter(|y| (1000..10000).contains(y));
+        let Some(year) = year else {
+            return self.cardinal(number);
+        };
+        let (high, low) = (year / 100, year % 100);
+        match low {
+            // `two thousand`, `two thousand five`
+            _ if high % 10 == 0 && low < 10 => self.cardinal(number),
+            // `nineteen hundred`
+            0 => Some(format!("{} {}", self.below_hundred(high)?, self.hundred)),
+            // `nineteen oh five`
+            1..=9 => Some(format!("{} {} {}", self.below_hundred(high)?, self.year_zero, self.small.get(&low)?)),
+            _ => Some(format!("{} {}", self.below_hundred(high)?, self.below_hundred(low)?)),
+        }
+    }
+
+    fn decimal(&self, value: &NumberValue) -> Option<String> {
+        let written = match value {
+            // the shortest representation, not the exact binary fraction
+            NumberValue::Float { value, .. } if value.is_finite() => value.to_string(),
+            NumberValue::Float { .. } => return None,
+            value => match NumberValue::from_ratio(value.to_ratio()?) {
+                exact @ (NumberValue::Integer { .. } | NumberValue::BigInteger { .. } | NumberValue::Decimal { .. }) => {
+                    exact.to_string()
+                }
+                _ => return None,
+            },
+        };
+        let (whole, fraction) = written.split_once('.').unwrap_or((&written, ""));
+        let mut words = self.cardinal(&whole.parse().ok()?)?;
+        if whole.starts_with('-') && whole.trim_start_matches(['-', '0']).is_empty() {
+            // `-0.5`
+            words = format!("{} {words}", self.negative);
+        }
+        if !fraction.is_empty() {
+            words.push(' ');
+            words.push_str(&self.point);
+            for digit in fraction.chars() {
+                words.push(' ');
+                words.push_str(self.small.get(&digit.to_digit(10)?)?);
+            }
+        }
+        Some(words)
+    }
+}
+
+/// Inverts a table, skipping the words of `skip`, eg: the ordinals mixed into `ones`.
+fn by_value(table: &HashMap<String, f64>, skip: &HashMap<String, f64>) -> BTreeMap<u32, String> {
+    let mut words: Vec<(&String, f64)> = table.iter().filter(|(k, _)| !skip.contains_key(*k)).map(|(k, v)| (k, *v)).collect();
+    words.sort_by(|a, b| a.0.cmp(b.0));
+    let mut inverted = BTreeMap::new();
+    for (word, value) in words {
+        if value.fract() == 0.0 && (0.0..1000.0).contains(&value) {
+            inverted.entry(value as u32).or_insert_with(|| word.clone());
+        }
+    }
+    inverted
+}
+
+/// Like `by_value` for the multiples, keyed by their power of ten.
+fn by_exponent(table: &HashMap<String, f64>, skip: &HashMap<String, f64>) -> BTreeMap<u32, String> {
+    let mut words: Vec<(&String, f64)> = table.iter().filter(|(k, _)| !skip.contains_key(*k)).map(|(k, v)| (k, *v)).collect();
+    words.sort_by(|a, b| a.0.cmp(b.0));
+    let mut inverted = BTreeMap::new();
+    for (word, value) in words {
+        let exponent = value.log10().round();
+        if value >= 100.0 && (value / 10f64.powf(exponent) - 1.0).abs() < 1e-9 {
+            inverted.entry(exponent as u32).or_insert_with(|| word.clone());
+        }
+    }
+    inverted
+}
@@ -0,0 +1,90 @@
+use num_rational::BigRational;
+use num_traits::ToPrimitive;
+
+use crate::parsers::number::{ascii_digits, words2exact, words2fraction, Data, NumberForm, NumberValue};
+use crate::parsers::number::exact::parse_numeral;
+
+/// Parses an ordinal to its cardinal value, marked with `form.ordinal`.
+///
+/// Spoken (`twenty-first`, `one hundredth`, `three hundred and fifth`) and numerals
+/// (`42nd`, `1,000th`). A numeral with the wrong suffix (`21th`) is rejected unless
+/// `Config::lenient_ordinals` is set, `one third` is a fraction and not an ordinal.
+pub fn words2ordinal(text: &str, data: &Data) -> Option<NumberValue> {
+    let text = text.trim();
+    let numeral = &data.regexes().ordinal_numeral;
+    if let Some(caps) = numeral.captures(text).ok().flatten() {
+        // `१२३rd` is checked as `123rd`
+        let number = ascii_digits(caps.name("number")?.as_str()).replace([',', '\'', '_'], "");
+        let suffix = caps.name("ordinal")?.as_str().to_lowercase();
+        let digits = number.trim_start_matches(['-', '+']);
+        let last_two = digits[digits.len().saturating_sub(2)..].parse::<u64>().ok()?;
+        if !data.config().lenient_ordinals.unwrap_or(false) && !data.is_ordinal_suffix(last_two, &suffix) {
+            return None;
+        }
+        return ordinal_value(parse_numeral(&number)?, false, data);
+    }
+
+    let ordinals = data.ordinals();
+    let lower = text.to_lowercase();
+    let words: Vec<&str> = lower
+        .split(|c: char| c.is_whitespace() || c == '-')
+        .filter(|w| !w.is_empty())
+        .collect();
+    let (last, rest) = words.split_last()?;
+    // only the last word is an ordinal: `twenty third` but not `third hundred`
+    if !ordinals.contains_key(*last) || rest.iter().any(|w| ordinals.contains_key(*w)) {
+        return None;
+    }
+    if words2fraction(text, data).is_some() {
+        return None;
+    }
+    ordinal_value(words2exact(text, data)?, true, data)
+}
+
+fn ordinal_value(value: BigRational, spoken: bool, data: &Data) -> Option<NumberValue> {
+    if !value.is_integer() {
+        return None;
+    }
+    let number = if data.config().exact.unwrap_or(false) {
+        NumberValue::from_ratio(value)
+    } else {
+        NumberValue::from_float(value.to_f64()?)
+    };
+    Some(number.with_form(NumberForm { ordinal: true, spoken, ..NumberForm::default() }))
+}
+
+/// Words after `a second` that leave it a moment: `wait a second please`, `a second ago`.
+const AFTER_A_SECOND: [&str; 26] = [
+    "to", "please", "and", "or", "ago", "later", "longer", "more", "before", "after", "now", "then", "of",
+    "for", "in", "on", "at", "with", "while", "so", "but", "if", "when", "till", "until", "here",
+];
+
+/// Whether the bare `second` at `span` is the unit of time and not the ordinal: `one second
+/// please`, `give me a second`, `5 per second`, where `the second time` and `a second chance`
+/// are ordinals.
+pub(crate) fn is_time_second(text: &str, span: (usize, usize), data: &Data) -> bool {
+    if !text.get(span.0..span.1).is_some_and(|s| s.eq_ignore_ascii_case("second")) {
+        return false;
+    }
+    let before = text.get(..span.0).unwrap_or("").to_lowercase();
+    let Some(previous) = before.split_whitespace().last() else {
+        return false;
+    };
+    let cardinal = previous.bytes().all(|b| b.is_ascii_digit() || b == b'.' || b == b',')
+        || (data.all_nums().contains_key(previous) && !data.ordinals().contains_key(previous));
+    if cardinal || previous == "per" {
+        return true;
+    }
+    if !data.a().iter().any(|a| a == previous) && previous != "an" {
+        return false;
+    }
+    // `a second chance` is counted, `a second, please` and `a second` are not
+    let after = text.get(span.1..).unwrap_or("");
+    if after.starts_with(|c: char| !c.is_whitespace()) {
+        return true;
+    }
+    match after.split_whitespace().next().map(str::to_lowercase) {
+        Some(next) if next.chars().all(char::is_alphabetic) => AFTER_A_SECOND.contains(&next.as_str()),
+        _ => true,
+    }
+}
@@ -0,0 +1,123 @@
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::ToPrimitive;
+
+use crate::parsers::number::{Config, NumberEntityParser, NumberMatch, NumberParser, NumberValue};
+use crate::parsers::number::constants::PERCENTAGE;
+
+/// What a percentage is counted in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PercentUnit {
+    /// `12%`, `twelve percent`, `0.5 per cent`
+    Percent,
+    /// `3‰`, `3 per mille`
+    PerMille,
+    /// `25 bps`, `25 basis points`
+    BasisPoints,
+    /// `3 percentage points`, `3pp`: the difference between two percentages, not a share
+    /// of something, so `up 3 percentage points` from 5% is 8% where `up 3%` is 5.15%.
+    /// A bare `pp` is only read next to a percent, it is more often pages: `see 300 pp.`
+    PercentagePoints,
+}
+
+impl PercentUnit {
+    /// How many of the unit make a whole.
+    pub fn per(&self) -> i64 {
+        match self {
+            PercentUnit::Percent | PercentUnit::PercentagePoints => 100,
+            PercentUnit::PerMille => 1_000,
+            PercentUnit::BasisPoints => 10_000,
+        }
+    }
+}
+
+/// A number followed by a percent sign or word, with its value as a fraction of one.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Percentage {
+    pub text: String,
+    pub span: (usize, usize),
+    pub number: NumberMatch,
+    pub unit: PercentUnit,
+    /// `12%` -> 0.12, `25 bps` -> 0.0025
+    pub value: NumberValue,
+}
+
+/// Longest first, so `percentage points` isn't read as `percent`.
+const UNITS: [(&str, PercentUnit); 19] = [
+    ("percentage points", PercentUnit::PercentagePoints),
+    ("percentage point", PercentUnit::PercentagePoints),
+    ("basis points", PercentUnit::BasisPoints),
+    ("basis point", PercentUnit::BasisPoints),
+    ("per thousand", PercentUnit::PerMille),
+    ("per mille", PercentUnit::PerMille),
+    ("per mil", PercentUnit::PerMille),
+    ("permille", PercentUnit::PerMille),
+    ("per cent", PercentUnit::Percent),
+    ("percent", PercentUnit::Percent),
+    ("p.p.", PercentUnit::PercentagePoints),
+    ("pct", PercentUnit::Percent),
+    ("bps", PercentUnit::BasisPoints),
+    ("pp", PercentUnit::PercentagePoints),
+    ("bp", PercentUnit::BasisPoints),
+    ("%", PercentUnit::Percent),
+    ("％", PercentUnit::Percent),
+    ("‰", PercentUnit::PerMille),
+    ("‱", PercentUnit::BasisPoints),
+];
+
+/// Signs and words that make a bare `pp` percentage points rather than pages.
+const PERCENT_CONTEXT: [&str; 5] = ["%", "％", "percent", "per cent", "pct"];
+
+/// The numbers of `text` followed by a percent unit, see [`PercentUnit`].
+///
+/// The unit may be attached (`12%`, `25bps`) or follow a space (`12 %`, `twelve percent`).
+/// The values are exact fractions when `exact` is set, as with `Config::exact`, and floats
+/// otherwise.
+pub fn find_percentages(numbers: &[NumberMatch], text: &str, exact: bool) -> Vec<Percentage> {
+    let lower = text.to_lowercase();
+    let percent_context = PERCENT_CONTEXT.iter().any(|sign| lower.contains(sign));
+    numbers.iter().filter_map(|number| percentage_of(number, text, exact, percent_context)).collect()
+}
+
+fn percentage_of(number: &NumberMatch, text: &str, exact: bool, percent_context: bool) -> Option<Percentage> {
+    let after = &text[number.span.1..];
+    let rest = after.trim_start();
+    let (marker, unit) = UNITS.iter().find(|(marker, _)| {
+        let word = marker.starts_with(char::is_alphabetic);
+        rest.get(..marker.len()).is_some_and(|start| start.eq_ignore_ascii_case(marker))
+            && !(word && rest[marker.len()..].starts_with(char::is_alphanumeric))
+            && (*marker != "pp" || percent_context)
+    })?;
+    let end = number.span.1 + after.len() - rest.len() + marker.len();
+    let ratio = number.value.to_ratio()? / BigRational::from_integer(BigInt::from(unit.per()));
+    let value = if exact {
+        NumberValue::from_ratio(ratio)
+    } else {
+        NumberValue::from_float(ratio.to_f64()?)
+    };
+    Some(Percentage {
+        text: text[number.span.0..end].to_string(),
+        span: (number.span.0, end),
+        number: number.clone(),
+        unit: *unit,
+        value,
+    })
+}
+
+impl NumberParser {
+    /// The percentages of `text`, see [`find_percentages`].
+    pub fn extract_percentages(&mut self, text: &str) -> Vec<Percentage> {
+        let numbers = self.extract(text);
+        let exact = self.data().config().exact.unwrap_or(false);
+        find_percentages(&numbers, text, exact)
+    }
+}
+
+impl NumberEntityParser {
+    /// Finds percentages as `percentage` entities, see [`find_percentages`].
+    pub fn percentages(config: Config) -> Self {
+        NumberEntityParser::new(config, PERCENTAGE, |parser, text| {
+            parser.extract_percentages(text).into_iter().map(|p| (p.text, p.span)).collect()
+        })
+    }
+}
@@ -0,0 +1,179 @@
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::{One, Pow, ToPrimitive, Zero};
+
+use crate::parsers::number::{Data, NumberForm, NumberMatch, NumberValue};
+use crate::parsers::number::constants::{POWER, SUPERSCRIPT};
+use crate::parsers::number::exact::{parse_numeral, MAX_EXPONENT};
+use crate::parsers::number::locale::canonical_numerals;
+
+/// Signs between a mantissa and a power of ten: `5 × 10^3`, `5x10⁻³`, `five times ten to the sixth`.
+const TIMES: [&str; 7] = ["×", "x", "X", "*", "·", "⋅", "times"];
+
+/// Words between a base and its exponent, the ones of `to the` need an ordinal or a
+/// negative exponent: `ten to the sixth`, `ten to the minus three`, `10 to the -3`.
+const EXPONENT_WORDS: [&str; 5] = ["to the power of", "to the power", "raised to the power of", "raised to the", "to the"];
+
+/// A number written as a power: `mantissa × base^exponent`.
+///
+/// `5 × 10^3`, `5x10³` and `five times ten to the power of three` are all 5 × 10^3, `10⁶`
+/// and `ten to the sixth` are 1 × 10^6 and `two squared` is 1 × 2^2. `1.2e-3` is 1.2 × 10^-3.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Power {
+    pub mantissa: NumberValue,
+    pub base: NumberValue,
+    pub exponent: i32,
+}
+
+impl Power {
+    /// `mantissa × base^exponent`, `None` for zero to a negative power.
+    pub fn to_ratio(&self) -> Option<BigRational> {
+        raise(self.mantissa.to_ratio()?, self.base.to_ratio()?, self.exponent)
+    }
+}
+
+fn raise(mantissa: BigRational, base: BigRational, exponent: i32) -> Option<BigRational> {
+    if base.is_zero() && exponent < 0 {
+        return None;
+    }
+    Some(mantissa * Pow::pow(base, exponent))
+}
+
+/// The mantissa and exponent of a numeral in e-notation: `1.2e-3`, `6.02E23`. `None` for
+/// exponents beyond a thousand, like [`parse_numeral`].
+pub fn e_notation(text: &str, exact: bool) -> Option<Power> {
+    let (mantissa, exponent) = text.split_once(['e', 'E'])?;
+    let exponent = exponent.parse::<i32>().ok().filter(|e| e.unsigned_abs() <= MAX_EXPONENT)?;
+    let mantissa = parse_numeral(mantissa)?;
+    Some(Power { mantissa: value_of(mantissa, exact)?, base: NumberValue::from(10), exponent })
+}
+
+fn value_of(ratio: BigRational, exact: bool) -> Option<NumberValue> {
+    if exact {
+        Some(NumberValue::from_ratio(ratio))
+    } else {
+        Some(NumberValue::from_float(ratio.to_f64()?))
+    }
+}
+
+/// Joins the numbers of a power into one: `5 × 10^3`, `10⁶`, `2**10`, `ten to the power of
+/// six`, `ten to the minus three`, `two squared`, `three cubed`.
+///
+/// A mantissa is only taken before a power of ten. Superscript exponents (`10²³`, `10⁻³`)
+/// are read with [`Data::superscript_ones`].
+pub fn merge_powers(numbers: Vec<NumberMatch>, text: &str, data: &Data) -> Vec<NumberMatch> {
+    let exact = data.config().exact.unwrap_or(false);
+    let mut merged: Vec<NumberMatch> = Vec::new();
+    let mut i = 0;
+    while i < numbers.len() {
+        let base = &numbers[i];
+        let exponent = match numbers.get(i + 1).and_then(|next| exponent_of(base, next, text, data)) {
+            Some(found) => {
+                i += 2;
+                Some(found)
+            }
+            None => {
+                i += 1;
+                squared_or_cubed(base, text)
+            }
+        };
+        let Some((exponent, end, spoken)) = exponent else {
+            merged.push(base.clone());
+            continue;
+        };
+        // `5 × 10^3`
+        let ten = base.value.to_ratio().is_some_and(|b| b == BigRational::from_integer(BigInt::from(10)));
+        let mantissa = merged
+            .last()
+            .filter(|m| ten && text.get(m.span.1..base.span.0).is_some_and(|between| TIMES.contains(&between.trim())))
+            .cloned();
+        let start = match &mantissa {
+            Some(m) => {
+                merged.pop();
+                m.span.0
+            }
+            None => base.span.0,
+        };
+        let power = Power {
+            mantissa: mantissa.as_ref().map_or(NumberValue::from(1), |m| m.value.clone()),
+            base: base.value.clone(),
+            exponent,
+        };
+        // from the digits, so `1.2 × 10^-3` is as exact as `1.2e-3`
+        let digits = |number: &NumberMatch| {
+            parse_numeral(&canonical_numerals(&number.text, data)).or_else(|| number.value.to_ratio())
+        };
+        let ratio = match &mantissa {
+            Some(m) => digits(m),
+            None => Some(BigRational::one()),
+        };
+        let Some(value) = ratio
+            .zip(digits(base))
+            .and_then(|(m, b)| raise(m, b, exponent))
+            .and_then(|ratio| value_of(ratio, exact))
+        else {
+            merged.push(base.clone());
+            continue;
+        };
+        let spoken = spoken || base.value.is_spoken() || mantissa.as_ref().is_some_and(|m| m.value.is_spoken());
+        merged.push(NumberMatch {
+            text: text[start..end].to_string(),
+            span: (start, end),
+            value: value.with_form(NumberForm { spoken, ..NumberForm::default() }),
+            number_type: POWER,
+            qualifier: None,
+            power: Some(power),
+        });
+    }
+    merged
+}
+
+/// The exponent `next` puts on `base`, where the power ends and whether the exponent was spoken.
+fn exponent_of(base: &NumberMatch, next: &NumberMatch, text: &str, data: &Data) -> Option<(i32, usize, bool)> {
+    let exponent = next.value.to_ratio().filter(|e| e.is_integer())?.to_integer().to_i32()?;
+    let between = text.get(base.span.1..next.span.0)?.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase();
+    let superscript = next.number_type == SUPERSCRIPT
+        && next.text.chars().all(|c| data.superscript_ones().contains_key(&c));
+    let (sign, worded) = match between.as_str() {
+        "^" | "**" => (1, false),
+        "^ -" | "^-" | "^−" | "^ −" | "**-" | "** -" => (-1, false),
+        "" | "⁺" if superscript => (1, false),
+        "⁻" if superscript => (-1, false),
+        words => {
+            // `ten to the minus three`, `10 to the power of -3`
+            let minus = [" minus", " negative", " -", " −"].iter().find_map(|sign| words.strip_suffix(sign));
+            let (words, sign) = match minus {
+                Some(words) => (words, -1),
+                None => (words, 1),
+            };
+            if !EXPONENT_WORDS.contains(&words) {
+                return None;
+            }
+            // `ten to the sixth (power)`, `ten to the minus three`, not `10 to the 3 floors`
+            if words == "to the" && !(next.value.is_ordinal() || exponent < 0 || sign < 0) {
+                return None;
+            }
+            (sign, true)
+        }
+    };
+    let mut end = next.span.1;
+    let after = &text[end..];
+    let rest = after.trim_start();
+    if worded && rest.get(..5).is_some_and(|w| w.eq_ignore_ascii_case("power")) && !rest[5..].starts_with(char::is_alphanumeric) {
+        end += after.len() - rest.len() + 5;
+    }
+    let exponent = sign * exponent;
+    (exponent.unsigned_abs() <= MAX_EXPONENT).then_some((exponent, end, next.value.is_spoken()))
+}
+
+/// `two squared`, `3 cubed`
+fn squared_or_cubed(base: &NumberMatch, text: &str) -> Option<(i32, usize, bool)> {
+    let after = &text[base.span.1..];
+    let rest = after.trim_start();
+    let start = base.span.1 + after.len() - rest.len();
+    [("squared", 2), ("cubed", 3)].iter().find_map(|&(word, exponent)| {
+        let follows = rest.get(..word.len()).is_some_and(|w| w.eq_ignore_ascii_case(word))
+            && !rest[word.len()..].starts_with(char::is_alphanumeric);
+        follows.then_some((exponent, start + word.len(), false))
+    })
+}
@@ -0,0 +1,178 @@
+use std::ops::Bound;
+
+use crate::parsers::number::NumberMatch;
+use self::QualifierKind::*;
+
+/// How a qualifier bends the number it comes with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QualifierKind {
+    /// `about 30`, `nearly two million`, `~5`
+    Approximate,
+    /// `more than 5` (strict), `at least 5`, `5 or more` (inclusive)
+    LowerBound { inclusive: bool },
+    /// `under 18` (strict), `no more than 10`, `up to a dozen` (inclusive)
+    UpperBound { inclusive: bool },
+}
+
+/// The words or symbol qualifying a number, with their own span: `more than` in `more than 5`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Qualifier {
+    pub kind: QualifierKind,
+    pub text: String,
+    pub span: (usize, usize),
+}
+
+const BEFORE: [(&str, QualifierKind); 50] = [
+    ("about", Approximate),
+    ("around", Approximate),
+    ("approximately", Approximate),
+    ("approx.", Approximate),
+    ("approx", Approximate),
+    ("roughly", Approximate),
+    ("nearly", Approximate),
+    ("almost", Approximate),
+    ("close to", Approximate),
+    ("circa", Approximate),
+    ("ca.", Approximate),
+    ("~", Approximate),
+    ("≈", Approximate),
+    ("more than", LowerBound { inclusive: false }),
+    ("greater than", LowerBound { inclusive: false }),
+    ("over", LowerBound { inclusive: false }),
+    ("above", LowerBound { inclusive: false }),
+    ("exceeding", LowerBound { inclusive: false }),
+    ("upwards of", LowerBound { inclusive: false }),
+    ("in excess of", LowerBound { inclusive: false }),
+    (">", LowerBound { inclusive: false }),
+    ("at least", LowerBound { inclusive: true }),
+    ("no less than", LowerBound { inclusive: true }),
+    ("not less than", LowerBound { inclusive: true }),
+    ("no fewer than", LowerBound { inclusive: true }),
+    ("not fewer than", LowerBound { inclusive: true }),
+    ("a minimum of", LowerBound { inclusive: true }),
+    ("minimum of", LowerBound { inclusive: true }),
+    ("min.", LowerBound { inclusive: true }),
+    (">=", LowerBound { inclusive: true }),
+    ("≥", LowerBound { inclusive: true }),
+    ("⩾", LowerBound { inclusive: true }),
+    ("less than", UpperBound { inclusive: false }),
+    ("fewer than", UpperBound { inclusive: false }),
+    ("lower than", UpperBound { inclusive: false }),
+    ("under", UpperBound { inclusive: false }),
+    ("below", UpperBound { inclusive: false }),
+    ("<", UpperBound { inclusive: false }),
+    ("at most", UpperBound { inclusive: true }),
+    ("no more than", UpperBound { inclusive: true }),
+    ("not more than", UpperBound { inclusive: true }),
+    ("no greater than", UpperBound { inclusive: true }),
+    ("not exceeding", UpperBound { inclusive: true }),
+    ("up to", UpperBound { inclusive: true }),
+    ("a maximum of", UpperBound { inclusive: true }),
+    ("maximum of", UpperBound { inclusive: true }),
+    ("max.", UpperBound { inclusive: true }),
+    ("<=", UpperBound { inclusive: true }),
+    ("≤", UpperBound { inclusive: true }),
+    ("⩽", UpperBound { inclusive: true }),
+];
+
+const AFTER: [(&str, QualifierKind); 14] = [
+    ("or more", LowerBound { inclusive: true }),
+    ("or greater", LowerBound { inclusive: true }),
+    ("or above", LowerBound { inclusive: true }),
+    ("or over", LowerBound { inclusive: true }),
+    ("and up", LowerBound { inclusive: true }),
+    ("and above", LowerBound { inclusive: true }),
+    ("and over", LowerBound { inclusive: true }),
+    ("or less", UpperBound { inclusive: true }),
+    ("or fewer", UpperBound { inclusive: true }),
+    ("or below", UpperBound { inclusive: true }),
+    ("or under", UpperBound { inclusive: true }),
+    ("and below", UpperBound { inclusive: true }),
+    ("and under", UpperBound { inclusive: true }),
+    ("or so", Approximate),
+];
+
+fn is_word(phrase: &str) -> bool {
+    phrase.starts_with(char::is_alphabetic)
+}
+
+/// The qualifier of the number at `span` in `text`, if any.
+///
+/// Phrases before the number win over the ones after it (`5 or more`, `5+`), and the longest
+/// one wins, so `no more than 10` is an inclusive upper bound and not `more than 10`. An
+/// article between the phrase and the number is skipped: `over a hundred`. Phrasing is English.
+pub fn find_qualifier(text: &str, span: (usize, usize)) -> Option<Qualifier> {
+    qualifier_before(text, span.0).or_else(|| qualifier_after(text, span.1))
+}
+
+fn qualifier_before(text: &str, start: usize) -> Option<Qualifier> {
+    let mut before = text[..start].trim_end();
+    for article in [" a", " an"] {
+        if before.len() > article.len() && ends_with_ignore_case(before, article) {
+            before = before[..before.len() - article.len()].trim_end();
+            break;
+        }
+    }
+    BEFORE
+        .iter()
+        .filter(|(phrase, _)| {
+            ends_with_ignore_case(before, phrase)
+                && (!is_word(phrase)
+                    || !before[..before.len() - phrase.len()].ends_with(char::is_alphanumeric))
+        })
+        .max_by_key(|(phrase, _)| phrase.len())
+        .map(|&(phrase, kind)| {
+            let span = (before.len() - phrase.len(), before.len());
+            Qualifier { kind, text: text[span.0..span.1].to_string(), span }
+        })
+}
+
+fn qualifier_after(text: &str, end: usize) -> Option<Qualifier> {
+    let after = &text[end..];
+    // `5+ years` but not `5+3`
+    if after.starts_with('+') && !after[1..].starts_with(char::is_alphanumeric) {
+        let kind = LowerBound { inclusive: true };
+        return Some(Qualifier { kind, text: "+".to_string(), span: (end, end + 1) });
+    }
+    let rest = after.trim_start();
+    let start = end + after.len() - rest.len();
+    AFTER
+        .iter()
+        .find(|(phrase, _)| {
+            starts_with_ignore_case(rest, phrase)
+                && !rest[phrase.len()..].starts_with(char::is_alphanumeric)
+        })
+        .map(|&(phrase, kind)| {
+            let span = (start, start + phrase.len());
+            Qualifier { kind, text: text[span.0..span.1].to_string(), span }
+        })
+}
+
+fn ends_with_ignore_case(text: &str, suffix: &str) -> bool {
+    text.len()
+        .checked_sub(suffix.len())
+        .and_then(|start| text.get(start..))
+        .is_some_and(|end| end.eq_ignore_ascii_case(suffix))
+}
+
+fn starts_with_ignore_case(text: &str, prefix: &str) -> bool {
+    text.get(..prefix.len()).is_some_and(|start| start.eq_ignore_ascii_case(prefix))
+}
+
+impl NumberMatch {
+    /// The values the number stands for, usable as a `RangeBounds<f64>`.
+    ///
+    /// A bare number is its own bounds, `more than 5` is `(Excluded(5), Unbounded)` and
+    /// `up to 10` is `(Unbounded, Included(10))`. `None` for approximations, which have no
+    /// exact bounds, and for numbers with no real value.
+    pub fn bounds(&self) -> Option<(Bound<f64>, Bound<f64>)> {
+        let value = self.value.to_f64()?;
+        let bound = |inclusive| if inclusive { Bound::Included(value) } else { Bound::Excluded(value) };
+        match self.qualifier.as_ref().map(|q| q.kind) {
+            None => Some((Bound::Included(value), Bound::Included(value))),
+            Some(Approximate) => None,
+            Some(LowerBound { inclusive }) => Some((bound(inclusive), Bound::Unbounded)),
+            Some(UpperBound { inclusive }) => Some((Bound::Unbounded, bound(inclusive))),
+        }
+    }
+}
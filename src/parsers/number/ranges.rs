@@ -0,0 +1,123 @@
+use crate::parsers::number::{Config, NumberEntityParser, NumberMatch, NumberParser};
+use crate::parsers::number::constants::RANGE;
+
+const DASHES: [char; 3] = ['-', '–', '—'];
+
+/// Two numbers read as the bounds of a range: `5-7`, `from 3 to 9`, `between ten and twenty`.
+///
+/// The bounds are inclusive unless the range is followed by `exclusive`: `between 5 and 7
+/// exclusive` leaves out both, `1 to 10 exclusive` only the upper one. The lower bound is
+/// the smaller number, `from 10 to 5 exclusive` is 5 to 10 without the 5.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NumberRange {
+    pub text: String,
+    pub span: (usize, usize),
+    pub lower: NumberMatch,
+    pub upper: NumberMatch,
+    pub lower_inclusive: bool,
+    pub upper_inclusive: bool,
+}
+
+impl NumberRange {
+    /// Whether `value` is inside the range, `None` when a bound has no real value.
+    pub fn contains(&self, value: f64) -> Option<bool> {
+        let (lower, upper) = (self.lower.value.to_f64()?, self.upper.value.to_f64()?);
+        let above = if self.lower_inclusive { value >= lower } else { value > lower };
+        let below = if self.upper_inclusive { value <= upper } else { value < upper };
+        Some(above && below)
+    }
+}
+
+/// Pairs up the consecutive numbers of `text` that form a range.
+///
+/// The numbers are joined by a hyphen or dash (`5-7`, `5–7`), `to`, `through` or `thru`
+/// (`twenty-one to thirty`), or by `and` after `between`. A leading `from` or `between`
+/// is part of the range. A dash only joins two increasing numbers outside of a list or a
+/// sum, so `2019-20`, `1-2-3` and `5 - 7 = -2` aren't ranges.
+pub fn find_ranges(numbers: &[NumberMatch], text: &str) -> Vec<NumberRange> {
+    let mut ranges = Vec::new();
+    let mut i = 0;
+    while i + 1 < numbers.len() {
+        match range_of(&numbers[i], &numbers[i + 1], text) {
+            Some(range) => {
+                ranges.push(range);
+                i += 2;
+            }
+            None => i += 1,
+        }
+    }
+    ranges
+}
+
+fn range_of(lower: &NumberMatch, upper: &NumberMatch, text: &str) -> Option<NumberRange> {
+    let (low, high) = (lower.value.to_f64()?, upper.value.to_f64()?);
+    let between = text.get(lower.span.1..upper.span.0)?.trim().to_lowercase();
+    let before = text[..lower.span.0].trim_end();
+    let prefix = before.rsplit(char::is_whitespace).next().unwrap_or_default().to_lowercase();
+    let next = text[upper.span.1..].trim_start();
+    let joined = match between.as_str() {
+        // `1-2-3` is a list and `5 - 7 = -2` a sum
+        "-" | "–" | "—" => low < high
+            && !before.ends_with(DASHES)
+            && !next.starts_with(DASHES)
+            && !next.starts_with('='),
+        "to" | "through" | "thru" => true,
+        "and" => prefix == "between",
+        _ => false,
+    };
+    if !joined {
+        return None;
+    }
+    let start = match prefix.as_str() {
+        "from" | "between" => before.len() - prefix.len(),
+        _ => lower.span.0,
+    };
+
+    let (mut end, mut lower_inclusive, mut upper_inclusive) = (upper.span.1, true, true);
+    let after = &text[end..];
+    let rest = after.trim_start_matches([' ', ',', '(']);
+    for (word, inclusive) in [("inclusive", true), ("exclusive", false)] {
+        let follows = rest.get(..word.len()).is_some_and(|w| w.eq_ignore_ascii_case(word))
+            && !rest[word.len()..].starts_with(char::is_alphanumeric);
+        if follows {
+            end += after.len() - rest.len() + word.len();
+            if after.trim_start().starts_with('(') && text[end..].starts_with(')') {
+                end += 1;
+            }
+            upper_inclusive = inclusive;
+            lower_inclusive = inclusive || prefix != "between";
+        }
+    }
+    // `from 10 to 5` counts down, its bounds swap with what they leave out
+    let (lower, upper) = if low > high {
+        (lower_inclusive, upper_inclusive) = (upper_inclusive, lower_inclusive);
+        (upper, lower)
+    } else {
+        (lower, upper)
+    };
+    Some(NumberRange {
+        text: text[start..end].to_string(),
+        span: (start, end),
+        lower: lower.clone(),
+        upper: upper.clone(),
+        lower_inclusive,
+        upper_inclusive,
+    })
+}
+
+impl NumberParser {
+    /// The ranges of `text`, see [`find_ranges`].
+    pub fn extract_ranges(&mut self, text: &str) -> Vec<NumberRange> {
+        let numbers = self.extract(text);
+        find_ranges(&numbers, text)
+    }
+}
+
+impl NumberEntityParser {
+    /// Finds ranges as `range` entities, see [`find_ranges`].
+    pub fn ranges(config: Config) -> Self {
+        NumberEntityParser::new(config, RANGE, |parser, text| {
+            parser.extract_ranges(text).into_iter().map(|r| (r.text, r.span)).collect()
+        })
+    }
+}
@@ -0,0 +1,125 @@
+use fancy_regex::Regex;
+use lazy_static::lazy_static;
+
+use crate::parsers::number::{Data, NumberForm, NumberValue};
+use crate::parsers::number::normalize::SpannedText;
+
+lazy_static! {
+    static ref STRICT: Regex =
+        Regex::new(r"^(?=[MDCLXVI])M{0,3}(?:CM|CD|D?C{0,3})(?:XC|XL|L?X{0,3})(?:IX|IV|V?I{0,3})$").unwrap();
+    // uppercase only, `mix` and `civil` are words, and `XVI's` is a possessive
+    static ref CANDIDATE: Regex =
+        Regex::new(r"(?<![\p{L}\d'’])[MDCLXVI]+(?![\p{L}\d]|['’](?!s(?![\p{L}\d])))").unwrap();
+}
+
+/// Words after which a numeral is always read as one: `Chapter IX`, `World War I`, `No. V`.
+const HEADINGS: [&str; 17] = [
+    "chapter", "part", "book", "volume", "vol.", "act", "scene", "article", "section",
+    "appendix", "annex", "title", "phase", "stage", "war", "no.", "vol",
+];
+
+/// Valid numerals that are more often words or abbreviations: `MIX`, `DC`, `CV`, `XL`.
+const FALSE_FRIENDS: [&str; 15] = [
+    "MIX", "DIX", "LIV", "CIV", "DI", "MI", "LI", "CD", "DC", "MD", "CV", "CC", "CM", "MM", "XL",
+];
+
+fn digit(c: char) -> Option<u64> {
+    match c {
+        'I' => Some(1),
+        'V' => Some(5),
+        'X' => Some(10),
+        'L' => Some(50),
+        'C' => Some(100),
+        'D' => Some(500),
+        'M' => Some(1000),
+        _ => None,
+    }
+}
+
+/// The value of an uppercase Roman numeral.
+///
+/// Strict numerals are in the standard form up to `MMMCMXCIX` (3999). Lenient ones also
+/// take repeated digits (`IIII`, `MMMM`) and any smaller power of ten before a bigger
+/// digit (`IC`), as long as the values shrink from left to right, so `IIX` is still rejected.
+pub fn roman2int(numeral: &str, lenient: bool) -> Option<u64> {
+    if !lenient {
+        if !STRICT.is_match(numeral).unwrap_or(false) {
+            return None;
+        }
+    } else if numeral.is_empty() {
+        return None;
+    }
+    let digits = numeral.chars().map(digit).collect::<Option<Vec<u64>>>()?;
+    let mut total = 0;
+    let mut last = u64::MAX;
+    let mut i = 0;
+    while i < digits.len() {
+        let (value, step, next_limit) = match digits.get(i + 1) {
+            Some(&next) if next > digits[i] => {
+                // only `I`, `X` and `C` are subtracted, and not again after: `XCX`
+                if ![1, 10, 100].contains(&digits[i]) {
+                    return None;
+                }
+                (next - digits[i], 2, digits[i] - 1)
+            }
+            _ => (digits[i], 1, digits[i]),
+        };
+        if value > last {
+            return None;
+        }
+        total += value;
+        last = next_limit;
+        i += step;
+    }
+    Some(total)
+}
+
+/// Parses a Roman numeral, marked with `form.roman`: `XIV` -> 14.
+///
+/// Uppercase only. `IIII` and other numerals outside the standard form need
+/// `Config::lenient_roman`.
+pub fn words2roman(text: &str, data: &Data) -> Option<NumberValue> {
+    let value = roman2int(text.trim(), data.config().lenient_roman.unwrap_or(false))?;
+    let form = NumberForm { roman: true, ..NumberForm::default() };
+    Some(NumberValue::from(i64::try_from(value).ok()?).with_form(form))
+}
+
+/// Finds the Roman numerals of a text, skipping the ones that are likely words.
+///
+/// A numeral after a heading (`Chapter IV`, `World War I`) is always kept. Otherwise the
+/// pronoun `I` is only a numeral after a name and before punctuation (`Louis I.`), the
+/// numerals in `FALSE_FRIENDS` are skipped and so is a numeral next to an uppercase word,
+/// as in `MIX IT UP`.
+pub fn find_romans(text: &str, data: &Data) -> Vec<SpannedText> {
+    let lenient = data.config().lenient_roman.unwrap_or(false);
+    let mut found = Vec::new();
+    for mat in CANDIDATE.find_iter(text).filter_map(|m| m.ok()) {
+        let numeral = mat.as_str();
+        if roman2int(numeral, lenient).is_none() {
+            continue;
+        }
+        let before = text[..mat.start()].split_whitespace().last().unwrap_or_default();
+        let after = text[mat.end()..].split_whitespace().next().unwrap_or_default();
+        let heading = before.trim_end_matches(':').to_lowercase();
+        if HEADINGS.contains(&heading.as_str()) || is_likely_numeral(numeral, before, after) {
+            found.push((numeral.to_string(), (mat.start(), mat.end())));
+        }
+    }
+    found
+}
+
+fn is_likely_numeral(numeral: &str, before: &str, after: &str) -> bool {
+    let shouting = |word: &str| {
+        let word = word.trim_matches(|c: char| !c.is_alphanumeric());
+        word.chars().count() > 1
+            && word.chars().all(char::is_uppercase)
+            && roman2int(word, true).is_none()
+    };
+    if numeral == "I" {
+        // `Louis I.` and `Elizabeth I,` but not `then I went`
+        let after_name = before.chars().next().is_some_and(char::is_uppercase)
+            && before.chars().all(char::is_alphabetic);
+        return after_name && !after.starts_with(char::is_alphanumeric);
+    }
+    !FALSE_FRIENDS.contains(&numeral) && !shouting(before) && !shouting(after)
+}
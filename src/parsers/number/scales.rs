@@ -0,0 +1,59 @@
+use std::collections::HashMap;
+
+use crate::parsers::number::Data;
+
+/// What `billion` and the larger `-illion` words are worth, see `Config::number_scale`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NumberScale {
+    /// Each word a thousand times the one before: `billion` is 10^9, `trillion` 10^12.
+    /// English today.
+    Short,
+    /// Each word a million times the one before: `billion` is 10^12, `trillion` 10^18, with
+    /// `milliard` for 10^9. Most European languages and older British English.
+    Long,
+}
+
+impl NumberScale {
+    /// The exponent in this scale of an `-illion` word worth 10^`exponent` in `from`:
+    /// `billion` is 10^9 in the short scale and 10^12 in the long one.
+    fn exponent_from(self, from: NumberScale, exponent: i32) -> i32 {
+        match (from, self) {
+            // the n-illion is 10^(3n + 3) short and 10^(6n) long
+            (NumberScale::Short, NumberScale::Long) => 2 * (exponent - 3),
+            (NumberScale::Long, NumberScale::Short) => exponent / 2 + 3,
+            _ => exponent,
+        }
+    }
+}
+
+/// Whether `word` is worth a different number in each scale: `billion`, `billones`,
+/// `trillionth`, but not `million` nor `milliard`.
+pub(crate) fn is_scaled(word: &str, value: f64) -> bool {
+    value >= 1e9 && ["illion", "illón", "illon"].iter().any(|stem| word.contains(stem))
+}
+
+/// The `-illion` words of `table`, written for the `from` scale, at their value in `to`.
+pub fn rescale(table: HashMap<String, f64>, from: NumberScale, to: NumberScale) -> HashMap<String, f64> {
+    table
+        .into_iter()
+        .map(|(word, value)| {
+            if !is_scaled(&word, value) {
+                return (word, value);
+            }
+            let exponent = value.log10().round() as i32;
+            (word, 10f64.powi(to.exponent_from(from, exponent)))
+        })
+        .collect()
+}
+
+/// Whether the value of `text` depends on the scale it's read in and `Config::number_scale`
+/// left it to the language: `5 billion`, `2.5bn`, `$3B`, not `5 million` nor `2 milliards`.
+pub fn ambiguous_scale(text: &str, data: &Data) -> bool {
+    if data.config().number_scale.is_some() {
+        return false;
+    }
+    let scaled = data.scaled_words();
+    text.split(|c: char| !c.is_alphanumeric())
+        .map(|word| word.trim_start_matches(|c: char| c.is_ascii_digit()))
+        .any(|word| scaled.contains_key(word) || scaled.contains_key(&word.to_lowercase()))
+}
@@ -0,0 +1,165 @@
+use crate::parsers::number::NumberMatch;
+
+/// The decimal mark and the grouping comma of Arabic: `٣٫٥`, `١٬٠٠٠`.
+const ARABIC_DECIMAL: char = '\u{66B}';
+const ARABIC_GROUPING: char = '\u{66C}';
+
+/// The zero of every run of ten decimal digits in Unicode 15 besides `0`-`9`, sorted. The
+/// five mathematical styles (bold, double-struck ...) are all [`DigitScript::Mathematical`].
+const DIGIT_ZEROS: [(char, DigitScript); 67] = [
+    ('\u{660}', DigitScript::ArabicIndic),
+    ('\u{6F0}', DigitScript::ExtendedArabicIndic),
+    ('\u{7C0}', DigitScript::Nko),
+    ('\u{966}', DigitScript::Devanagari),
+    ('\u{9E6}', DigitScript::Bengali),
+    ('\u{A66}', DigitScript::Gurmukhi),
+    ('\u{AE6}', DigitScript::Gujarati),
+    ('\u{B66}', DigitScript::Oriya),
+    ('\u{BE6}', DigitScript::Tamil),
+    ('\u{C66}', DigitScript::Telugu),
+    ('\u{CE6}', DigitScript::Kannada),
+    ('\u{D66}', DigitScript::Malayalam),
+    ('\u{DE6}', DigitScript::SinhalaLith),
+    ('\u{E50}', DigitScript::Thai),
+    ('\u{ED0}', DigitScript::Lao),
+    ('\u{F20}', DigitScript::Tibetan),
+    ('\u{1040}', DigitScript::Myanmar),
+    ('\u{1090}', DigitScript::MyanmarShan),
+    ('\u{17E0}', DigitScript::Khmer),
+    ('\u{1810}', DigitScript::Mongolian),
+    ('\u{1946}', DigitScript::Limbu),
+    ('\u{19D0}', DigitScript::NewTaiLue),
+    ('\u{1A80}', DigitScript::TaiThamHora),
+    ('\u{1A90}', DigitScript::TaiThamTham),
+    ('\u{1B50}', DigitScript::Balinese),
+    ('\u{1BB0}', DigitScript::Sundanese),
+    ('\u{1C40}', DigitScript::Lepcha),
+    ('\u{1C50}', DigitScript::OlChiki),
+    ('\u{A620}', DigitScript::Vai),
+    ('\u{A8D0}', DigitScript::Saurashtra),
+    ('\u{A900}', DigitScript::KayahLi),
+    ('\u{A9D0}', DigitScript::Javanese),
+    ('\u{A9F0}', DigitScript::MyanmarTaiLaing),
+    ('\u{AA50}', DigitScript::Cham),
+    ('\u{ABF0}', DigitScript::MeeteiMayek),
+    ('\u{FF10}', DigitScript::Fullwidth),
+    ('\u{104A0}', DigitScript::Osmanya),
+    ('\u{10D30}', DigitScript::HanifiRohingya),
+    ('\u{11066}', DigitScript::Brahmi),
+    ('\u{110F0}', DigitScript::SoraSompeng),
+    ('\u{11136}', DigitScript::Chakma),
+    ('\u{111D0}', DigitScript::Sharada),
+    ('\u{112F0}', DigitScript::Khudawadi),
+    ('\u{11450}', DigitScript::Newa),
+    ('\u{114D0}', DigitScript::Tirhuta),
+    ('\u{11650}', DigitScript::Modi),
+    ('\u{116C0}', DigitScript::Takri),
+    ('\u{11730}', DigitScript::Ahom),
+    ('\u{118E0}', DigitScript::WarangCiti),
+    ('\u{11950}', DigitScript::DivesAkuru),
+    ('\u{11C50}', DigitScript::Bhaiksuki),
+    ('\u{11D50}', DigitScript::MasaramGondi),
+    ('\u{11DA0}', DigitScript::GunjalaGondi),
+    ('\u{11F50}', DigitScript::Kawi),
+    ('\u{16A60}', DigitScript::Mro),
+    ('\u{16AC0}', DigitScript::Tangsa),
+    ('\u{16B50}', DigitScript::PahawhHmong),
+    ('\u{1D7CE}', DigitScript::Mathematical),
+    ('\u{1D7D8}', DigitScript::Mathematical),
+    ('\u{1D7E2}', DigitScript::Mathematical),
+    ('\u{1D7EC}', DigitScript::Mathematical),
+    ('\u{1D7F6}', DigitScript::Mathematical),
+    ('\u{1E140}', DigitScript::NyiakengPuachueHmong),
+    ('\u{1E2F0}', DigitScript::Wancho),
+    ('\u{1E4F0}', DigitScript::NagMundari),
+    ('\u{1E950}', DigitScript::Adlam),
+    ('\u{1FBF0}', DigitScript::Segmented),
+];
+
+/// The script of the digits of a numeral: `345`, `٣٤٥` (Arabic-Indic), `३४५` (Devanagari),
+/// `１２３` (fullwidth) ...
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum DigitScript {
+    /// `0`-`9`
+    #[default]
+    Ascii,
+    ArabicIndic, ExtendedArabicIndic, Nko, Devanagari, Bengali, Gurmukhi, Gujarati, Oriya,
+    Tamil, Telugu, Kannada, Malayalam, SinhalaLith, Thai, Lao, Tibetan, Myanmar, MyanmarShan,
+    Khmer, Mongolian, Limbu, NewTaiLue, TaiThamHora, TaiThamTham, Balinese, Sundanese, Lepcha,
+    OlChiki, Vai, Saurashtra, KayahLi, Javanese, MyanmarTaiLaing, Cham, MeeteiMayek, Fullwidth,
+    Osmanya, HanifiRohingya, Brahmi, SoraSompeng, Chakma, Sharada, Khudawadi, Newa, Tirhuta,
+    Modi, Takri, Ahom, WarangCiti, DivesAkuru, Bhaiksuki, MasaramGondi, GunjalaGondi, Kawi, Mro,
+    Tangsa, PahawhHmong, Mathematical, NyiakengPuachueHmong, Wancho, NagMundari, Adlam,
+    Segmented,
+}
+
+/// The value and script of a decimal digit of any script: `٣` -> 3, `３` -> 3.
+pub fn digit_value(c: char) -> Option<(u32, DigitScript)> {
+    if c.is_ascii_digit() {
+        return Some((c as u32 - '0' as u32, DigitScript::Ascii));
+    }
+    let i = DIGIT_ZEROS.partition_point(|(zero, _)| *zero <= c).checked_sub(1)?;
+    let (zero, script) = DIGIT_ZEROS[i];
+    let value = c as u32 - zero as u32;
+    (value < 10).then_some((value, script))
+}
+
+/// The script of the first digit of `text` that isn't ASCII, [`DigitScript::Ascii`] without one.
+pub fn digit_script(text: &str) -> DigitScript {
+    text.chars()
+        .filter_map(digit_value)
+        .map(|(_, script)| script)
+        .find(|script| *script != DigitScript::Ascii)
+        .unwrap_or_default()
+}
+
+/// `text` with the digits of every script written `0`-`9`: `٣٫٥` -> `3.5`, `１２３` -> `123`.
+pub fn ascii_digits(text: &str) -> String {
+    AsciiDigits::new(text).text
+}
+
+/// A text with its digits made ASCII and where each of its bytes came from, so numbers
+/// found in it can be put back in the original.
+pub(crate) struct AsciiDigits {
+    pub text: String,
+    /// The byte of the original text each byte of `text` comes from, and its length.
+    offsets: Vec<usize>,
+}
+
+impl AsciiDigits {
+    pub fn new(original: &str) -> Self {
+        let mut text = String::with_capacity(original.len());
+        let mut offsets = Vec::with_capacity(original.len() + 1);
+        for (i, c) in original.char_indices() {
+            let ascii = match c {
+                ARABIC_DECIMAL => Some('.'),
+                ARABIC_GROUPING => Some(','),
+                c if c.is_ascii() => None,
+                c => digit_value(c).and_then(|(value, _)| char::from_digit(value, 10)),
+            };
+            match ascii {
+                Some(ascii) => {
+                    text.push(ascii);
+                    offsets.push(i);
+                }
+                None => {
+                    text.push(c);
+                    offsets.extend(i..i + c.len_utf8());
+                }
+            }
+        }
+        offsets.push(original.len());
+        AsciiDigits { text, offsets }
+    }
+
+    /// `number`, found in `text`, with the span and the text it has in `original`.
+    pub fn restore(&self, number: &mut NumberMatch, original: &str) {
+        let span = (self.offsets[number.span.0], self.offsets[number.span.1]);
+        number.text = original[span.0..span.1].to_string();
+        number.span = span;
+        if let Some(qualifier) = &mut number.qualifier {
+            qualifier.span = (self.offsets[qualifier.span.0], self.offsets[qualifier.span.1]);
+            qualifier.text = original[qualifier.span.0..qualifier.span.1].to_string();
+        }
+    }
+}
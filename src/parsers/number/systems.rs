@@ -0,0 +1,78 @@
+use fancy_regex::Regex;
+use lazy_static::lazy_static;
+use num_rational::BigRational;
+use num_traits::Zero;
+
+use crate::parsers::number::Data;
+use crate::parsers::number::exact::{parse_numeral, table_value};
+
+lazy_static! {
+    // `1,00,000`, `12,34,567`
+    static ref INDIAN_GROUPING: Regex = Regex::new(r"(?<!\d)\d{1,2},\d{2},\d{3}(?!\d)").unwrap();
+}
+
+/// How the large numbers of a text are named and grouped.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum NumberSystem {
+    /// Powers of a thousand: `thousand`, `million`, `1,000,000`.
+    #[default]
+    Western,
+    /// `lakh` (10^5) and `crore` (10^7): `3 lakh`, `2.5 crore`, `12,34,567`.
+    Indian,
+    /// Powers of ten thousand: `万` (10^4), `億` (10^8), `兆` (10^12), as in `3万` or `1億2000万`.
+    Myriad,
+}
+
+/// The numbering system `text` is written in, [`NumberSystem::Western`] unless it uses a myriad,
+/// an Indian multiple (`5 lakh`, `2.5cr`, `1 lakh crore`) or the Indian grouping of digits.
+pub fn number_system(text: &str, data: &Data) -> NumberSystem {
+    let myriads = data.myriads();
+    if text.chars().any(|c| myriads.contains_key(&c)) {
+        return NumberSystem::Myriad;
+    }
+    let indian = data.indian_multiples();
+    let lower = text.to_lowercase();
+    let words = lower
+        .split(|c: char| !c.is_alphanumeric())
+        .map(|word| word.trim_start_matches(|c: char| c.is_ascii_digit()));
+    if words.clone().any(|word| indian.contains_key(word)) || INDIAN_GROUPING.is_match(text).unwrap_or(false) {
+        NumberSystem::Indian
+    } else {
+        NumberSystem::Western
+    }
+}
+
+/// Converts digits grouped by myriads: `3万` -> 30000, `1億2000万` -> 120000000, `3.5万` -> 35000.
+///
+/// The myriads have to go down, `2000万1億` isn't a number, and the digits after the last one
+/// have to be below it: `12万3456`.
+pub fn words2myriad(text: &str, data: &Data) -> Option<BigRational> {
+    let myriads = data.myriads();
+    if !text.chars().any(|c| myriads.contains_key(&c)) {
+        return None;
+    }
+    let mut total = BigRational::zero();
+    let mut digits = String::new();
+    let mut last: Option<BigRational> = None;
+    for c in text.trim().chars() {
+        let Some(myriad) = myriads.get(&c) else {
+            digits.push(c);
+            continue;
+        };
+        let myriad = table_value(*myriad)?;
+        if last.as_ref().is_some_and(|last| *last <= myriad) {
+            return None;
+        }
+        total += parse_numeral(digits.trim())? * &myriad;
+        digits.clear();
+        last = Some(myriad);
+    }
+    if !digits.trim().is_empty() {
+        let rest = parse_numeral(digits.trim())?;
+        if last.is_some_and(|last| rest >= last) {
+            return None;
+        }
+        total += rest;
+    }
+    Some(total)
+}
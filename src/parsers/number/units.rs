@@ -0,0 +1,261 @@
+use crate::parsers::number::{words2num, Config, Data, DataHolder, NumberEntityParser, NumberMatch, NumberParser, NumberValue};
+use crate::parsers::number::constants::QUANTITY;
+use crate::parsers::number::locale::canonical_numerals;
+use self::Dimension::*;
+
+/// What a unit measures, each with a base unit the quantities are normalized to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dimension {
+    Length,
+    Mass,
+    Time,
+    Temperature,
+    Volume,
+    DataSize,
+    Speed,
+}
+
+impl Dimension {
+    /// `m`, `kg`, `s`, `K`, `L`, `B` and `m/s`.
+    pub fn base_unit(&self) -> &'static str {
+        match self {
+            Dimension::Length => "m",
+            Dimension::Mass => "kg",
+            Dimension::Time => "s",
+            Dimension::Temperature => "K",
+            Dimension::Volume => "L",
+            Dimension::DataSize => "B",
+            Dimension::Speed => "m/s",
+        }
+    }
+}
+
+/// A unit of measure: `value * factor + offset` is the value in the base unit of its dimension.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Unit {
+    /// The unit as written: `km`, `kilograms`, `°F`.
+    pub name: String,
+    pub dimension: Dimension,
+    pub factor: f64,
+    /// Only temperatures have one: `°C` is `K - 273.15`.
+    pub offset: f64,
+}
+
+impl Unit {
+    pub fn to_base(&self, value: f64) -> f64 {
+        value * self.factor + self.offset
+    }
+
+    pub fn from_base(&self, value: f64) -> f64 {
+        (value - self.offset) / self.factor
+    }
+
+    /// `value` of this unit in `other`, `None` when they measure different things.
+    pub fn convert(&self, value: f64, other: &Unit) -> Option<f64> {
+        (self.dimension == other.dimension).then(|| other.from_base(self.to_base(value)))
+    }
+}
+
+/// Symbols taking an SI prefix: `km`, `mg`, `ms`, `mL`, `GB`, `Mbit`.
+const PREFIXED_SYMBOLS: [(&str, Dimension, f64); 7] = [
+    ("m", Length, 1.0),
+    ("g", Mass, 0.001),
+    ("s", Time, 1.0),
+    ("L", Volume, 1.0),
+    ("l", Volume, 1.0),
+    ("B", DataSize, 1.0),
+    ("bit", DataSize, 0.125),
+];
+
+/// Names taking a prefix name: `kilometres`, `milligrams`, `megabytes`.
+const PREFIXED_NAMES: [(&str, Dimension, f64); 18] = [
+    ("metres", Length, 1.0), ("metre", Length, 1.0), ("meters", Length, 1.0), ("meter", Length, 1.0),
+    ("grams", Mass, 0.001), ("gram", Mass, 0.001), ("grammes", Mass, 0.001), ("gramme", Mass, 0.001),
+    ("seconds", Time, 1.0), ("second", Time, 1.0), ("litres", Volume, 1.0), ("litre", Volume, 1.0),
+    ("liters", Volume, 1.0), ("liter", Volume, 1.0), ("bytes", DataSize, 1.0), ("byte", DataSize, 1.0),
+    ("bits", DataSize, 0.125), ("bit", DataSize, 0.125),
+];
+
+/// Case-sensitive symbols without a prefix. `in` and `K` are left out, they are more often
+/// a word and a thousand.
+const SYMBOLS: [(&str, Dimension, f64); 26] = [
+    ("mi", Length, 1609.344), ("ft", Length, 0.3048), ("yd", Length, 0.9144), ("nmi", Length, 1852.0),
+    ("t", Mass, 1000.0), ("lb", Mass, 0.453_592_37), ("lbs", Mass, 0.453_592_37), ("oz", Mass, 0.028_349_523_125),
+    ("sec", Time, 1.0), ("min", Time, 60.0), ("h", Time, 3600.0), ("hr", Time, 3600.0), ("hrs", Time, 3600.0),
+    ("gal", Volume, 3.785_411_784), ("cc", Volume, 0.001), ("m³", Volume, 1000.0), ("cm³", Volume, 0.001),
+    ("KB", DataSize, 1e3), ("KiB", DataSize, 1024.0), ("MiB", DataSize, 1_048_576.0),
+    ("GiB", DataSize, 1_073_741_824.0), ("TiB", DataSize, 1_099_511_627_776.0),
+    ("mph", Speed, 0.447_04), ("km/h", Speed, 1.0 / 3.6), ("kph", Speed, 1.0 / 3.6), ("m/s", Speed, 1.0),
+];
+
+/// Prefixed symbols that are words or times of day: `as`, `am`, `pm`.
+const NOT_UNITS: [&str; 3] = ["as", "am", "pm"];
+
+/// Case-insensitive names without a prefix, the ones of many words first.
+const NAMES: [(&str, Dimension, f64); 39] = [
+    ("kilometres per hour", Speed, 1.0 / 3.6), ("kilometers per hour", Speed, 1.0 / 3.6),
+    ("metres per second", Speed, 1.0), ("meters per second", Speed, 1.0),
+    ("miles per hour", Speed, 0.447_04), ("nautical miles", Length, 1852.0), ("nautical mile", Length, 1852.0),
+    ("fluid ounces", Volume, 0.029_573_529_562_5), ("fluid ounce", Volume, 0.029_573_529_562_5),
+    ("cubic metres", Volume, 1000.0), ("cubic meters", Volume, 1000.0),
+    ("miles", Length, 1609.344), ("mile", Length, 1609.344), ("feet", Length, 0.3048), ("foot", Length, 0.3048),
+    ("inches", Length, 0.0254), ("inch", Length, 0.0254), ("yards", Length, 0.9144), ("yard", Length, 0.9144),
+    ("tonnes", Mass, 1000.0), ("tonne", Mass, 1000.0), ("pounds", Mass, 0.453_592_37), ("pound", Mass, 0.453_592_37),
+    ("ounces", Mass, 0.028_349_523_125), ("ounce", Mass, 0.028_349_523_125),
+    ("minutes", Time, 60.0), ("minute", Time, 60.0), ("hours", Time, 3600.0), ("hour", Time, 3600.0),
+    ("days", Time, 86_400.0), ("day", Time, 86_400.0), ("weeks", Time, 604_800.0), ("week", Time, 604_800.0),
+    ("years", Time, 31_557_600.0), ("year", Time, 31_557_600.0), ("knots", Speed, 0.514_444), ("knot", Speed, 0.514_444),
+    ("gallons", Volume, 3.785_411_784), ("gallon", Volume, 3.785_411_784),
+];
+
+const FAHRENHEIT: (f64, f64) = (5.0 / 9.0, 459.67 * 5.0 / 9.0);
+const CELSIUS: (f64, f64) = (1.0, 273.15);
+
+/// Temperatures, all case-insensitive.
+const TEMPERATURES: [(&str, (f64, f64)); 14] = [
+    ("degrees fahrenheit", FAHRENHEIT), ("degree fahrenheit", FAHRENHEIT), ("degrees f", FAHRENHEIT),
+    ("fahrenheit", FAHRENHEIT), ("°f", FAHRENHEIT), ("℉", FAHRENHEIT),
+    ("degrees celsius", CELSIUS), ("degree celsius", CELSIUS), ("degrees c", CELSIUS),
+    ("celsius", CELSIUS), ("°c", CELSIUS), ("℃", CELSIUS),
+    ("kelvins", (1.0, 0.0)), ("kelvin", (1.0, 0.0)),
+];
+
+/// Prefixes of units that aren't bare number suffixes in [`Data`]: `5m` isn't 0.005 by
+/// default (`Config::exclude_suffixes`), but `mL` is a millilitre.
+const UNIT_PREFIXES: [(&str, f64); 3] = [("m", 1e-3), ("µ", 1e-6), ("μ", 1e-6)];
+
+/// The unit written `name`, SI prefixes read from the suffixes of `data`: `km`, `GB`,
+/// `kilograms`, `µs`.
+pub fn parse_unit(name: &str, data: &Data) -> Option<Unit> {
+    let unit = |dimension, factor, offset| Unit { name: name.to_string(), dimension, factor, offset };
+    let lower = name.to_lowercase();
+    if let Some((_, (factor, offset))) = TEMPERATURES.iter().find(|(n, _)| *n == lower) {
+        return Some(unit(Temperature, *factor, *offset));
+    }
+    let exact = SYMBOLS
+        .iter()
+        .chain(&PREFIXED_SYMBOLS)
+        .find(|(symbol, ..)| *symbol == name)
+        .or_else(|| NAMES.iter().chain(&PREFIXED_NAMES).find(|(n, ..)| *n == lower));
+    if let Some(&(_, dimension, factor)) = exact {
+        return Some(unit(dimension, factor, 0.0));
+    }
+    if NOT_UNITS.contains(&name) {
+        return None;
+    }
+    let prefix_value = |prefix: &str| {
+        UNIT_PREFIXES
+            .iter()
+            .find(|(p, _)| *p == prefix)
+            .map(|(_, value)| *value)
+            // billions, not SI prefixes
+            .or_else(|| data.suffixes().get(prefix).filter(|_| !["B", "bn"].contains(&prefix)).copied())
+    };
+    let symbol = PREFIXED_SYMBOLS.iter().find_map(|&(symbol, dimension, factor)| {
+        let prefix = name.strip_suffix(symbol).filter(|p| !p.is_empty())?;
+        Some(unit(dimension, factor * prefix_value(prefix)?, 0.0))
+    });
+    symbol.or_else(|| {
+        PREFIXED_NAMES.iter().find_map(|&(base, dimension, factor)| {
+            let prefix = lower.strip_suffix(base).filter(|p| !p.is_empty())?;
+            Some(unit(dimension, factor * data.suffixes_by_name().get(prefix)?, 0.0))
+        })
+    })
+}
+
+/// A number followed by a unit: `5 km`, `3.2 kilograms`, `98.6 °F`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Quantity {
+    pub text: String,
+    pub span: (usize, usize),
+    pub number: NumberMatch,
+    /// The value in `unit`, which differs from the number's in `5h` (not 500) or `5 kilo meters`.
+    pub value: NumberValue,
+    pub unit: Unit,
+}
+
+impl Quantity {
+    /// The value in the base unit of the dimension, see [`Dimension::base_unit`].
+    pub fn base_value(&self) -> Option<f64> {
+        Some(self.unit.to_base(self.value.to_f64()?))
+    }
+
+    /// The value in `unit`, `None` when it measures something else.
+    pub fn to(&self, unit: &Unit) -> Option<f64> {
+        self.unit.convert(self.value.to_f64()?, unit)
+    }
+}
+
+/// The quantities among the `numbers` of `text`.
+///
+/// The unit follows the number, attached or after spaces. A suffix the number took is read
+/// as a unit when it is one, so `5h` is five hours and `5k m` five thousand metres.
+pub fn find_quantities(numbers: &[NumberMatch], text: &str, data: &Data) -> Vec<Quantity> {
+    numbers.iter().filter_map(|number| quantity_of(number, text, data)).collect()
+}
+
+fn quantity_of(number: &NumberMatch, text: &str, data: &Data) -> Option<Quantity> {
+    let quantity = |value, unit: Unit, end| Quantity {
+        text: text[number.span.0..end].to_string(),
+        span: (number.span.0, end),
+        number: number.clone(),
+        value,
+        unit,
+    };
+    // `5h`, `3 kilo` + `grams`
+    let attached = text[number.span.1..].chars().take_while(|c| is_unit_char(*c)).map(char::len_utf8).sum::<usize>();
+    let end = number.span.1 + attached;
+    let digits = number.text.trim_end_matches(char::is_alphabetic).trim_end();
+    let letters = text[number.span.0 + digits.len()..end].trim_start();
+    if digits.len() < number.text.len() && !digits.is_empty() {
+        if let Some(unit) = parse_unit(letters, data) {
+            let holder = DataHolder { type_: "text", text: Some(canonical_numerals(digits, data)), int: None, float: None };
+            return Some(quantity(words2num(holder, data)?, unit, end));
+        }
+    }
+    let (unit, end) = unit_after(text, number.span.1, data)?;
+    Some(quantity(number.value.clone(), unit, end))
+}
+
+fn is_unit_char(c: char) -> bool {
+    c.is_alphabetic() || "°/³µμ℃℉".contains(c)
+}
+
+fn unit_after(text: &str, end: usize, data: &Data) -> Option<(Unit, usize)> {
+    let after = &text[end..];
+    let rest = after.trim_start();
+    let start = end + after.len() - rest.len();
+    // `degrees celsius`, `miles per hour`
+    let phrase = TEMPERATURES
+        .iter()
+        .map(|(name, _)| *name)
+        .chain(NAMES.iter().map(|(name, ..)| *name))
+        .filter(|name| name.contains(' '))
+        .find(|name| {
+            rest.get(..name.len()).is_some_and(|w| w.eq_ignore_ascii_case(name))
+                && !rest[name.len()..].starts_with(char::is_alphanumeric)
+        });
+    let len = match phrase {
+        Some(name) => name.len(),
+        None => rest.chars().take_while(|c| is_unit_char(*c)).map(char::len_utf8).sum(),
+    };
+    let unit = parse_unit(&rest[..len], data)?;
+    Some((unit, start + len))
+}
+
+impl NumberParser {
+    /// The quantities of `text`, see [`find_quantities`].
+    pub fn extract_quantities(&mut self, text: &str) -> Vec<Quantity> {
+        let numbers = self.extract(text);
+        find_quantities(&numbers, text, self.data())
+    }
+}
+
+impl NumberEntityParser {
+    /// Finds quantities as `quantity` entities, see [`find_quantities`].
+    pub fn quantities(config: Config) -> Self {
+        NumberEntityParser::new(config, QUANTITY, |parser, text| {
+            parser.extract_quantities(text).into_iter().map(|q| (q.text, q.span)).collect()
+        })
+    }
+}
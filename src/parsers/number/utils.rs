@@ -1,4 +1,15 @@
 use fancy_regex::Regex;
+use lazy_static::lazy_static;
+use num_bigint::BigInt;
+
+use crate::parsers::number::constants::{BINARY, HEX, INTEGER, OCTAL};
+use crate::parsers::number::literals::strip_type_suffix;
+use crate::parsers::number::scripts::{ascii_digits, digit_script};
+use crate::parsers::number::value::{NumberForm, NumberValue};
+
+lazy_static! {
+    static ref SEPARATORS: Regex = Regex::new(r#"[\,'_]"#).unwrap();
+}
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct DataHolder {
@@ -35,51 +46,76 @@ pub fn to_base(mut x: u32, radix: u32) -> u32 {
         .unwrap()
 }
 
-pub fn parse_number(string: &str) -> Result<(i64, &'static str), &'static str> {
-    let string = string.trim();
+/// Parses an integer literal, `0b`, `0o` and `0x` prefixes set the radix. Digits of any
+/// script are read: `٣٤٥` is 345 with [`DigitScript::ArabicIndic`](crate::parsers::number::DigitScript).
+///
+/// Prefixed literals may have underscores and a type suffix (`0xFF_FF`, `0b1010u8`), and
+/// beyond `i64` they are a `BigInteger`: `0xFFFF_FFFF_FFFF_FFFF`.
+pub fn parse_number(string: &str) -> Result<NumberValue, &'static str> {
+    let script = digit_script(string);
+    let ascii = ascii_digits(string);
+    let string = ascii.trim();
     let string_lower = string.to_lowercase();
 
-    if string_lower.starts_with("0b") {
-        match i64::from_str_radix(&string[2..], 2) {
-            Ok(num) => Ok((num, "binary")),
-            Err(_) => Err("Invalid binary number"),
-        }
+    let (digits, radix, error) = if string_lower.starts_with("0b") {
+        (&string[2..], 2, "Invalid binary number")
     } else if string_lower.starts_with("0o") {
-        match i64::from_str_radix(&string[2..], 8) {
-            Ok(num) => Ok((num, "octal")),
-            Err(_) => Err("Invalid octal number"),
-        }
+        (&string[2..], 8, "Invalid octal number")
     } else if string_lower.starts_with("0x") {
-        match i64::from_str_radix(&string[2..], 16) {
-            Ok(num) => Ok((num, "hexadecimal")),
-            Err(_) => Err("Invalid hexadecimal number"),
-        }
+        (&string[2..], 16, "Invalid hexadecimal number")
     } else {
-        match string.parse::<i64>() {
-            Ok(num) => Ok((num, "integer")),
-            Err(_) => Err("Invalid decimal number"),
-        }
+        (string, 10, "Invalid decimal number")
+    };
+    let digits = match radix {
+        10 => digits.to_string(),
+        _ => strip_type_suffix(string).0[2..].replace('_', ""),
+    };
+    let form = NumberForm { script, ..NumberForm::radix(radix) };
+    match i64::from_str_radix(&digits, radix) {
+        Ok(value) => Ok(NumberValue::Integer { value, form }),
+        // `from_str_radix` takes a sign, a literal doesn't
+        Err(_) if radix == 10 || digits.starts_with(['+', '-']) => Err(error),
+        Err(_) => match BigInt::parse_bytes(digits.as_bytes(), radix) {
+            Some(value) => Ok(NumberValue::BigInteger { value, form }),
+            None => Err(error),
+        },
     }
 }
 
-pub fn string_to_num(string: String) -> DataHolder {
-    let mut type_: &'static str = "text";
-    let mut int: Option<i64> = None;
-    let mut float: Option<f64> = None;
-    let text: Option<String> = Some(string.clone());
-
-    if let Ok((parsed_int, parsed_type)) = parse_number(&string) {
-        int = Some(parsed_int);
-        type_ = parsed_type;
-    } else if let Ok(parsed_float) = string.parse::<f64>() {
-        float = Some(parsed_float);
-        type_ = "float";
+/// Parses a numeral, `None` when the string isn't one.
+pub fn string_to_num(string: String) -> Option<NumberValue> {
+    parse_number(&string).ok().or_else(|| {
+        let value = NumberValue::from(ascii_digits(&string).parse::<f64>().ok()?);
+        let form = NumberForm { script: digit_script(&string), ..value.form() };
+        Some(value.with_form(form))
+    })
+}
+
+impl From<NumberValue> for DataHolder {
+    fn from(value: NumberValue) -> Self {
+        match value {
+            NumberValue::Integer { value, form } => DataHolder {
+                type_: value_kind(form),
+                text: None,
+                int: Some(value),
+                float: None,
+            },
+            other => DataHolder {
+                type_: "float",
+                text: None,
+                int: None,
+                float: other.to_f64(),
+            },
+        }
     }
+}
 
-    match type_ {
-        "float" => DataHolder { type_, text: None, int: None, float },
-        "integer" | "octal" | "binary" | "hexadecimal" => DataHolder { type_, text: None, int, float: None },
-        _ => DataHolder { type_: "text", text, int: None, float: None },
+fn value_kind(form: NumberForm) -> &'static str {
+    match form.radix {
+        2 => BINARY,
+        8 => OCTAL,
+        16 => HEX,
+        _ => INTEGER,
     }
 }
 
@@ -99,7 +135,7 @@ pub fn pair(tokens: &mut Vec<f64>, holder: Option<f64>) -> Vec<(f64, f64)> {
     }
 
     // If the length is odd, append the holder to make it even
-    if tk_len % 2 != 0 {
+    if !tk_len.is_multiple_of(2) {
         tokens.push(holder);
         tk_len += 1;
     }
@@ -121,8 +157,7 @@ pub fn _clean(data: DataHolder, lower: Option<bool>) -> DataHolder {
     match data.text {
         Some(text) => {
             let mut cleaned_text = text.clone();
-            cleaned_text = Regex::new(r#"[\,'_]"#)
-                .unwrap()
+            cleaned_text = SEPARATORS
                 .replace_all(&cleaned_text, "")
                 .to_string();
             if lower.unwrap_or(false) {
@@ -0,0 +1,319 @@
+use std::fmt;
+use std::ops::Neg;
+
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::{One, Pow, Signed, ToPrimitive, Zero};
+
+use crate::parsers::number::constants::{
+    BINARY, COMPLEX, FLOAT, HEX, INTEGER, OCTAL, ORDINAL, RATIONAL, ROMAN, SPOKEN,
+};
+use crate::parsers::number::scripts::DigitScript;
+use crate::parsers::number::systems::NumberSystem;
+
+/// How a number was written: its radix, whether it was an ordinal, spoken or a Roman numeral,
+/// the script of its digits, the numbering system of its large numbers and whether their
+/// scale was a guess.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NumberForm {
+    pub radix: u32,
+    pub ordinal: bool,
+    pub spoken: bool,
+    pub roman: bool,
+    pub script: DigitScript,
+    pub system: NumberSystem,
+    /// Read with the scale of the language, `billion` might have meant the other one,
+    /// see [`ambiguous_scale`](crate::parsers::number::ambiguous_scale).
+    pub ambiguous_scale: bool,
+}
+
+impl Default for NumberForm {
+    fn default() -> Self {
+        NumberForm {
+            radix: 10,
+            ordinal: false,
+            spoken: false,
+            roman: false,
+            script: DigitScript::Ascii,
+            system: NumberSystem::Western,
+            ambiguous_scale: false,
+        }
+    }
+}
+
+impl NumberForm {
+
+    pub fn radix(radix: u32) -> Self {
+        NumberForm { radix, ..NumberForm::default() }
+    }
+}
+
+/// The value of a parsed number together with the form it was written in.
+///
+/// ```
+/// use nlp_rust::parsers::number::{parse_number, NumberValue};
+///
+/// match parse_number("0x1F") {
+///     Ok(NumberValue::Integer { value, form }) => assert_eq!((value, form.radix), (31, 16)),
+///     other => panic!("unexpected {:?}", other),
+/// }
+/// ```
+///
+/// `BigInteger` and `Decimal` are only produced in exact mode (`Config::exact`) and for
+/// prefixed literals beyond `i64` (`0xFFFF_FFFF_FFFF_FFFF`),
+/// `Decimal` is `digits / 10^scale`, eg: `1.10` -> `{ digits: 110, scale: 2 }`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum NumberValue {
+    Integer { value: i64, form: NumberForm },
+    BigInteger { value: BigInt, form: NumberForm },
+    Float { value: f64, form: NumberForm },
+    Decimal { digits: BigInt, scale: u32, form: NumberForm },
+    Rational { numer: i64, denom: i64, form: NumberForm },
+    Complex { re: f64, im: f64, form: NumberForm },
+}
+
+impl NumberValue {
+
+    /// A fraction in lowest terms with a positive denominator, `None` when `denom` is zero
+    /// or the fraction doesn't fit: `1 / i64::MIN` is `-1 / 2^63`.
+    pub fn rational(numer: i64, denom: i64) -> Option<Self> {
+        if denom == 0 {
+            return None;
+        }
+        let divisor = i128::from(gcd(numer.unsigned_abs(), denom.unsigned_abs())) * i128::from(denom.signum());
+        Some(NumberValue::Rational {
+            numer: i64::try_from(i128::from(numer) / divisor).ok()?,
+            denom: i64::try_from(i128::from(denom) / divisor).ok()?,
+            form: NumberForm::default(),
+        })
+    }
+
+    /// Whole floats become `Integer`, `2.5` stays a `Float`.
+    pub fn from_float(value: f64) -> Self {
+        if value.fract() == 0.0 && value.abs() < i64::MAX as f64 {
+            NumberValue::from(value as i64)
+        } else {
+            NumberValue::from(value)
+        }
+    }
+
+    /// The exact value: integers, terminating decimals and everything else as a fraction.
+    pub fn from_ratio(value: BigRational) -> Self {
+        if value.is_integer() {
+            let value = value.to_integer();
+            return match value.to_i64() {
+                Some(value) => NumberValue::from(value),
+                None => NumberValue::BigInteger { value, form: NumberForm::default() },
+            };
+        }
+        if let Some(scale) = decimal_scale(value.denom()) {
+            let digits = (value * BigRational::from_integer(ten_pow(scale))).to_integer();
+            return NumberValue::Decimal { digits, scale, form: NumberForm::default() };
+        }
+        match (value.numer().to_i64(), value.denom().to_i64()) {
+            (Some(numer), Some(denom)) => NumberValue::Rational { numer, denom, form: NumberForm::default() },
+            _ => NumberValue::from(value.to_f64().unwrap_or(f64::NAN)),
+        }
+    }
+
+    /// A parsed fraction, kept as `numer/denom` unless it is whole (`4/2`) or too big for `i64`,
+    /// then it is read like any number outside exact mode, see [`NumberValue::from_float`].
+    pub fn from_fraction(value: BigRational) -> Self {
+        match (value.is_integer(), value.numer().to_i64(), value.denom().to_i64()) {
+            (false, Some(numer), Some(denom)) => NumberValue::Rational { numer, denom, form: NumberForm::default() },
+            _ => NumberValue::from_float(value.to_f64().unwrap_or(f64::NAN)),
+        }
+    }
+
+    /// The value as an exact fraction, `None` for complex numbers with an imaginary part
+    /// and for infinite or NaN floats.
+    pub fn to_ratio(&self) -> Option<BigRational> {
+        match self {
+            NumberValue::Integer { value, .. } => Some(BigRational::from_integer(BigInt::from(*value))),
+            NumberValue::BigInteger { value, .. } => Some(BigRational::from_integer(value.clone())),
+            NumberValue::Float { value, .. } => BigRational::from_float(*value),
+            NumberValue::Decimal { digits, scale, .. } => {
+                Some(BigRational::new(digits.clone(), ten_pow(*scale)))
+            }
+            NumberValue::Rational { numer, denom, .. } => {
+                Some(BigRational::new(BigInt::from(*numer), BigInt::from(*denom)))
+            }
+            NumberValue::Complex { re, im, .. } if *im == 0.0 => BigRational::from_float(*re),
+            NumberValue::Complex { .. } => None,
+        }
+    }
+
+    pub fn form(&self) -> NumberForm {
+        match self {
+            NumberValue::Integer { form, .. }
+            | NumberValue::BigInteger { form, .. }
+            | NumberValue::Float { form, .. }
+            | NumberValue::Decimal { form, .. }
+            | NumberValue::Rational { form, .. }
+            | NumberValue::Complex { form, .. } => *form,
+        }
+    }
+
+    pub fn with_form(mut self, new_form: NumberForm) -> Self {
+        match &mut self {
+            NumberValue::Integer { form, .. }
+            | NumberValue::BigInteger { form, .. }
+            | NumberValue::Float { form, .. }
+            | NumberValue::Decimal { form, .. }
+            | NumberValue::Rational { form, .. }
+            | NumberValue::Complex { form, .. } => *form = new_form,
+        }
+        self
+    }
+
+    pub fn radix(&self) -> u32 {
+        self.form().radix
+    }
+
+    pub fn is_ordinal(&self) -> bool {
+        self.form().ordinal
+    }
+
+    pub fn is_spoken(&self) -> bool {
+        self.form().spoken
+    }
+
+    pub fn is_roman(&self) -> bool {
+        self.form().roman
+    }
+
+    pub fn script(&self) -> DigitScript {
+        self.form().script
+    }
+
+    pub fn system(&self) -> NumberSystem {
+        self.form().system
+    }
+
+    pub fn is_ambiguous_scale(&self) -> bool {
+        self.form().ambiguous_scale
+    }
+
+    /// The value as a float, `None` for complex numbers with an imaginary part.
+    ///
+    /// Lossy for big integers and decimals.
+    pub fn to_f64(&self) -> Option<f64> {
+        match self {
+            NumberValue::Integer { value, .. } => Some(*value as f64),
+            NumberValue::BigInteger { value, .. } => value.to_f64(),
+            NumberValue::Float { value, .. } => Some(*value),
+            NumberValue::Decimal { .. } => self.to_ratio()?.to_f64(),
+            NumberValue::Rational { numer, denom, .. } => Some(*numer as f64 / *denom as f64),
+            NumberValue::Complex { re, im, .. } => (*im == 0.0).then_some(*re),
+        }
+    }
+
+    /// One of the kinds in `constants`: `ordinal`, `spoken`, `hexadecimal`, `roman`, `integer` ...
+    pub fn kind(&self) -> &'static str {
+        let form = self.form();
+        if form.roman {
+            return ROMAN;
+        }
+        if form.ordinal {
+            return ORDINAL;
+        }
+        if form.spoken {
+            return SPOKEN;
+        }
+        match (self, form.radix) {
+            (NumberValue::Complex { .. }, _) => COMPLEX,
+            (NumberValue::Rational { .. }, _) => RATIONAL,
+            (NumberValue::Float { .. } | NumberValue::Decimal { .. }, _) => FLOAT,
+            (_, 2) => BINARY,
+            (_, 8) => OCTAL,
+            (_, 16) => HEX,
+            _ => INTEGER,
+        }
+    }
+}
+
+impl From<i64> for NumberValue {
+    fn from(value: i64) -> Self {
+        NumberValue::Integer { value, form: NumberForm::default() }
+    }
+}
+
+impl From<f64> for NumberValue {
+    fn from(value: f64) -> Self {
+        NumberValue::Float { value, form: NumberForm::default() }
+    }
+}
+
+impl Neg for NumberValue {
+    type Output = NumberValue;
+
+    fn neg(self) -> Self::Output {
+        match self {
+            NumberValue::Integer { value, form } => match value.checked_neg() {
+                Some(value) => NumberValue::Integer { value, form },
+                None => NumberValue::BigInteger { value: -BigInt::from(value), form },
+            },
+            NumberValue::BigInteger { value, form } => NumberValue::BigInteger { value: -value, form },
+            NumberValue::Float { value, form } => NumberValue::Float { value: -value, form },
+            NumberValue::Decimal { digits, scale, form } => NumberValue::Decimal { digits: -digits, scale, form },
+            NumberValue::Rational { numer, denom, form } => match numer.checked_neg() {
+                Some(numer) => NumberValue::Rational { numer, denom, form },
+                None => NumberValue::from_ratio(-BigRational::new(numer.into(), denom.into())).with_form(form),
+            },
+            NumberValue::Complex { re, im, form } => NumberValue::Complex { re: -re, im: -im, form },
+        }
+    }
+}
+
+impl PartialEq<f64> for NumberValue {
+    fn eq(&self, other: &f64) -> bool {
+        self.to_f64() == Some(*other)
+    }
+}
+
+impl fmt::Display for NumberValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            NumberValue::Integer { value, .. } => write!(f, "{value}"),
+            NumberValue::BigInteger { ref value, .. } => write!(f, "{value}"),
+            NumberValue::Float { value, .. } => write!(f, "{value}"),
+            NumberValue::Decimal { ref digits, scale, .. } => {
+                let scale = scale as usize;
+                let sign = if digits.is_negative() { "-" } else { "" };
+                let digits = format!("{:0>width$}", digits.abs(), width = scale + 1);
+                let (whole, fraction) = digits.split_at(digits.len() - scale);
+                write!(f, "{sign}{whole}.{fraction}")
+            }
+            NumberValue::Rational { numer, denom, .. } => write!(f, "{numer}/{denom}"),
+            NumberValue::Complex { re, im, .. } if im < 0.0 => write!(f, "{re}-{}j", -im),
+            NumberValue::Complex { re, im, .. } => write!(f, "{re}+{im}j"),
+        }
+    }
+}
+
+pub(crate) fn ten_pow(exponent: u32) -> BigInt {
+    Pow::pow(BigInt::from(10), exponent)
+}
+
+/// `Some(n)` when `denom` divides `10^n`, ie: the fraction has a terminating decimal expansion.
+fn decimal_scale(denom: &BigInt) -> Option<u32> {
+    let mut rest = denom.clone();
+    let (mut twos, mut fives) = (0, 0);
+    let (two, five) = (BigInt::from(2), BigInt::from(5));
+    while (&rest % &two).is_zero() {
+        rest /= &two;
+        twos += 1;
+    }
+    while (&rest % &five).is_zero() {
+        rest /= &five;
+        fives += 1;
+    }
+    rest.is_one().then_some(twos.max(fives))
+}
+
+fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a.max(1)
+}
@@ -1,16 +1,19 @@
-use fancy_regex::Regex;
+use num_traits::ToPrimitive;
 
 use crate::parsers::number::*;
+use crate::parsers::number::classes::NumberInfo;
+use crate::parsers::number::constants::{BINARY, HEX, OCTAL};
+use crate::parsers::number::exact::beyond_max_exponent;
 use crate::parsers::number::utils::*;
 
-fn convert_to_number(tokens: Vec<DataHolder>, _data: &mut Data) -> Vec<DataHolder> {
+fn convert_to_number(tokens: Vec<DataHolder>, _data: &Data) -> Vec<DataHolder> {
     tokens
         .iter()
         .map(|t| {
             if t.type_ == "text" {
                 let cleaned_token = _clean(t.clone(), Some(true));
                 let text_token = cleaned_token.text.unwrap();
-                string_to_num(text_token)
+                string_to_num(text_token).map(DataHolder::from).unwrap_or_else(|| t.clone())
             } else {
                 t.clone()
             }
@@ -18,7 +21,7 @@ fn convert_to_number(tokens: Vec<DataHolder>, _data: &mut Data) -> Vec<DataHolde
         .collect::<Vec<DataHolder>>()
 }
 
-fn _word_to_number(tokens: Vec<DataHolder>, data: &mut Data) -> Vec<DataHolder> {
+fn _word_to_number(tokens: Vec<DataHolder>, data: &Data) -> Vec<DataHolder> {
     let mut all_n = data.all_nums().clone();
     all_n.extend(data.informal_all().clone());
     tokens
@@ -44,14 +47,17 @@ fn _word_to_number(tokens: Vec<DataHolder>, data: &mut Data) -> Vec<DataHolder>
         .collect::<Vec<DataHolder>>()
 }
 
-fn convert_suffixes(tokens: Vec<DataHolder>, data: &mut Data) -> Vec<DataHolder> {
-    let pattern = Regex::new(&data.number_followed_by_suffix_regex()).unwrap();
+fn convert_suffixes(tokens: Vec<DataHolder>, data: &Data) -> Vec<DataHolder> {
+    // a clone shares the compiled regex while `data` converts the numbers
+    let shared = data.clone();
+    let pattern = &shared.regexes().suffix;
 
     tokens
         .iter()
         .map(|t| {
             if t.type_ == "text" {
-                let cleaned_token = _clean(t.clone(), Some(true));
+                // suffixes are case sensitive, `M` is mega and `m` is milli
+                let cleaned_token = _clean(t.clone(), Some(false));
                 let text_token = cleaned_token.text.unwrap_or_default();
 
                 if let Some(caps) = pattern.captures(&text_token).unwrap() {
@@ -89,8 +95,10 @@ fn convert_suffixes(tokens: Vec<DataHolder>, data: &mut Data) -> Vec<DataHolder>
 }
 
 
-fn convert_ordinals(tokens: Vec<DataHolder>, data: &mut Data) -> Vec<DataHolder> {
-    let pattern = Regex::new(&data.ordinal_numeral_regex()).unwrap();
+fn convert_ordinals(tokens: Vec<DataHolder>, data: &Data) -> Vec<DataHolder> {
+    // a clone shares the compiled regex while `data` converts the numbers
+    let shared = data.clone();
+    let pattern = &shared.regexes().ordinal;
 
     tokens
         .into_iter()  // Changed from into_par_iter to into_iter
@@ -103,29 +111,18 @@ fn convert_ordinals(tokens: Vec<DataHolder>, data: &mut Data) -> Vec<DataHolder>
                     let num_str = caps.name("number").unwrap().as_str();
                     let ordinal = caps.name("ordinal").unwrap().as_str().to_lowercase();
 
-                    let mut num = convert_to_number(vec![DataHolder {
+                    let mut num = holder_value(&convert_to_number(vec![DataHolder {
                         type_: "text",
                         text: Some(num_str.to_string()),
                         int: None,
                         float: None,
-                    }], data)[0]
-                        .clone()
-                        .float
-                        .or(Some(0.0))
-                        .unwrap();
-
-                    if data.ordinal_suffixes().contains(&ordinal) {
-                        num = convert_to_number(vec![DataHolder {
-                            type_: "text",
-                            text: Some(num_str.to_string()),
-                            int: None,
-                            float: None,
-                        }], data)[0]
-                            .clone()
-                            .float
-                            .unwrap();
-                    } else if let Some(multiplier) = data.ordinals().get(ordinal.as_str()) {
-                        num *= multiplier;
+                    }], data)[0])
+                        .unwrap_or(0.0);
+
+                    if !data.ordinal_suffixes().contains(&ordinal) {
+                        if let Some(multiplier) = data.ordinals().get(ordinal.as_str()) {
+                            num *= multiplier;
+                        }
                     }
 
                     DataHolder {
@@ -144,14 +141,14 @@ fn convert_ordinals(tokens: Vec<DataHolder>, data: &mut Data) -> Vec<DataHolder>
         .collect::<Vec<DataHolder>>()
 }
 
-fn convert_supersubscript(tokens: Vec<DataHolder>, data: &mut Data) -> Vec<DataHolder> {
+fn convert_supersubscript(tokens: Vec<DataHolder>, data: &Data) -> Vec<DataHolder> {
     tokens
         .iter()
         .map(|t| {
             if t.type_ == "text" {
                 let cleaned_token = _clean(t.clone(), Some(true));
                 let text_token = cleaned_token.text.unwrap();
-                let first_char = text_token.chars().nth(0).unwrap();
+                let first_char = text_token.chars().next().unwrap();
                 if data.superscript_ones().contains_key(&first_char) {
                     let n = text_token.chars()
                     .map(|c| (*data.superscript_ones().get(&c).unwrap() as i64).to_string())
@@ -197,18 +194,18 @@ struct ConversionPipe {
 }
 
 impl ConversionPipe {
-    pub fn new(data: &mut Data) -> Self {
+    pub fn new(data: &Data) -> Self {
 
         ConversionPipe { data: data.clone()}
     }
 
     pub fn call(&mut self, tokens: Vec<DataHolder>) -> Vec<DataHolder> {
         let mut tokens = tokens;
-        tokens = convert_to_number(tokens, &mut self.data);
-        tokens = convert_ordinals(tokens, &mut self.data);
-        tokens = convert_suffixes(tokens, &mut self.data);
-        tokens = convert_supersubscript(tokens, &mut self.data);
-        tokens = _word_to_number(tokens, &mut self.data);
+        tokens = convert_to_number(tokens, &self.data);
+        tokens = convert_ordinals(tokens, &self.data);
+        tokens = convert_suffixes(tokens, &self.data);
+        tokens = convert_supersubscript(tokens, &self.data);
+        tokens = _word_to_number(tokens, &self.data);
         tokens
     }
 }
@@ -276,7 +273,7 @@ fn find_total(tokens: Vec<(f64, f64)>) -> f64 {
     total
 }
 
-pub fn try_power(n: Vec<DataHolder>, data: &mut Data) -> Option<f64> {
+pub fn try_power(n: Vec<DataHolder>, data: &Data) -> Option<f64> {
     let mut neg = 1.0;
     let mut n = n.clone();
 
@@ -289,19 +286,11 @@ pub fn try_power(n: Vec<DataHolder>, data: &mut Data) -> Option<f64> {
     }
 
     let res = ConversionPipe::new(data).call(n.clone());
-    let mult = match res.get(0) {
-        Some(holder) if holder.type_ == "float" => holder.float?,
-        Some(holder) if holder.type_ == "int" => (holder.int?) as f64,
-        _ => return None,
-    };
-
-    let num = match res.get(1) {
-        Some(holder) if holder.type_ == "float" => holder.float?,
-        Some(holder) if holder.type_ == "int" => (holder.int?) as f64,
-        _ => return None,
-    };
+    let mult = holder_value(res.first()?)?;
+    let num = holder_value(res.get(1)?)?;
 
-    let op: fn(f64, f64) -> f64 = if num > mult && data.informal_all().contains_key(&n[1].text.clone().unwrap().to_lowercase()) {
+    // `forty two` and `hundred five` are sums, `two million` and `two halves` are products
+    let op: fn(f64, f64) -> f64 = if mult > num && !data.informal_all().contains_key(&n[1].text.clone()?.to_lowercase()) {
         add
     } else {
         mul
@@ -310,6 +299,10 @@ pub fn try_power(n: Vec<DataHolder>, data: &mut Data) -> Option<f64> {
     Some(op(neg * num, mult))
 }
 
+fn holder_value(holder: &DataHolder) -> Option<f64> {
+    holder.float.or(holder.int.map(|v| v as f64))
+}
+
 fn point_num(tokens: Vec<DataHolder>) -> f64 {
     let mut tokens = tokens;
     let mut last = 1.0;
@@ -325,7 +318,7 @@ fn point_num(tokens: Vec<DataHolder>) -> f64 {
         None => (&tokens[..], &[][..]),
     };
     let whole_numbers: Vec<f64> = whole_tokens.iter()
-        .filter_map(|x| x.float)
+        .filter_map(holder_value)
         .collect();
 
     let paired = pair_tokens(whole_numbers);
@@ -338,9 +331,9 @@ fn point_num(tokens: Vec<DataHolder>) -> f64 {
     let mut dec_string: String = dec_tokens.iter()
         .map(|x| {
             if x.type_ == "float" {
-                x.float.clone().unwrap().to_string()
+                x.float.unwrap().to_string()
             } else {
-                ((x.int.clone().unwrap()) as f64).to_string()
+                ((x.int.unwrap()) as f64).to_string()
             }
         }
         )
@@ -359,10 +352,10 @@ fn filter_tokens(tokens: &mut Vec<DataHolder>, unwanted: &str, leave_last: Optio
     while i >= 0 {
         if let Some(token) = tokens.get(i as usize) {
             if let Some(text) = &token.text {
-                if text.to_lowercase() == unwanted {
-                    if !leave_last || tokens.iter().skip(i as usize).any(|t| t.text.as_ref().map_or(false, |s| s.to_lowercase() == unwanted)) {
-                        tokens.remove(i as usize);
-                    }
+                // with `leave_last` only the last occurrence survives
+                if text.to_lowercase() == unwanted
+                    && (!leave_last || tokens.iter().skip(i as usize + 1).any(|t| t.text.as_ref().is_some_and(|s| s.to_lowercase() == unwanted))) {
+                    tokens.remove(i as usize);
                 }
             }
         }
@@ -371,16 +364,119 @@ fn filter_tokens(tokens: &mut Vec<DataHolder>, unwanted: &str, leave_last: Optio
     tokens.clone()
 }
 
-pub fn words2num(dataholder: DataHolder, data: &mut Data) -> Option<f64> {
-    if dataholder.type_ == "integer" || dataholder.type_ == "octal" || dataholder.type_ == "binary" || dataholder.type_ == "hexadecimal" {
-        return Some(dataholder.int.clone().unwrap() as f64)
+/// Converts a number written with digits, words or both to its value.
+///
+/// `twenty-one` -> `Integer(21)` flagged as spoken, `0x1F` -> `Integer(31)` in radix 16,
+/// `1,287` -> `Integer(1287)` and `one and a half` -> `Float(1.5)`.
+pub fn words2num(dataholder: DataHolder, data: &Data) -> Option<NumberValue> {
+    let text = match dataholder {
+        DataHolder { text: Some(text), .. } => text,
+        DataHolder { int: Some(value), type_, .. } => {
+            let radix = match type_ {
+                t if t == BINARY => 2,
+                t if t == OCTAL => 8,
+                t if t == HEX => 16,
+                _ => 10,
+            };
+            return Some(NumberValue::Integer { value, form: NumberForm::radix(radix) });
+        }
+        DataHolder { float, .. } => return float.map(NumberValue::from),
+    };
+    let cleaned = _clean(DataHolder { type_: "text", text: Some(text.clone()), int: None, float: None }, None)
+        .text
+        .unwrap_or_default();
+    // `0755`, `10u32`, `1.5f`
+    if data.config().code_literals.unwrap_or(false) {
+        if let Some(literal) = parse_literal(&text) {
+            return Some(literal);
+        }
     }
-    if dataholder.type_ == "float" {
-        return dataholder.float.clone();
+    if let Ok(literal) = parse_number(&cleaned) {
+        return Some(literal);
     }
+    // `3万`, `1億2000万`
+    if let Some(myriad) = words2myriad(&text, data) {
+        return if data.config().exact.unwrap_or(false) {
+            Some(NumberValue::from_ratio(myriad))
+        } else {
+            myriad.to_f64().map(NumberValue::from_float)
+        };
+    }
+    // `三百五十`, `一万二千`, read like spoken numbers
+    if let Some(cjk) = words2cjk(&text, data) {
+        let value = if data.config().exact.unwrap_or(false) {
+            NumberValue::from_ratio(cjk)
+        } else {
+            NumberValue::from_float(cjk.to_f64()?)
+        };
+        return Some(value.with_form(NumberForm { spoken: true, ..NumberForm::default() }));
+    }
+    if data.config().parse_roman.unwrap_or(false) {
+        if let Some(roman) = words2roman(&text, data) {
+            return Some(roman);
+        }
+    }
+    if data.config().parse_complex.unwrap_or(false) {
+        if let Some((re, im)) = words2complex(&text, data) {
+            let spoken = NumberInfo::new(data.clone()).is_spoken(&text);
+            return Some(NumberValue::Complex { re, im, form: NumberForm { spoken, ..NumberForm::default() } });
+        }
+    }
+    if let Some(fraction) = words2fraction(&text, data) {
+        let spoken = NumberInfo::new(data.clone()).is_spoken(&text);
+        let value = match NumberValue::from_fraction(fraction.clone()) {
+            NumberValue::Float { .. } if data.config().exact.unwrap_or(false) => NumberValue::from_ratio(fraction),
+            value => value,
+        };
+        return Some(value.with_form(NumberForm { spoken, ..NumberForm::default() }));
+    }
+    // `negative twenty-one`, `minus zero point five`
+    if let Some((first, rest)) = text.trim().split_once(char::is_whitespace) {
+        if data.negatives().contains(&first.to_lowercase()) {
+            let value = words2num(DataHolder { type_: "text", text: Some(rest.to_string()), int: None, float: None }, data)?;
+            let form = NumberForm { spoken: true, ..value.form() };
+            return Some((-value).with_form(form));
+        }
+    }
+    // `42nd` and `twenty-first`, a bad suffix (`21th`) isn't a number at all
+    if NumberInfo::new(data.clone()).get_ordinal_suffix(&text).is_some() {
+        return words2ordinal(&text, data);
+    }
+    let exact = if data.config().exact.unwrap_or(false) {
+        words2exact(&text, data)
+    } else {
+        None
+    };
+    let value = match exact {
+        Some(_) => 0.0,
+        // the float conversion only knows English, the other languages go through their tables
+        None if data.language.code() != "en" => words2exact(&text, data)?.to_f64()?,
+        None => words2float(text.clone(), data)?,
+    };
+    // `1e99999999` is past `MAX_EXPONENT`, exact mode doesn't round it to infinity
+    if exact.is_none() && data.config().exact.unwrap_or(false) && beyond_max_exponent(text.trim()) {
+        return None;
+    }
+    let mut info = NumberInfo::new(data.clone());
+    let form = NumberForm { spoken: info.is_spoken(&text), ..NumberForm::default() };
+    let lower = text.to_lowercase();
+    let written_as_float = if form.spoken {
+        lower.split_whitespace().any(|w| data.points().contains(&w.to_string()))
+    } else {
+        info.is_float(&text)
+    };
+    let number = if let Some(exact) = exact {
+        NumberValue::from_ratio(exact)
+    } else if written_as_float {
+        NumberValue::from(value)
+    } else {
+        NumberValue::from_float(value)
+    };
+    Some(number.with_form(form))
+}
 
-
-    let mut number = dataholder.text?;
+fn words2float(text: String, data: &Data) -> Option<f64> {
+    let mut number = text;
     
     // Tokenize and filter the number
     let tokens: Vec<DataHolder> = filter_tokens(&mut tokenize(&number)
@@ -393,7 +489,7 @@ pub fn words2num(dataholder: DataHolder, data: &mut Data) -> Option<f64> {
         })
         .collect(), "a", None);
     if tokens.len() == 1 {
-        number = tokens.get(0)?.text.clone()?;
+        number = tokens.first()?.text.clone()?;
     }
 
     let num = ConversionPipe::new(data).call(vec![DataHolder {
@@ -404,9 +500,9 @@ pub fn words2num(dataholder: DataHolder, data: &mut Data) -> Option<f64> {
     }]);
     
     if num.len() == 1 {
-        if let Some(DataHolder { float: Some(value), .. }) = num.get(0) {
+        if let Some(DataHolder { float: Some(value), .. }) = num.first() {
             return Some(*value);
-        } else if let Some(DataHolder { int: Some(value), .. }) = num.get(0) {
+        } else if let Some(DataHolder { int: Some(value), .. }) = num.first() {
             return Some((*value) as f64);
         }
     }
@@ -439,7 +535,7 @@ pub fn words2num(dataholder: DataHolder, data: &mut Data) -> Option<f64> {
              })
              .collect(), data);
              if value.is_some(){
-                return Some(value?);
+                return value;
              }
     }
     let string_tokens = tokens.clone();
@@ -459,16 +555,16 @@ pub fn words2num(dataholder: DataHolder, data: &mut Data) -> Option<f64> {
     }
 
     if tokens.len() == 2 && tokens.iter().all(|t| t.float.is_some() || t.int.is_some()) {
-        let first = string_tokens.get(0)?.replace(" ", "");
+        let first = string_tokens.first()?.replace(" ", "");
         if first.len() == 5 && &first[1..2] == "," {
-            if let Some(DataHolder { float: Some(val), .. }) = tokens.get(0) {
+            if let Some(DataHolder { float: Some(val), .. }) = tokens.first() {
                 return Some(val / 1000.0);
             }
         }
         let op: fn(f64, f64) -> f64 = if let (
             Some(DataHolder { float: Some(val1), .. }),        
             Some(DataHolder { float: Some(val2), .. })
-        ) = (tokens.get(0), tokens.get(1)) {
+        ) = (tokens.first(), tokens.get(1)) {
             if val1 > val2 {
                 add
             } else {
@@ -478,8 +574,8 @@ pub fn words2num(dataholder: DataHolder, data: &mut Data) -> Option<f64> {
             mul
         };
         return Some(op(
-            tokens.get(0)?.float?,
-            tokens.get(1)?.float?,
+            holder_value(tokens.first()?)?,
+            holder_value(tokens.get(1)?)?,
         ));
     }
 
@@ -507,40 +603,42 @@ pub fn words2num(dataholder: DataHolder, data: &mut Data) -> Option<f64> {
     let mut points = None;
     let mut negative = 1.0;
 
-    if let Some(DataHolder { text: Some(s), .. }) = tokens.get(0) {
+    if let Some(DataHolder { text: Some(s), .. }) = tokens.first() {
         if data.negatives().contains(s) {
             tokens.remove(0);
             negative = -1.0;
         }
     }
 
+    // anything left that is neither a number nor a connective is not part of a number
+    let connectives = [data.ands().as_slice(), data.points().as_slice()].concat();
+    if tokens.iter().any(|t| t.text.as_ref().is_some_and(|s| !connectives.contains(s))) {
+        return None;
+    }
+
     let mut operation: Option<fn(f64, f64) -> f64> = None;
     let mut fraction = 0.0;
 
     if tokens.len() >= 2 {
         if let Some(DataHolder { float: Some(value), .. }) = tokens.last() {
-            if *value < 1.0 {
-                if let Some(DataHolder { text: Some(s), .. }) = tokens.get(tokens.len() - 2) {
-                    if s == "and" {
+            let value = *value;
+            if value < 1.0 {
+                match tokens.get(tokens.len() - 2) {
+                    Some(DataHolder { text: Some(s), .. }) if s == "and" => {
                         operation = Some(add);
-                        fraction = *value;
+                        fraction = value;
+                        tokens.pop();
+                    }
+                    Some(t) if holder_value(t).is_some() => {
+                        operation = Some(mul);
+                        fraction = value;
                         tokens.pop();
                     }
-                } else if let Some(DataHolder { float: Some(value), .. }) = tokens.get(tokens.len() - 2) {
-                    operation = Some(mul);
-                    fraction = *value;
+                    _ => {}
                 }
             }
         }
     }
-
-    if operation == Some(mul) {
-        fraction = if let Some(DataHolder { float: Some(value), .. }) = tokens.pop() {
-            value
-        } else {
-            return None;
-        };
-    }
     
     tokens = filter_tokens(&mut tokens, "and",  None);
     
@@ -551,7 +649,7 @@ pub fn words2num(dataholder: DataHolder, data: &mut Data) -> Option<f64> {
         tokens.truncate(point_idx);
     }
     
-    let paired = pair_tokens(tokens.into_iter().filter_map(|t| t.float).collect());
+    let paired = pair_tokens(tokens.iter().filter_map(holder_value).collect());
     let summed = sum_nums(paired);
 
     let mut new_tokens: Vec<f64> = summed.clone();
@@ -571,4 +669,4 @@ pub fn words2num(dataholder: DataHolder, data: &mut Data) -> Option<f64> {
 
 
 
-            
\ No newline at end of file
+            
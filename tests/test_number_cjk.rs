@@ -0,0 +1,57 @@
+#[cfg(test)]
+mod tests {
+    use nlp_rust::parsers::number::{words2cjk, Config, Data, NumberParser, NumberSystem};
+
+    #[test]
+    fn test_cjk_numerals() {
+        let mut parser = NumberParser::default();
+        for (text, number, value) in [
+            ("三百五十", "三百五十", "350"),
+            ("一万二千", "一万二千", "12000"),
+            ("二十一個", "二十一", "21"),
+            ("十五", "十五", "15"),
+            ("一千零五", "一千零五", "1005"),
+            ("两千", "两千", "2000"),
+            ("二〇二四年", "二〇二四", "2024"),
+            ("三点五", "三点五", "3.5"),
+            ("一億二千万", "一億二千万", "120000000"),
+            ("価格は二十五万円です", "二十五万", "250000"),
+        ] {
+            let found = parser.extract(text);
+            assert_eq!(found.len(), 1, "{text}");
+            assert_eq!(found[0].text, number);
+            assert_eq!(found[0].value.to_string(), value, "{text}");
+        }
+    }
+
+    #[test]
+    fn test_cjk_mixed_and_financial() {
+        let mut parser = NumberParser::default();
+        for (text, number, value) in [
+            ("3万五千", "3万五千", "35000"),
+            ("１２万３千", "１２万３千", "123000"),
+            ("壹佰贰拾元", "壹佰贰拾", "120"),
+            ("壹萬貳仟", "壹萬貳仟", "12000"),
+        ] {
+            let found = parser.extract(text);
+            assert_eq!(found.len(), 1, "{text}");
+            assert_eq!(found[0].text, number);
+            assert_eq!(found[0].value.to_string(), value, "{text}");
+        }
+        let data = Data::new(Config::default());
+        assert_eq!(words2cjk("十百", &data), None);
+        assert_eq!(words2cjk("万一億", &data), None);
+        assert_eq!(words2cjk("3万", &data), None);
+    }
+
+    #[test]
+    fn test_cjk_match() {
+        let found = NumberParser::default().extract("在庫 一万二千 個");
+        assert_eq!(found[0].span, (7, 19));
+        assert_eq!(found[0].number_type, "spoken");
+        assert!(found[0].value.is_spoken());
+        assert_eq!(found[0].value.system(), NumberSystem::Myriad);
+        let mut exact = NumberParser::new(Config { exact: Some(true), ..Config::default() });
+        assert_eq!(exact.extract("九千九百九十九兆")[0].value.to_string(), "9999000000000000");
+    }
+}
@@ -0,0 +1,63 @@
+#[cfg(test)]
+mod tests {
+    use nlp_rust::parsers::number::{Config, NumberParser, NumberValue};
+
+    fn parts(value: &NumberValue) -> Option<(f64, f64)> {
+        match value {
+            NumberValue::Complex { re, im, .. } => Some((*re, *im)),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_typed_complex() {
+        let config = Config { parse_complex: Some(true), ..Config::default() };
+        let matches = NumberParser::new(config).extract("z = 3+4i, w = -2.5j and 5 - 2i or 3+i");
+        assert!(matches.iter().all(|m| m.number_type == "complex"));
+        let found: Vec<(String, Option<(f64, f64)>)> = matches
+            .into_iter()
+            .map(|m| (m.text, parts(&m.value)))
+            .collect();
+        assert_eq!(found, vec![
+            ("3+4i".to_string(), Some((3.0, 4.0))),
+            ("-2.5j".to_string(), Some((0.0, -2.5))),
+            ("5 - 2i".to_string(), Some((5.0, -2.0))),
+            ("3+i".to_string(), Some((3.0, 1.0))),
+        ]);
+    }
+
+    #[test]
+    fn test_spoken_complex() {
+        let config = Config { parse_complex: Some(true), ..Config::default() };
+        let mut parser = NumberParser::new(config);
+        let matches = parser.extract("two plus three i, twenty-one point five minus seven i and four j");
+        // typed or spoken, the value decides the type
+        assert!(matches.iter().all(|m| m.number_type == "complex"));
+        let found: Vec<(String, Option<(f64, f64)>)> = matches
+            .into_iter()
+            .map(|m| (m.text, parts(&m.value)))
+            .collect();
+        assert_eq!(found, vec![
+            ("two plus three i".to_string(), Some((2.0, 3.0))),
+            ("twenty-one point five minus seven i".to_string(), Some((21.5, -7.0))),
+            ("four j".to_string(), Some((0.0, 4.0))),
+        ]);
+        // the unit is lowercase, `I` is a pronoun
+        let found: Vec<String> = parser
+            .extract("the three I saw")
+            .into_iter()
+            .map(|m| m.value.to_string())
+            .collect();
+        assert_eq!(found, vec!["3"]);
+    }
+
+    #[test]
+    fn test_complex_flag_off() {
+        let found: Vec<String> = NumberParser::default()
+            .extract("3+4i and two plus three i")
+            .into_iter()
+            .map(|m| m.value.to_string())
+            .collect();
+        assert_eq!(found, vec!["3", "4", "2", "3"]);
+    }
+}
@@ -0,0 +1,45 @@
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use nlp_rust::parsers::number::{Config, Data, NumberParser};
+
+    #[test]
+    fn test_tables_are_built_once() {
+        let data = Data::new(Config::default());
+        assert!(std::ptr::eq(data.ones(), data.ones()));
+        assert!(std::ptr::eq(data.all_valid(), data.all_valid()));
+        assert!(std::ptr::eq(data.integer_regex(), data.integer_regex()));
+        // clones share the tables and the compiled regexes
+        let clone = data.clone();
+        assert!(std::ptr::eq(data.multiples(), clone.multiples()));
+        assert!(std::ptr::eq(data.regexes(), clone.regexes()));
+        // the config they were built from can be read, not changed
+        let exact = Data::new(Config { exact: Some(true), ..Config::default() });
+        assert_eq!(exact.config().exact, Some(true));
+    }
+
+    #[test]
+    fn test_data_shared_across_threads() {
+        let data = Data::new(Config::default());
+        let texts = ["I have 5 apples", "twenty-one pears", "one hundred and five", "3.5k"];
+        let expected: Vec<Vec<String>> = texts
+            .iter()
+            .map(|text| NumberParser::with_data(data.clone()).extract(text).into_iter().map(|m| m.text).collect())
+            .collect();
+        thread::scope(|scope| {
+            let handles: Vec<_> = texts
+                .iter()
+                .map(|text| {
+                    let data = data.clone();
+                    scope.spawn(move || {
+                        NumberParser::with_data(data).extract(text).into_iter().map(|m| m.text).collect::<Vec<_>>()
+                    })
+                })
+                .collect();
+            for (handle, expected) in handles.into_iter().zip(expected) {
+                assert_eq!(handle.join().unwrap(), expected);
+            }
+        });
+    }
+}
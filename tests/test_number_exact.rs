@@ -0,0 +1,87 @@
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use num_bigint::BigInt;
+    use nlp_rust::parsers::number::{words2num, Config, Data, DataHolder, NumberParser, NumberValue};
+
+    fn exact(text: &str) -> Option<NumberValue> {
+        let data = Data::new(Config { exact: Some(true), ..Config::default() });
+        let holder = DataHolder {
+            type_: "text",
+            text: Some(text.to_string()),
+            int: None,
+            float: None,
+        };
+        words2num(holder, &data)
+    }
+
+    #[test]
+    fn test_exact_integers() {
+        assert!(matches!(
+            exact("nine quintillion and one"),
+            Some(NumberValue::Integer { value: 9_000_000_000_000_000_001, .. })
+        ));
+        assert!(matches!(exact("minus seven"), Some(NumberValue::Integer { value: -7, .. })));
+        assert!(matches!(exact("2.5M"), Some(NumberValue::Integer { value: 2_500_000, .. })));
+    }
+
+    #[test]
+    fn test_exact_decimals() {
+        match exact("one point one") {
+            Some(NumberValue::Decimal { digits, scale, form }) => {
+                assert_eq!((digits, scale), (BigInt::from(11), 1));
+                assert!(form.spoken);
+            }
+            other => panic!("expected a decimal, got {:?}", other),
+        }
+        assert_eq!(exact("-1,287.05").map(|v| v.to_string()), Some("-1287.05".to_string()));
+        assert_eq!(exact("two point five").map(|v| v.to_string()), Some("2.5".to_string()));
+        assert_eq!(exact("1e-20").map(|v| v.to_string()), Some("0.00000000000000000001".to_string()));
+        assert!(matches!(exact("two point five million"), Some(NumberValue::Integer { value: 2_500_000, .. })));
+        assert!(matches!(exact("one point five billion"), Some(NumberValue::Integer { value: 1_500_000_000, .. })));
+    }
+
+    #[test]
+    fn test_exact_huge_exponents() {
+        // no exact value and no rounded float either, rather than overflowing or building a huge integer
+        assert_eq!(exact("1e-2147483648"), None);
+        assert_eq!(exact("1e999999999"), None);
+        assert_eq!(exact("1e99999999999999999999"), None);
+    }
+
+    #[test]
+    fn test_beyond_i64() {
+        let value = exact("one hundred twenty three vigintillion").unwrap();
+        let expected = BigInt::from(123) * BigInt::from_str(&format!("1{}", "0".repeat(63))).unwrap();
+        assert_eq!(value, NumberValue::BigInteger { value: expected, form: value.form() });
+        // the float is only a convenience
+        assert_eq!(value.to_f64(), Some(1.23e65));
+
+        let digits = "123456789012345678901234567890";
+        assert_eq!(exact(digits).map(|v| v.to_string()), Some(digits.to_string()));
+    }
+
+    #[test]
+    fn test_float_mode_is_default() {
+        let data = Data::new(Config::default());
+        let holder = DataHolder {
+            type_: "text",
+            text: Some("one point one".to_string()),
+            int: None,
+            float: None,
+        };
+        assert!(matches!(words2num(holder, &data), Some(NumberValue::Float { .. })));
+    }
+
+    #[test]
+    fn test_exact_extraction_and_merging() {
+        let mut parser = NumberParser::new(Config { exact: Some(true), ..Config::default() });
+        let values: Vec<String> = parser
+            .extract("nine quintillion and one, ¾ million and 1.5 dozen")
+            .into_iter()
+            .map(|m| m.value.to_string())
+            .collect();
+        assert_eq!(values, vec!["9000000000000000001", "750000", "18"]);
+    }
+}
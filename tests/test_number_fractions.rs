@@ -0,0 +1,80 @@
+#[cfg(test)]
+mod tests {
+    use nlp_rust::parsers::number::{words2num, Config, Data, DataHolder, NumberParser, NumberValue};
+
+    fn fraction(text: &str) -> Option<(i64, i64, f64)> {
+        let data = Data::new(Config::default());
+        let holder = DataHolder {
+            type_: "text",
+            text: Some(text.to_string()),
+            int: None,
+            float: None,
+        };
+        match words2num(holder, &data)? {
+            value @ NumberValue::Rational { numer, denom, .. } => Some((numer, denom, value.to_f64()?)),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_spoken_fractions() {
+        assert_eq!(fraction("three quarters"), Some((3, 4, 0.75)));
+        assert_eq!(fraction("five eighths"), Some((5, 8, 0.625)));
+        assert_eq!(fraction("a fifth"), Some((1, 5, 0.2)));
+        assert_eq!(fraction("one and three sixteenths"), Some((19, 16, 1.1875)));
+        assert_eq!(fraction("two and a half"), Some((5, 2, 2.5)));
+        let (numer, denom, _) = fraction("two thirds").unwrap();
+        assert_eq!((numer, denom), (2, 3));
+        // singular denominators need a numerator of one
+        assert_eq!(fraction("twenty third"), None);
+    }
+
+    #[test]
+    fn test_typed_fractions() {
+        assert_eq!(fraction("3/4"), Some((3, 4, 0.75)));
+        assert_eq!(fraction("1 1/2"), Some((3, 2, 1.5)));
+        assert_eq!(fraction("-1 1/2"), Some((-3, 2, -1.5)));
+        assert_eq!(fraction("1½"), Some((3, 2, 1.5)));
+        assert_eq!(fraction("6/8"), Some((3, 4, 0.75)));
+        assert_eq!(fraction("1/0"), None);
+        // beyond `i64` they are floats, unless read exactly
+        for text in ["1/9223372036854775808", "99999999999999999999 1/2"] {
+            let holder = DataHolder { type_: "text", text: Some(text.to_string()), int: None, float: None };
+            let value = words2num(holder.clone(), &Data::new(Config::default()));
+            assert!(matches!(value, Some(NumberValue::Float { .. })), "{text}");
+            let exact = Data::new(Config { exact: Some(true), ..Config::default() });
+            assert!(matches!(words2num(holder, &exact), Some(NumberValue::Decimal { .. })), "{text}");
+        }
+    }
+
+    #[test]
+    fn test_fraction_extraction() {
+        let mut parser = NumberParser::default();
+        let found: Vec<(String, String, &str)> = parser
+            .extract("add three quarters cup and 1 1/2 tsp on 12/31/2024, the twenty third")
+            .into_iter()
+            .map(|m| (m.text, m.value.to_string(), m.number_type))
+            .collect();
+        assert_eq!(found, vec![
+            ("three quarters".to_string(), "3/4".to_string(), "spoken"),
+            ("1 1/2".to_string(), "3/2".to_string(), "rational"),
+            ("12".to_string(), "12".to_string(), "integer"),
+            ("31".to_string(), "31".to_string(), "integer"),
+            ("2024".to_string(), "2024".to_string(), "integer"),
+            ("twenty third".to_string(), "23".to_string(), "ordinal"),
+        ]);
+        // a year isn't the whole part of a mixed number
+        let found: Vec<(String, String)> = parser
+            .extract("in 2023 1/3 of users left")
+            .into_iter()
+            .map(|m| (m.text, m.value.to_string()))
+            .collect();
+        assert_eq!(found, vec![("2023".to_string(), "2023".to_string()), ("1/3".to_string(), "1/3".to_string())]);
+        assert_eq!(parser.extract("2\n1/2").len(), 2);
+        // signs follow `Config::signs_allowed`, like the other numerals
+        assert_eq!(parser.extract("x -1/2")[0].value.to_string(), "1/2");
+        assert_eq!(parser.extract("x -5")[0].value.to_string(), "5");
+        let signed = Config { signs_allowed: Some(true), ..Config::default() };
+        assert_eq!(NumberParser::new(signed).extract("x -1/2")[0].value.to_string(), "-1/2");
+    }
+}
@@ -0,0 +1,62 @@
+#[cfg(test)]
+mod tests {
+    use nlp_rust::parsers::number::{words2num, Config, Data, DataHolder, NumberParser};
+
+    fn config(language: &str) -> Config {
+        Config { language: Some(language.to_string()), ..Config::default() }
+    }
+
+    fn value(language: &str, text: &str) -> Option<String> {
+        let data = Data::new(config(language));
+        let holder = DataHolder {
+            type_: "text",
+            text: Some(text.to_string()),
+            int: None,
+            float: None,
+        };
+        Some(words2num(holder, &data)?.to_string())
+    }
+
+    fn extract(language: &str, text: &str) -> Vec<(String, String)> {
+        NumberParser::new(config(language))
+            .extract(text)
+            .into_iter()
+            .map(|m| (m.text, m.value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_compounds() {
+        assert_eq!(value("es", "dos mil quince").as_deref(), Some("2015"));
+        assert_eq!(value("es", "trescientos cuarenta y dos").as_deref(), Some("342"));
+        assert_eq!(value("fr", "vingt-et-un").as_deref(), Some("21"));
+        assert_eq!(value("fr", "quatre-vingt-dix-neuf").as_deref(), Some("99"));
+        assert_eq!(value("fr", "cent quatre-vingts").as_deref(), Some("180"));
+        assert_eq!(value("de", "einundzwanzig").as_deref(), Some("21"));
+        assert_eq!(value("de", "zweihundertdreiundvierzig").as_deref(), Some("243"));
+        assert_eq!(value("de", "eine Million").as_deref(), Some("1000000"));
+    }
+
+    #[test]
+    fn test_extraction_per_language() {
+        assert_eq!(extract("es", "pagó dos mil quince pesos por veintiún libros"), vec![
+            ("dos mil quince".to_string(), "2015".to_string()),
+            ("veintiún".to_string(), "21".to_string()),
+        ]);
+        assert_eq!(extract("fr", "soixante et onze ou deux mille vingt-quatre"), vec![
+            ("soixante et onze".to_string(), "71".to_string()),
+            ("deux mille vingt-quatre".to_string(), "2024".to_string()),
+        ]);
+        assert_eq!(extract("de", "Einundzwanzig Autos und zweitausendfünfzehn Räder"), vec![
+            ("Einundzwanzig".to_string(), "21".to_string()),
+            ("zweitausendfünfzehn".to_string(), "2015".to_string()),
+        ]);
+        // the English words mean nothing in another pack
+        assert_eq!(extract("de", "twenty one"), vec![]);
+    }
+
+    #[test]
+    fn test_unknown_language_is_english() {
+        assert_eq!(extract("xx", "twenty-one"), vec![("twenty-one".to_string(), "21".to_string())]);
+    }
+}
@@ -0,0 +1,64 @@
+#[cfg(test)]
+mod tests {
+    use nlp_rust::parsers::number::{parse_literal, parse_number, strip_type_suffix, Config, NumberParser, NumberValue};
+
+    #[test]
+    fn test_prefixed_literals() {
+        let mut parser = NumberParser::default();
+        let found = parser.extract("mask 0xFF_FF, flags 0B1010_1010, mode 0O755, v 0xFFu8");
+        assert_eq!(found.iter().map(|m| m.text.as_str()).collect::<Vec<_>>(), ["0xFF_FF", "0B1010_1010", "0O755", "0xFFu8"]);
+        assert_eq!(found.iter().map(|m| m.value.to_string()).collect::<Vec<_>>(), ["65535", "170", "493", "255"]);
+        assert_eq!(found.iter().map(|m| m.number_type).collect::<Vec<_>>(), ["hexadecimal", "binary", "octal", "hexadecimal"]);
+        let found = parser.extract("max 0xFFFF_FFFF_FFFF_FFFF");
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].text, "0xFFFF_FFFF_FFFF_FFFF");
+        assert_eq!(found[0].value.to_string(), "18446744073709551615");
+        assert_eq!(found[0].number_type, "hexadecimal");
+        assert!(matches!(parse_number("0x1_0000_0000_0000_0000"), Ok(NumberValue::BigInteger { form, .. }) if form.radix == 16));
+        assert!(parse_number("0b102").is_err());
+        // Rust allows a trailing `_`
+        let found = parser.extract("0x1_ and 0b1_");
+        assert_eq!(found.iter().map(|m| m.text.as_str()).collect::<Vec<_>>(), ["0x1_", "0b1_"]);
+        assert_eq!(found.iter().map(|m| m.value.to_string()).collect::<Vec<_>>(), ["1", "1"]);
+        assert_eq!(found.iter().map(|m| m.number_type).collect::<Vec<_>>(), ["hexadecimal", "binary"]);
+    }
+
+    #[test]
+    fn test_code_literals() {
+        let config = Config { code_literals: Some(true), ..Config::default() };
+        let mut parser = NumberParser::new(config);
+        let found = parser.extract("chmod 0755, n = 10u32, x = 1.5f, y = 2.5f64, z = 100UL");
+        assert_eq!(found.iter().map(|m| m.text.as_str()).collect::<Vec<_>>(), ["0755", "10u32", "1.5f", "2.5f64", "100UL"]);
+        assert_eq!(found.iter().map(|m| m.value.to_string()).collect::<Vec<_>>(), ["493", "10", "1.5", "2.5", "100"]);
+        assert_eq!(found.iter().map(|m| m.number_type).collect::<Vec<_>>(), ["octal", "integer", "float", "float", "integer"]);
+        let found = parser.extract("0.75 and 0789");
+        assert_eq!(found.iter().map(|m| m.text.as_str()).collect::<Vec<_>>(), ["0.75", "0789"]);
+        assert_eq!(found.iter().map(|m| m.value.to_string()).collect::<Vec<_>>(), ["0.75", "789"]);
+        assert_eq!(found.iter().map(|m| m.number_type).collect::<Vec<_>>(), ["float", "integer"]);
+        // the parts of dates and times stay decimal
+        let found = parser.extract("2024-01-05");
+        assert_eq!(found.iter().map(|m| m.text.as_str()).collect::<Vec<_>>(), ["2024", "01", "05"]);
+        assert_eq!(found.iter().map(|m| m.value.to_string()).collect::<Vec<_>>(), ["2024", "1", "5"]);
+        assert_eq!(found.iter().map(|m| m.number_type).collect::<Vec<_>>(), ["integer", "integer", "integer"]);
+        let found = parser.extract("at 12:05");
+        assert_eq!(found.iter().map(|m| m.text.as_str()).collect::<Vec<_>>(), ["12", "05"]);
+        assert_eq!(found.iter().map(|m| m.value.to_string()).collect::<Vec<_>>(), ["12", "5"]);
+        assert_eq!(found.iter().map(|m| m.number_type).collect::<Vec<_>>(), ["integer", "integer"]);
+        // in prose `0755` is decimal and `f` is femto
+        let mut parser = NumberParser::default();
+        assert_eq!(parser.extract("0755")[0].value.to_string(), "755");
+        assert_eq!(parser.extract("n = 10u32")[0].text, "10");
+    }
+
+    #[test]
+    fn test_parse_literal() {
+        assert_eq!(strip_type_suffix("10u32"), ("10", Some("u32")));
+        assert_eq!(strip_type_suffix("0xFF_u8"), ("0xFF", Some("u8")));
+        assert_eq!(strip_type_suffix("0x1f"), ("0x1f", None));
+        assert_eq!(parse_literal("0755").map(|v| (v.to_string(), v.form().radix)), Some(("493".to_string(), 8)));
+        assert_eq!(parse_literal("1.5f").map(|v| v.to_string()), Some("1.5".to_string()));
+        assert_eq!(parse_literal("0x1f").map(|v| v.to_string()), Some("31".to_string()));
+        assert_eq!(parse_literal("09"), None);
+        assert_eq!(parse_literal("hundred"), None);
+    }
+}
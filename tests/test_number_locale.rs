@@ -0,0 +1,62 @@
+#[cfg(test)]
+mod tests {
+    use nlp_rust::parsers::number::{Config, NumberLocale, NumberParser, Separators};
+
+    #[test]
+    fn test_fixed_locales() {
+        let german = NumberLocale::Fixed(Separators::german());
+        let french = NumberLocale::Fixed(Separators::french());
+        let indian = NumberLocale::Fixed(Separators::indian());
+        for (locale, text, number, value) in [
+            (&german, "1.234,56", "1.234,56", "1234.56"),
+            (&german, "minus 2,5", "minus 2,5", "-2.5"),
+            (&german, "1,5 million", "1,5 million", "1500000"),
+            (&french, "1 234 567,5", "1 234 567,5", "1234567.5"),
+            (&french, "1\u{202F}000 €", "1\u{202F}000", "1000"),
+            (&NumberLocale::Fixed(Separators::swiss()), "CHF 1'234'567.50", "1'234'567.50", "1234567.5"),
+            (&indian, "1,00,000", "1,00,000", "100000"),
+            (&indian, "12,34,567.89", "12,34,567.89", "1234567.89"),
+            (&NumberLocale::default(), "1,234.56", "1,234.56", "1234.56"),
+        ] {
+            let config = Config { number_locale: Some(locale.clone()), ..Config::default() };
+            let found = NumberParser::new(config).extract(text);
+            assert_eq!(found.len(), 1, "{text}");
+            assert_eq!(found[0].text, number);
+            assert_eq!(found[0].value.to_string(), value, "{text}");
+        }
+        let config = Config { number_locale: Some(german), ..Config::default() };
+        assert_eq!(NumberParser::new(config).extract("3.5").len(), 2);
+    }
+
+    #[test]
+    fn test_auto_locale() {
+        let config = Config { number_locale: Some(NumberLocale::Auto), ..Config::default() };
+        let mut parser = NumberParser::new(config);
+        for (text, value) in [("1.234,56", "1234.56"), ("1,234.56", "1234.56"), ("1.234.567", "1234567"), ("3,5", "3.5")] {
+            let found = parser.extract(text);
+            assert_eq!(found.len(), 1, "{text}");
+            assert_eq!(found[0].text, text);
+            assert_eq!(found[0].value.to_string(), value, "{text}");
+        }
+        let found = parser.extract("1,234 and 1.234");
+        assert_eq!(found.iter().map(|m| m.text.as_str()).collect::<Vec<_>>(), ["1,234", "1.234"]);
+        assert_eq!(found.iter().map(|m| m.value.to_string()).collect::<Vec<_>>(), ["1234", "1.234"]);
+        let found = parser.extract("1,2,3");
+        assert_eq!(found.iter().map(|m| m.text.as_str()).collect::<Vec<_>>(), ["1", "2", "3"]);
+    }
+
+    #[test]
+    fn test_locale_canonical() {
+        assert_eq!(NumberLocale::Fixed(Separators::german()).canonical("1.234,5e3"), "1234.5e3");
+        assert_eq!(NumberLocale::Auto.canonical("1'234,5"), "1234.5");
+        assert_eq!(NumberLocale::Auto.canonical("0,125"), "0.125");
+        let mut parser = NumberParser::new(Config {
+            number_locale: Some(NumberLocale::Fixed(Separators::german())),
+            exact: Some(true),
+            ..Config::default()
+        });
+        let money = parser.extract_money("€1.234,56");
+        assert_eq!(money[0].minor_units, Some(123456));
+        assert_eq!(parser.extract("2.000 Leute")[0].number_type, "integer");
+    }
+}
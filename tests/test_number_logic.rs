@@ -0,0 +1,56 @@
+#[cfg(test)]
+mod tests {
+    use nlp_rust::parsers::number::{Config, Data, Logic, NumberParser};
+
+    fn boundaries(tokens: &[&str]) -> Vec<bool> {
+        let data = Data::new(Config::default());
+        let tokens = tokens.iter().map(|t| t.to_string()).collect();
+        Logic::new(tokens, data).apply_sequence_logic()
+    }
+
+    fn texts(text: &str) -> Vec<String> {
+        NumberParser::default()
+            .extract(text)
+            .into_iter()
+            .map(|m| m.text)
+            .collect()
+    }
+
+    #[test]
+    fn test_chain_of_multiples() {
+        assert_eq!(
+            boundaries(&["two", "hundred", "five", "thousand", "three"]),
+            vec![true, true, true, true, false]
+        );
+    }
+
+    #[test]
+    fn test_separate_ones() {
+        assert_eq!(boundaries(&["five", "three"]), vec![false, false]);
+        assert_eq!(boundaries(&["five", ",", "three"]), vec![false, false, false]);
+    }
+
+    #[test]
+    fn test_nothing_follows_an_ordinal() {
+        assert_eq!(boundaries(&["twenty", "third", "million"]), vec![true, false, false]);
+    }
+
+    #[test]
+    fn test_increasing_multiple_cuts_the_chain() {
+        assert_eq!(
+            boundaries(&["billion", "million", "billion"]),
+            vec![true, false, false]
+        );
+        assert_eq!(boundaries(&["thousand", "thousand"]), vec![false, false]);
+    }
+
+    #[test]
+    fn test_split_spoken_numbers() {
+        assert_eq!(texts("two hundred five thousand three"), vec!["two hundred five thousand three"]);
+        assert_eq!(texts("five, three"), vec!["five", "three"]);
+        assert_eq!(texts("one two three"), vec!["one", "two", "three"]);
+        assert_eq!(texts("three hundred and fifty six"), vec!["three hundred and fifty six"]);
+        assert_eq!(texts("one and a half cups"), vec!["one and a half"]);
+        assert_eq!(texts("a quarter and two halves"), vec!["a quarter", "two halves"]);
+    }
+}
@@ -0,0 +1,69 @@
+#[cfg(test)]
+mod tests {
+    use nlp_rust::parsers::number::{Config, NumberParser};
+
+    fn extract(config: Config, text: &str) -> Vec<(String, (usize, usize), f64)> {
+        NumberParser::new(config)
+            .extract(text)
+            .into_iter()
+            .map(|m| (m.text, m.span, m.value.to_f64().unwrap()))
+            .collect()
+    }
+
+    #[test]
+    fn test_merge_multiples() {
+        let text = "about ¾ million people";
+        assert_eq!(
+            extract(Config::default(), text),
+            vec![("¾ million".to_string(), (6, 16), 750000.0)]
+        );
+        let config = Config { merge_multiples: Some(false), ..Config::default() };
+        assert_eq!(extract(config, text).len(), 2);
+    }
+
+    #[test]
+    fn test_merge_informals() {
+        assert_eq!(
+            extract(Config::default(), "buy 1.5 dozen eggs"),
+            vec![("1.5 dozen".to_string(), (4, 13), 18.0)]
+        );
+        assert_eq!(
+            extract(Config::default(), "5.5 and a quarter"),
+            vec![("5.5 and a quarter".to_string(), (0, 17), 5.75)]
+        );
+        let config = Config { merge_informals: Some(false), ..Config::default() };
+        assert_eq!(extract(config, "buy 1.5 dozen eggs").len(), 2);
+    }
+
+    #[test]
+    fn test_merge_points() {
+        let text = "it weighs 3 point 25 kg";
+        assert_eq!(extract(Config::default(), text).len(), 2);
+        assert_eq!(extract(Config::default(), "3 point 2").len(), 2);
+        assert_eq!(extract(Config::default(), "3 point 2 5").len(), 3);
+        let config = Config { merge_points: Some(true), ..Config::default() };
+        assert_eq!(extract(config.clone(), text), vec![("3 point 25".to_string(), (10, 20), 3.25)]);
+        assert_eq!(extract(config.clone(), "3 point 2"), vec![("3 point 2".to_string(), (0, 9), 3.2)]);
+        assert_eq!(extract(config, "3 point 2 5"), vec![("3 point 2 5".to_string(), (0, 11), 3.25)]);
+        // words alone are always one number
+        assert_eq!(extract(Config::default(), "three point two"), vec![("three point two".to_string(), (0, 15), 3.2)]);
+    }
+
+    #[test]
+    fn test_merge_implied() {
+        let text = "2 thousand 5 hundred";
+        assert_eq!(extract(Config::default(), text).len(), 2);
+        assert_eq!(extract(Config::default(), "twenty 5").len(), 2);
+        let config = Config { merge_implied: Some(true), ..Config::default() };
+        assert_eq!(extract(config.clone(), text), vec![(text.to_string(), (0, 20), 2500.0)]);
+        assert_eq!(extract(config.clone(), "twenty 5"), vec![("twenty 5".to_string(), (0, 8), 25.0)]);
+        // plain numerals next to each other are a list
+        assert_eq!(extract(config, "1,000 300").len(), 2);
+    }
+
+    #[test]
+    fn test_merge_disabled() {
+        let config = Config { merge: Some(false), ..Config::default() };
+        assert_eq!(extract(config, "buy 1.5 dozen eggs").len(), 2);
+    }
+}
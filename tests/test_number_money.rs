@@ -0,0 +1,79 @@
+#[cfg(test)]
+mod tests {
+    use nlp_rust::entity::EntityParser;
+    use nlp_rust::parsers::number::{minor_unit_digits, Config, NumberEntityParser, NumberParser, NumberValue};
+
+    #[test]
+    fn test_money_forms() {
+        let mut parser = NumberParser::default();
+        for (text, money, amount, currency, minor_units) in [
+            ("raised $3.5M", "$3.5M", "3500000", "USD", 350_000_000),
+            ("€1,200.50 due", "€1,200.50", "1200.5", "EUR", 120_050),
+            ("a £2bn deal", "£2bn", "2000000000", "GBP", 200_000_000_000),
+            ("a £5 bn deal", "£5 bn", "5000000000", "GBP", 500_000_000_000),
+            ("$3.5 M raised", "$3.5 M", "3500000", "USD", 350_000_000),
+            ("¥500", "¥500", "500", "JPY", 500),
+            ("1.2k EUR", "1.2k EUR", "1200", "EUR", 120_000),
+        ] {
+            let found = parser.extract_money(text);
+            assert_eq!(found.len(), 1, "{text}");
+            assert_eq!(found[0].text, money);
+            assert_eq!(found[0].amount.to_string(), amount, "{text}");
+            assert_eq!((found[0].currency, found[0].minor_units), (currency, Some(minor_units)), "{text}");
+        }
+        let found = parser.extract_money("USD 40 and 40 USD");
+        assert_eq!(found.iter().map(|m| m.text.as_str()).collect::<Vec<_>>(), ["USD 40", "40 USD"]);
+        assert!(found.iter().all(|m| m.currency == "USD" && m.minor_units == Some(4000)));
+        let found = parser.extract_money("A$20 or $5mn");
+        assert_eq!(found.iter().map(|m| m.text.as_str()).collect::<Vec<_>>(), ["A$20", "$5mn"]);
+        assert_eq!(found.iter().map(|m| m.currency).collect::<Vec<_>>(), ["AUD", "USD"]);
+        assert_eq!(found.iter().map(|m| m.minor_units).collect::<Vec<_>>(), [Some(2000), Some(500_000_000)]);
+        assert!(parser.extract_money("12 apples and 5 pounds of flour").is_empty());
+        // `m` after a space may be a length
+        assert_eq!(parser.extract_money("$5 m")[0].text, "$5");
+    }
+
+    #[test]
+    fn test_signs_between_amounts() {
+        // the `$` leads the next amount, it isn't the trailing sign of the one before
+        let found = NumberParser::default().extract_money("Items: 3 $10 each");
+        assert_eq!(found.len(), 1);
+        assert_eq!((found[0].text.as_str(), found[0].span), ("$10", (9, 12)));
+        let found = NumberParser::default().extract_money("I paid $5 for 2 $3 items");
+        assert_eq!(found.iter().map(|m| (m.text.as_str(), m.span)).collect::<Vec<_>>(), vec![("$5", (7, 9)), ("$3", (16, 18))]);
+    }
+
+    #[test]
+    fn test_spoken_money() {
+        let mut parser = NumberParser::default();
+        for (text, money, amount, currency, minor_units) in [
+            ("five dollars and ten cents", "five dollars and ten cents", "5.1", "USD", 510),
+            ("twenty euros", "twenty euros", "20", "EUR", 2000),
+            ("£3 and 50 pence", "£3 and 50 pence", "3.5", "GBP", 350),
+            ("only 99 cents", "99 cents", "0.99", "USD", 99),
+        ] {
+            let found = parser.extract_money(text);
+            assert_eq!(found.len(), 1, "{text}");
+            assert_eq!(found[0].text, money);
+            assert_eq!(found[0].amount.to_string(), amount, "{text}");
+            assert_eq!((found[0].currency, found[0].minor_units), (currency, Some(minor_units)), "{text}");
+        }
+    }
+
+    #[test]
+    fn test_minor_units() {
+        assert_eq!(minor_unit_digits("USD"), Some(2));
+        assert_eq!(minor_unit_digits("JPY"), Some(0));
+        assert_eq!(minor_unit_digits("KWD"), Some(3));
+        assert_eq!(minor_unit_digits("XYZ"), None);
+        let found = NumberParser::default().extract_money("$19.99");
+        assert_eq!((found[0].amount.to_string(), found[0].minor_units), ("19.99".to_string(), Some(1999)));
+        let exact = NumberParser::new(Config { exact: Some(true), ..Config::default() }).extract_money("€1,200.50");
+        assert!(matches!(exact[0].amount, NumberValue::Decimal { .. }));
+        assert_eq!(exact[0].minor_units, Some(120_050));
+        let tokens = NumberEntityParser::money(Config::default()).parse("invoice total: $1,000,000");
+        assert_eq!(tokens.len(), 1);
+        assert_eq!(tokens[0].text, "$1,000,000");
+        assert_eq!(tokens[0].entity, Some("money".to_string()));
+    }
+}
@@ -0,0 +1,68 @@
+#[cfg(test)]
+mod tests {
+    use nlp_rust::parsers::number::{
+        num2words, words2num, Config, Data, DataHolder, NumberForm, NumberValue, WordsConfig, WordsStyle,
+    };
+
+    fn spell(value: NumberValue, config: WordsConfig) -> Option<String> {
+        num2words(&value, &config, &Data::new(Config::default()))
+    }
+
+    fn style(style: WordsStyle) -> WordsConfig {
+        WordsConfig { style, ..WordsConfig::default() }
+    }
+
+    #[test]
+    fn test_cardinals() {
+        assert_eq!(spell(0.into(), WordsConfig::default()).unwrap(), "zero");
+        assert_eq!(spell((-21).into(), WordsConfig::default()).unwrap(), "negative twenty-one");
+        assert_eq!(
+            spell(1_234_567_891.into(), WordsConfig::default()).unwrap(),
+            "one billion two hundred thirty-four million five hundred sixty-seven thousand eight hundred ninety-one"
+        );
+        let british = WordsConfig { british_and: true, hyphenate: false, ..WordsConfig::default() };
+        assert_eq!(spell(105.into(), british.clone()).unwrap(), "one hundred and five");
+        assert_eq!(spell(1042.into(), british).unwrap(), "one thousand and forty two");
+        assert_eq!(spell(NumberValue::rational(1, 3).unwrap(), WordsConfig::default()), None);
+    }
+
+    #[test]
+    fn test_ordinals_years_and_decimals() {
+        assert_eq!(spell(121.into(), style(WordsStyle::Ordinal)).unwrap(), "one hundred twenty-first");
+        assert_eq!(spell(100.into(), style(WordsStyle::Ordinal)).unwrap(), "one hundredth");
+        assert_eq!(spell(20.into(), style(WordsStyle::Ordinal)).unwrap(), "twentieth");
+        assert_eq!(spell(1984.into(), style(WordsStyle::Year)).unwrap(), "nineteen eighty-four");
+        assert_eq!(spell(1905.into(), style(WordsStyle::Year)).unwrap(), "nineteen oh five");
+        assert_eq!(spell(1900.into(), style(WordsStyle::Year)).unwrap(), "nineteen hundred");
+        assert_eq!(spell(2005.into(), style(WordsStyle::Year)).unwrap(), "two thousand five");
+        assert_eq!(spell(3.25.into(), style(WordsStyle::Decimal)).unwrap(), "three point two five");
+        assert_eq!(spell((-0.5).into(), WordsConfig::default()).unwrap(), "negative zero point five");
+        assert_eq!(spell(2.5.into(), style(WordsStyle::Ordinal)), None);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let data = Data::new(Config::default());
+        let read = |text: String| {
+            words2num(DataHolder { type_: "text", text: Some(text), int: None, float: None }, &data)
+        };
+        let configs = [
+            WordsConfig::default(),
+            WordsConfig { british_and: true, hyphenate: false, ..WordsConfig::default() },
+        ];
+        for number in [0i64, 7, 13, 40, 99, 101, 110, 999, 1001, 20_020, 700_000, 1_000_001, 987_654_321, -58] {
+            for config in configs.iter().cloned() {
+                let words = spell(number.into(), config).unwrap();
+                let spoken = NumberForm { spoken: true, ..NumberForm::default() };
+                assert_eq!(read(words.clone()), Some(NumberValue::from(number).with_form(spoken)), "{words}");
+                let words = spell(number.into(), style(WordsStyle::Ordinal)).unwrap();
+                let value = read(words.clone()).unwrap();
+                assert!(value.is_ordinal() && value == number as f64, "{words}");
+            }
+        }
+        for number in [3.25, 0.5, 12.75] {
+            let words = spell(number.into(), style(WordsStyle::Decimal)).unwrap();
+            assert_eq!(read(words.clone()).and_then(|v| v.to_f64()), Some(number), "{words}");
+        }
+    }
+}
@@ -0,0 +1,77 @@
+#[cfg(test)]
+mod tests {
+    use nlp_rust::parsers::number::{words2ordinal, Config, Data, NumberParser, NumberValue};
+
+    fn ordinal(text: &str, config: Config) -> Option<i64> {
+        let data = Data::new(config);
+        match words2ordinal(text, &data)? {
+            NumberValue::Integer { value, form } if form.ordinal => Some(value),
+            other => panic!("expected an ordinal integer, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_spoken_ordinals() {
+        assert_eq!(ordinal("twenty-first", Config::default()), Some(21));
+        assert_eq!(ordinal("one hundredth", Config::default()), Some(100));
+        assert_eq!(ordinal("three hundred and fifth", Config::default()), Some(305));
+        assert_eq!(ordinal("two thousandth", Config::default()), Some(2000));
+        assert_eq!(ordinal("quadrillionth", Config::default()), Some(1_000_000_000_000_000));
+        // not ordinals
+        assert_eq!(ordinal("one third", Config::default()), None);
+        assert_eq!(ordinal("third hundred", Config::default()), None);
+        assert_eq!(ordinal("twenty", Config::default()), None);
+    }
+
+    #[test]
+    fn test_numeral_ordinals() {
+        for (text, value) in [("1st", 1), ("2nd", 2), ("3rd", 3), ("11th", 11), ("12th", 12),
+                              ("13th", 13), ("42nd", 42), ("101st", 101), ("111th", 111), ("1,000th", 1000)] {
+            assert_eq!(ordinal(text, Config::default()), Some(value), "{text}");
+        }
+        // digits of other scripts
+        assert_eq!(ordinal("३rd", Config::default()), Some(3));
+        assert_eq!(ordinal("१२३rd", Config::default()), Some(123));
+        for text in ["21th", "1nd", "12nd", "113rd", "१२१th"] {
+            assert_eq!(ordinal(text, Config::default()), None, "{text}");
+        }
+        let lenient = Config { lenient_ordinals: Some(true), ..Config::default() };
+        assert_eq!(ordinal("21th", lenient), Some(21));
+    }
+
+    #[test]
+    fn test_ordinal_extraction() {
+        let found: Vec<(String, String, bool)> = NumberParser::default()
+            .extract("the twenty-first and 42nd but not 21th")
+            .into_iter()
+            .map(|m| (m.text, m.value.to_string(), m.value.is_ordinal()))
+            .collect();
+        assert_eq!(found, vec![
+            ("twenty-first".to_string(), "21".to_string(), true),
+            ("42nd".to_string(), "42".to_string(), true),
+        ]);
+        let found: Vec<String> = NumberParser::new(Config { lenient_ordinals: Some(true), ..Config::default() })
+            .extract("the 21th")
+            .into_iter()
+            .map(|m| m.text)
+            .collect();
+        assert_eq!(found, vec!["21th"]);
+    }
+
+    #[test]
+    fn test_second_as_time() {
+        let ordinals = |text: &str| -> Vec<String> {
+            NumberParser::default()
+                .extract(text)
+                .into_iter()
+                .filter(|m| m.value.is_ordinal())
+                .map(|m| m.text)
+                .collect()
+        };
+        for text in ["one second please", "give me a second", "wait a second, please", "5 per second"] {
+            assert!(ordinals(text).is_empty(), "{text}");
+        }
+        assert_eq!(ordinals("the second time"), vec!["second"]);
+        assert_eq!(ordinals("a second chance"), vec!["second"]);
+    }
+}
@@ -0,0 +1,102 @@
+#[cfg(test)]
+mod tests {
+    use nlp_rust::entity::{EntityParser, ExtractionPipeline, RegexEntityParser};
+    use nlp_rust::parsers::number::NumberParser;
+    use nlp_rust::parsers::number::constants::{FLOAT, HEX, INTEGER, ORDINAL, SPOKEN, SUPERSCRIPT};
+
+    #[test]
+    fn test_extract_digits_and_words() {
+        let mut parser = NumberParser::default();
+        let numbers = parser.extract("I have 5 apples and twenty-one pears, 3.5k too");
+        assert_eq!(numbers.len(), 3);
+
+        assert_eq!(numbers[0].text, "5");
+        assert_eq!(numbers[0].span, (7, 8));
+        assert_eq!(numbers[0].value, 5.0);
+        assert_eq!(numbers[0].number_type, INTEGER);
+
+        assert_eq!(numbers[1].text, "twenty-one");
+        assert_eq!(numbers[1].span, (20, 30));
+        assert_eq!(numbers[1].value, 21.0);
+        assert_eq!(numbers[1].number_type, SPOKEN);
+
+        assert_eq!(numbers[2].text, "3.5k");
+        assert_eq!(numbers[2].value, 3500.0);
+        assert_eq!(numbers[2].number_type, FLOAT);
+    }
+
+    #[test]
+    fn test_extract_number_types() {
+        let mut parser = NumberParser::default();
+        let numbers = parser.extract("0x1F on the 3rd, ¾ of 1,287 and one and a half");
+        let found: Vec<(&str, f64, &str)> = numbers
+            .iter()
+            .map(|n| (n.text.as_str(), n.value.to_f64().unwrap(), n.number_type))
+            .collect();
+        assert_eq!(found, vec![
+            ("0x1F", 31.0, HEX),
+            ("3rd", 3.0, ORDINAL),
+            ("¾", 0.75, SUPERSCRIPT),
+            ("1,287", 1287.0, INTEGER),
+            ("one and a half", 1.5, SPOKEN),
+        ]);
+    }
+
+    #[test]
+    fn test_spans_point_into_original_text() {
+        let text = "Someone paid three hundred and fifty six for ¾ of it";
+        let mut parser = NumberParser::default();
+        for number in parser.extract(text) {
+            assert_eq!(&text[number.span.0..number.span.1], number.text);
+        }
+    }
+
+    #[test]
+    fn test_spans_take_the_article() {
+        let mut parser = NumberParser::default();
+        let found: Vec<(String, (usize, usize))> = parser
+            .extract("a hundred cows, about a million ants and a billion stars")
+            .into_iter()
+            .map(|m| (m.text, m.span))
+            .collect();
+        assert_eq!(found, vec![
+            ("a hundred".to_string(), (0, 9)),
+            ("a million".to_string(), (22, 31)),
+            ("a billion".to_string(), (41, 50)),
+        ]);
+        // the `a` of `5a` isn't an article
+        for text in ["5a million", "x5a hundred"] {
+            let found = parser.extract(text);
+            assert!(found.iter().all(|m| !m.text.starts_with('a')), "{text}");
+            assert!(found.iter().all(|m| text[m.span.0..m.span.1] == m.text), "{text}");
+        }
+    }
+
+    #[test]
+    fn test_no_numbers() {
+        let mut parser = NumberParser::default();
+        assert!(parser.extract("").is_empty());
+        assert!(parser.extract("someone has nothing").is_empty());
+    }
+
+    #[test]
+    fn test_number_parser_in_pipeline() {
+        let email = RegexEntityParser::new(vec![("email", r"\b[a-z]+@[a-z]+\.com\b")], None);
+        let mut pipeline = ExtractionPipeline {
+            parsers: vec![Box::new(email), Box::new(NumberParser::default())],
+        };
+        let tokens = pipeline.extract("mail joe@mail.com twelve times");
+        let entities: Vec<(&str, Option<&str>)> = tokens
+            .iter()
+            .filter(|t| t.entity.is_some())
+            .map(|t| (t.text.as_str(), t.entity.as_deref()))
+            .collect();
+        assert_eq!(entities, vec![("joe@mail.com", Some("email")), ("twelve", Some("number"))]);
+
+        let mut parser = NumberParser::default();
+        let tokens = parser.parse_tokenize("ten cats");
+        assert_eq!(tokens.len(), 2);
+        assert_eq!(tokens[0].span, Some((0, 3)));
+        assert_eq!(tokens[1].text, " cats");
+    }
+}
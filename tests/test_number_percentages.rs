@@ -0,0 +1,56 @@
+#[cfg(test)]
+mod tests {
+    use nlp_rust::entity::EntityParser;
+    use nlp_rust::parsers::number::{Config, NumberEntityParser, NumberParser, NumberValue, PercentUnit};
+
+    #[test]
+    fn test_percent_forms() {
+        let mut parser = NumberParser::default();
+        for (text, percentage, unit, value) in [
+            ("margins of 12%", "12%", PercentUnit::Percent, "0.12"),
+            ("twelve percent", "twelve percent", PercentUnit::Percent, "0.12"),
+            ("0.5 per cent", "0.5 per cent", PercentUnit::Percent, "0.005"),
+            ("3‰ of it", "3‰", PercentUnit::PerMille, "0.003"),
+            ("cut by 25 bps", "25 bps", PercentUnit::BasisPoints, "0.0025"),
+        ] {
+            let found = parser.extract_percentages(text);
+            assert_eq!(found.len(), 1, "{text}");
+            assert_eq!(found[0].text, percentage);
+            assert_eq!(found[0].unit, unit, "{text}");
+            assert_eq!(found[0].value.to_string(), value, "{text}");
+        }
+        let found = parser.extract_percentages("40 basis points, 10 %");
+        assert_eq!(found.iter().map(|p| p.text.as_str()).collect::<Vec<_>>(), ["40 basis points", "10 %"]);
+        assert_eq!(found.iter().map(|p| p.unit).collect::<Vec<_>>(), [PercentUnit::BasisPoints, PercentUnit::Percent]);
+        assert_eq!(found.iter().map(|p| p.value.to_string()).collect::<Vec<_>>(), ["0.004", "0.1"]);
+        assert!(parser.extract_percentages("5 percentages and 2 ppm").is_empty());
+    }
+
+    #[test]
+    fn test_percentage_points() {
+        let mut parser = NumberParser::default();
+        let found = parser.extract_percentages("up 3 percentage points, or 4%");
+        assert_eq!(found.iter().map(|p| p.text.as_str()).collect::<Vec<_>>(), ["3 percentage points", "4%"]);
+        assert_eq!(found.iter().map(|p| p.unit).collect::<Vec<_>>(), [PercentUnit::PercentagePoints, PercentUnit::Percent]);
+        assert_eq!(found.iter().map(|p| p.value.to_string()).collect::<Vec<_>>(), ["0.03", "0.04"]);
+        let found = parser.extract_percentages("a 2pp rise to 7%");
+        assert_eq!(found.len(), 2);
+        assert_eq!((found[0].text.as_str(), found[0].unit), ("2pp", PercentUnit::PercentagePoints));
+        assert_eq!(found[0].value.to_string(), "0.02");
+        // pages, without a percent around
+        assert!(parser.extract_percentages("see 300 pp.").is_empty());
+        assert!(parser.extract_percentages("a 2pp rise").is_empty());
+    }
+
+    #[test]
+    fn test_percentage_values() {
+        let found = NumberParser::new(Config { exact: Some(true), ..Config::default() }).extract_percentages("12% and 1/3 %");
+        assert_eq!(found[0].value.to_string(), "0.12");
+        assert!(matches!(found[1].value, NumberValue::Rational { numer: 1, denom: 300, .. }));
+        assert_eq!(found[0].number.text, "12");
+        let tokens = NumberEntityParser::percentages(Config::default()).parse("rates rose 1.5 percent");
+        assert_eq!(tokens.len(), 1);
+        assert_eq!(tokens[0].text, "1.5 percent");
+        assert_eq!(tokens[0].entity, Some("percentage".to_string()));
+    }
+}
@@ -0,0 +1,66 @@
+#[cfg(test)]
+mod tests {
+    use nlp_rust::parsers::number::{e_notation, Config, NumberParser, NumberValue};
+
+    #[test]
+    fn test_power_forms() {
+        let mut parser = NumberParser::default();
+        for (text, value) in [
+            ("5 × 10^3", "5000"),
+            ("5x10⁻³", "0.005"),
+            ("10⁶", "1000000"),
+            ("ten to the power of six", "1000000"),
+            ("ten to the sixth power", "1000000"),
+            ("two squared", "4"),
+            ("2**10", "1024"),
+            ("five times ten to the minus three", "0.005"),
+            ("10 to the power of -3", "0.001"),
+            ("1.2e-3", "0.0012"),
+        ] {
+            let found = parser.extract(text);
+            assert_eq!(found.len(), 1, "{text}");
+            assert_eq!(found[0].text, text);
+            assert_eq!(found[0].value.to_string(), value, "{text}");
+        }
+        assert_eq!(parser.extract("2 x 3").len(), 2);
+        assert_eq!(parser.extract("from 10 to 20").len(), 2);
+    }
+
+    #[test]
+    fn test_power_parts() {
+        let mut parser = NumberParser::default();
+        for text in ["5 × 10^-3", "5x10⁻³", "5e-3", "five times ten to the minus three"] {
+            let found = parser.extract(text);
+            let power = found[0].power.as_ref().unwrap();
+            assert_eq!(power.mantissa.to_string(), "5", "{text}");
+            assert_eq!(power.base.to_string(), "10", "{text}");
+            assert_eq!(power.exponent, -3, "{text}");
+        }
+        // every form with a power has the same type
+        for text in ["5 × 10^3", "10⁶", "two squared", "1.2e-3", "6.02E23"] {
+            assert_eq!(parser.extract(text)[0].number_type, "power", "{text}");
+        }
+        let found = parser.extract("3 cubed");
+        assert_eq!(found[0].power.as_ref().map(|p| p.exponent), Some(3));
+        assert_eq!(found[0].number_type, "power");
+        assert!(parser.extract("42").pop().unwrap().power.is_none());
+        // exponents beyond a thousand aren't powers
+        assert_eq!(e_notation("2e1000", false).map(|p| p.exponent), Some(1000));
+        assert!(e_notation("1e999999", false).is_none());
+        assert!(parser.extract("1e999999")[0].power.is_none());
+    }
+
+    #[test]
+    fn test_power_exact() {
+        let mut parser = NumberParser::new(Config { exact: Some(true), ..Config::default() });
+        let written = parser.extract("1.2 × 10^-3").pop().unwrap();
+        let e = parser.extract("1.2e-3").pop().unwrap();
+        assert_eq!(written.value, e.value);
+        assert!(matches!(written.value, NumberValue::Decimal { .. }));
+        assert_eq!(written.power.unwrap().to_ratio(), e.power.unwrap().to_ratio());
+        // beyond `MAX_EXPONENT` there is no exact value, and no infinity either
+        assert!(parser.extract("1e99999999").is_empty());
+        let merged = NumberParser::new(Config { merge_powers: Some(false), ..Config::default() }).extract("10^3");
+        assert_eq!(merged.len(), 2);
+    }
+}
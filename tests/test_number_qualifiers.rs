@@ -0,0 +1,59 @@
+#[cfg(test)]
+mod tests {
+    use std::ops::{Bound, RangeBounds};
+
+    use nlp_rust::parsers::number::{find_qualifier, NumberParser};
+    use nlp_rust::parsers::number::QualifierKind::{Approximate, LowerBound, UpperBound};
+
+    #[test]
+    fn test_qualifier_phrasings() {
+        let strict_lower = LowerBound { inclusive: false };
+        let lower = LowerBound { inclusive: true };
+        let strict_upper = UpperBound { inclusive: false };
+        let upper = UpperBound { inclusive: true };
+        let mut parser = NumberParser::default();
+        for (text, number, value, kind, phrase) in [
+            ("About 30 people", "30", "30", Approximate, "About"),
+            ("nearly two million", "two million", "2000000", Approximate, "nearly"),
+            ("over a hundred", "a hundred", "100", strict_lower, "over"),
+            ("at least 5", "5", "5", lower, "at least"),
+            ("no more than 10", "10", "10", upper, "no more than"),
+            ("x ≥ 5", "5", "5", lower, "≥"),
+            ("y < 3.2", "3.2", "3.2", strict_upper, "<"),
+            ("5+ years", "5", "5", lower, "+"),
+            ("10 or fewer", "10", "10", upper, "or fewer"),
+        ] {
+            let found = parser.extract(text);
+            assert_eq!(found.len(), 1, "{text}");
+            assert_eq!(found[0].text, number);
+            assert_eq!(found[0].value.to_string(), value, "{text}");
+            let qualifier = found[0].qualifier.as_ref().unwrap();
+            assert_eq!((qualifier.kind, qualifier.text.as_str()), (kind, phrase), "{text}");
+        }
+        let found = parser.extract("5 + 3 is 8");
+        assert_eq!(found.iter().map(|m| m.text.as_str()).collect::<Vec<_>>(), ["5", "3", "8"]);
+        assert!(found.iter().all(|m| m.qualifier.is_none()));
+        // words ending with a qualifier
+        assert!(find_qualifier("moreover 5", (9, 10)).is_none());
+        assert_eq!(find_qualifier("thunder 5", (8, 9)), None);
+    }
+
+    #[test]
+    fn test_qualifier_bounds() {
+        let numbers = NumberParser::default().extract("more than 5 bedrooms, up to 3 baths, about 100 sqm and 2 garages");
+        let bounds: Vec<_> = numbers.iter().map(|m| m.bounds()).collect();
+        assert_eq!(
+            bounds,
+            vec![
+                Some((Bound::Excluded(5.0), Bound::Unbounded)),
+                Some((Bound::Unbounded, Bound::Included(3.0))),
+                None,
+                Some((Bound::Included(2.0), Bound::Included(2.0))),
+            ]
+        );
+        let bedrooms = bounds[0].unwrap();
+        assert!(!bedrooms.contains(&5.0));
+        assert!(bedrooms.contains(&6.0));
+        assert_eq!(numbers[0].qualifier.as_ref().unwrap().span, (0, 9));
+    }
+}
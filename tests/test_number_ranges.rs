@@ -0,0 +1,62 @@
+#[cfg(test)]
+mod tests {
+    use nlp_rust::entity::EntityParser;
+    use nlp_rust::parsers::number::{Config, NumberEntityParser, NumberParser};
+
+    #[test]
+    fn test_range_phrasings() {
+        let mut parser = NumberParser::default();
+        for (text, range, lower, upper) in [
+            ("wait 5-7 days", "5-7", "5", "7"),
+            ("ages 18–65 only", "18–65", "18", "65"),
+            ("count from 3 to 9", "from 3 to 9", "3", "9"),
+            ("pages 10 through 20", "10 through 20", "10", "20"),
+            ("Between ten and twenty people", "Between ten and twenty", "10", "20"),
+            ("twenty-one to thirty-five", "twenty-one to thirty-five", "21", "35"),
+            ("from 3.5 to four", "from 3.5 to four", "3.5", "4"),
+        ] {
+            let found = parser.extract_ranges(text);
+            assert_eq!(found.len(), 1, "{text}");
+            assert_eq!(found[0].text, range);
+            assert_eq!(found[0].lower.value.to_string(), lower, "{text}");
+            assert_eq!(found[0].upper.value.to_string(), upper, "{text}");
+            assert!(found[0].lower_inclusive && found[0].upper_inclusive, "{text}");
+        }
+    }
+
+    #[test]
+    fn test_range_bounds() {
+        let mut parser = NumberParser::default();
+        for (text, range, lower, upper, inclusive) in [
+            ("between 5 and 7 exclusive", "between 5 and 7 exclusive", "5", "7", (false, false)),
+            ("1 to 10 (exclusive)", "1 to 10 (exclusive)", "1", "10", (true, false)),
+            ("1 to 10, inclusive", "1 to 10, inclusive", "1", "10", (true, true)),
+            ("from 10 to 5 exclusive", "from 10 to 5 exclusive", "5", "10", (false, true)),
+            ("5 to 3", "5 to 3", "3", "5", (true, true)),
+        ] {
+            let found = parser.extract_ranges(text);
+            assert_eq!(found.len(), 1, "{text}");
+            assert_eq!(found[0].text, range);
+            assert_eq!(found[0].lower.value.to_string(), lower, "{text}");
+            assert_eq!(found[0].upper.value.to_string(), upper, "{text}");
+            assert_eq!((found[0].lower_inclusive, found[0].upper_inclusive), inclusive, "{text}");
+        }
+        let found = parser.extract_ranges("between 5 and 7 exclusive");
+        assert_eq!(found[0].contains(6.0), Some(true));
+        assert_eq!(found[0].contains(7.0), Some(false));
+        let found = parser.extract_ranges("from 10 to 5 exclusive");
+        assert_eq!((found[0].contains(5.0), found[0].contains(10.0)), (Some(false), Some(true)));
+    }
+
+    #[test]
+    fn test_not_ranges() {
+        let mut parser = NumberParser::default();
+        for text in ["the 2019-20 season", "5 - 7 = -2", "dial 1-2-3", "5 and 7", "five or six"] {
+            assert!(parser.extract_ranges(text).is_empty(), "{text}");
+        }
+        let tokens = NumberEntityParser::ranges(Config::default()).parse("open 9 to 5 daily");
+        assert_eq!(tokens.len(), 1);
+        assert_eq!(tokens[0].text, "9 to 5");
+        assert_eq!(tokens[0].entity, Some("range".to_string()));
+    }
+}
@@ -0,0 +1,64 @@
+#[cfg(test)]
+mod tests {
+    use nlp_rust::parsers::number::constants::ROMAN;
+    use nlp_rust::parsers::number::{roman2int, Config, NumberParser};
+
+    #[test]
+    fn test_roman2int() {
+        for (numeral, value) in [("I", 1), ("IV", 4), ("IX", 9), ("XIV", 14), ("XL", 40), ("XCIX", 99),
+                                 ("CDXLIV", 444), ("MCMLXXXIV", 1984), ("MMXXIV", 2024), ("MMMCMXCIX", 3999)] {
+            assert_eq!(roman2int(numeral, false), Some(value), "{numeral}");
+            assert_eq!(roman2int(numeral, true), Some(value), "{numeral}");
+        }
+        for numeral in ["", "IIII", "VV", "IC", "IIX", "XCX", "MMMM", "iv", "ABC"] {
+            assert_eq!(roman2int(numeral, false), None, "{numeral}");
+        }
+        assert_eq!(roman2int("IIII", true), Some(4));
+        assert_eq!(roman2int("VIIII", true), Some(9));
+        assert_eq!(roman2int("IC", true), Some(99));
+        assert_eq!(roman2int("IL", true), Some(49));
+        assert_eq!(roman2int("MMMM", true), Some(4000));
+        for numeral in ["IIX", "XCX", "VX", "IVX"] {
+            assert_eq!(roman2int(numeral, true), None, "{numeral}");
+        }
+    }
+
+    #[test]
+    fn test_roman_extraction() {
+        let config = Config { parse_roman: Some(true), ..Config::default() };
+        let mut parser = NumberParser::new(config);
+        let found = parser.extract("Chapter IX covers Louis XVI and World War I.");
+        assert_eq!(found.iter().map(|m| m.text.as_str()).collect::<Vec<_>>(), ["IX", "XVI", "I"]);
+        assert_eq!(found.iter().map(|m| m.value.to_string()).collect::<Vec<_>>(), ["9", "16", "1"]);
+        assert!(found.iter().all(|m| m.number_type == ROMAN));
+        // possessives with either apostrophe, but not contractions
+        for (text, numeral, value) in [
+            ("Louis XVI's reign", "XVI", "16"),
+            ("Louis XVI’s reign", "XVI", "16"),
+            ("Elizabeth I, not what I said", "I", "1"),
+            ("Chapter XL", "XL", "40"),
+        ] {
+            let found = parser.extract(text);
+            assert_eq!(found.len(), 1, "{text}");
+            assert_eq!(found[0].text, numeral);
+            assert_eq!(found[0].value.to_string(), value, "{text}");
+            assert_eq!(found[0].number_type, ROMAN, "{text}");
+        }
+        assert!(parser.extract("I'm sure I’ve seen it").is_empty());
+        // words, abbreviations and uppercase text
+        assert!(parser.extract("I think we should MIX the paint in DC").is_empty());
+        assert!(parser.extract("CIVIC DUTY, VIVID and LIVE").is_empty());
+        // mixed with other numbers
+        let found = parser.extract("Part IV has 12 pages");
+        assert_eq!(found.iter().map(|m| m.text.as_str()).collect::<Vec<_>>(), ["IV", "12"]);
+        assert_eq!(found.iter().map(|m| m.number_type).collect::<Vec<_>>(), [ROMAN, "integer"]);
+        // strict unless asked otherwise
+        assert!(parser.extract("the clock face shows IIII").is_empty());
+        let config = Config { parse_roman: Some(true), lenient_roman: Some(true), ..Config::default() };
+        let found = NumberParser::new(config).extract("the clock face shows IIII");
+        assert_eq!(found.len(), 1);
+        assert_eq!((found[0].text.as_str(), found[0].value.to_string()), ("IIII", "4".to_string()));
+        // off by default
+        assert!(NumberParser::default().extract("Chapter IX").is_empty());
+    }
+}
@@ -0,0 +1,62 @@
+#[cfg(test)]
+mod tests {
+    use nlp_rust::parsers::number::{Config, NumberParser, NumberScale};
+
+    fn scale_parser(language: &str, scale: Option<NumberScale>) -> NumberParser {
+        NumberParser::new(Config { language: Some(language.to_string()), number_scale: scale, ..Config::default() })
+    }
+
+    #[test]
+    fn test_short_and_long_scales() {
+        let short = Some(NumberScale::Short);
+        let long = Some(NumberScale::Long);
+        for (language, scale, text, value) in [
+            ("en", short, "5 billion", "5000000000"),
+            ("en", long, "5 billion", "5000000000000"),
+            ("en", long, "2 trillion", "2000000000000000000"),
+            ("en", long, "3 milliard", "3000000000"),
+            ("fr", short, "cinq billions", "5000000000"),
+            ("es", short, "dos billones", "2000000000"),
+            ("de", long, "zwei Milliarden", "2000000000"),
+        ] {
+            let found = scale_parser(language, scale).extract(text);
+            assert_eq!(found.len(), 1, "{text}");
+            assert_eq!(found[0].text, text);
+            assert_eq!(found[0].value.to_string(), value, "{text}");
+            assert!(!found[0].value.is_ambiguous_scale(), "{text}");
+        }
+    }
+
+    #[test]
+    fn test_scaled_suffixes() {
+        for (scale, text, number, value) in [
+            (NumberScale::Short, "2.5bn", "2.5bn", "2500000000"),
+            (NumberScale::Long, "2.5bn", "2.5bn", "2500000000000"),
+            (NumberScale::Long, "$3B", "3B", "3000000000000"),
+            (NumberScale::Long, "3G", "3G", "3000000000"),
+        ] {
+            let found = scale_parser("en", Some(scale)).extract(text);
+            assert_eq!(found.len(), 1, "{text}");
+            assert_eq!(found[0].text, number);
+            assert_eq!(found[0].value.to_string(), value, "{text}");
+            assert!(!found[0].value.is_ambiguous_scale(), "{text}");
+        }
+    }
+
+    #[test]
+    fn test_ambiguous_scale() {
+        for (language, text, number, value, ambiguous) in [
+            ("en", "5 billion", "5 billion", "5000000000", true),
+            ("en", "$3B", "3B", "3000000000", true),
+            ("fr", "cinq billions", "cinq billions", "5000000000000", true),
+            ("en", "5 million", "5 million", "5000000", false),
+            ("fr", "deux milliards", "deux milliards", "2000000000", false),
+        ] {
+            let found = scale_parser(language, None).extract(text);
+            assert_eq!(found.len(), 1, "{text}");
+            assert_eq!(found[0].text, number);
+            assert_eq!(found[0].value.to_string(), value, "{text}");
+            assert_eq!(found[0].value.is_ambiguous_scale(), ambiguous, "{text}");
+        }
+    }
+}
@@ -0,0 +1,54 @@
+#[cfg(test)]
+mod tests {
+    use nlp_rust::parsers::number::{ascii_digits, parse_number, string_to_num, DigitScript, NumberParser};
+
+    #[test]
+    fn test_digit_scripts() {
+        let mut parser = NumberParser::default();
+        for (text, number, span, value, script) in [
+            ("price ٣٤٥", "٣٤٥", (6, 12), "345", DigitScript::ArabicIndic),
+            ("۱۲۳", "۱۲۳", (0, 6), "123", DigitScript::ExtendedArabicIndic),
+            ("कुल १२३", "१२३", (10, 19), "123", DigitScript::Devanagari),
+            ("১২৩", "১২৩", (0, 9), "123", DigitScript::Bengali),
+            ("ราคา ๑๒๓ บาท", "๑๒๓", (13, 22), "123", DigitScript::Thai),
+        ] {
+            let found = parser.extract(text);
+            assert_eq!(found.len(), 1, "{text}");
+            assert_eq!((found[0].text.as_str(), found[0].span), (number, span));
+            assert_eq!(found[0].value.to_string(), value, "{text}");
+            assert_eq!(found[0].value.script(), script, "{text}");
+        }
+        let found = parser.extract("１２３ and 45");
+        assert_eq!(found.iter().map(|m| (m.text.as_str(), m.span)).collect::<Vec<_>>(), [("１２３", (0, 9)), ("45", (14, 16))]);
+        assert_eq!(found.iter().map(|m| m.value.script()).collect::<Vec<_>>(), [DigitScript::Fullwidth, DigitScript::Ascii]);
+    }
+
+    #[test]
+    fn test_scripts_with_separators() {
+        let mut parser = NumberParser::default();
+        for (text, number, span, value, script) in [
+            ("٣٫٥ kg", "٣٫٥", (0, 6), "3.5", DigitScript::ArabicIndic),
+            ("١٬٠٠٠", "١٬٠٠٠", (0, 10), "1000", DigitScript::ArabicIndic),
+            ("１２.５", "１２.５", (0, 10), "12.5", DigitScript::Fullwidth),
+            ("１２万", "１２万", (0, 9), "120000", DigitScript::Fullwidth),
+        ] {
+            let found = parser.extract(text);
+            assert_eq!(found.len(), 1, "{text}");
+            assert_eq!((found[0].text.as_str(), found[0].span), (number, span));
+            assert_eq!(found[0].value.to_string(), value, "{text}");
+            assert_eq!(found[0].value.script(), script, "{text}");
+        }
+        let found = parser.extract("around ٥٠");
+        assert_eq!(found[0].qualifier.as_ref().map(|q| (q.text.as_str(), q.span)), Some(("around", (0, 6))));
+    }
+
+    #[test]
+    fn test_parse_scripts() {
+        assert_eq!(ascii_digits("٣٤٥ and १२३"), "345 and 123");
+        let value = parse_number("٣٤٥").unwrap();
+        assert_eq!((value.to_string(), value.script()), ("345".to_string(), DigitScript::ArabicIndic));
+        let value = string_to_num("١٢٫٥".to_string()).unwrap();
+        assert_eq!((value.to_string(), value.script()), ("12.5".to_string(), DigitScript::ArabicIndic));
+        assert!(parse_number("١٢x").is_err());
+    }
+}
@@ -0,0 +1,58 @@
+#[cfg(test)]
+mod tests {
+    use nlp_rust::parsers::number::{Config, NumberLocale, NumberParser, NumberSystem, Separators};
+
+    #[test]
+    fn test_indian_system() {
+        let mut parser = NumberParser::default();
+        for (text, number, value, system) in [
+            ("3 lakh", "3 lakh", "300000", NumberSystem::Indian),
+            ("2.5 crore", "2.5 crore", "25000000", NumberSystem::Indian),
+            ("five lakh twenty thousand", "five lakh twenty thousand", "520000", NumberSystem::Indian),
+            ("Rs 1 lakh crore", "1 lakh crore", "1000000000000", NumberSystem::Indian),
+            ("2 thousand crore", "2 thousand crore", "20000000000", NumberSystem::Indian),
+            ("a 2.5cr deal", "2.5cr", "25000000", NumberSystem::Indian),
+            ("5 million", "5 million", "5000000", NumberSystem::Western),
+        ] {
+            let found = parser.extract(text);
+            assert_eq!(found.len(), 1, "{text}");
+            assert_eq!(found[0].text, number);
+            assert_eq!(found[0].value.to_string(), value, "{text}");
+            assert_eq!(found[0].value.system(), system, "{text}");
+        }
+        let found = NumberParser::new(Config {
+            number_locale: Some(NumberLocale::Fixed(Separators::indian())),
+            ..Config::default()
+        })
+        .extract("12,34,567");
+        assert_eq!((found[0].value.to_string(), found[0].value.system()), ("1234567".to_string(), NumberSystem::Indian));
+    }
+
+    #[test]
+    fn test_myriad_system() {
+        let mut parser = NumberParser::default();
+        for (text, number, value) in [
+            ("3万", "3万", "30000"),
+            ("1億2000万円", "1億2000万", "120000000"),
+            ("12万3456", "12万3456", "123456"),
+            ("3.5万人", "3.5万", "35000"),
+            ("1,234万", "1,234万", "12340000"),
+        ] {
+            let found = parser.extract(text);
+            assert_eq!(found.len(), 1, "{text}");
+            assert_eq!(found[0].text, number);
+            assert_eq!(found[0].value.to_string(), value, "{text}");
+            assert_eq!(found[0].value.system(), NumberSystem::Myriad, "{text}");
+        }
+        assert!(parser.extract("2000万1億").is_empty());
+    }
+
+    #[test]
+    fn test_systems_exact() {
+        let mut parser = NumberParser::new(Config { exact: Some(true), ..Config::default() });
+        let found = parser.extract("5兆 and 2.5 lakh crore");
+        assert_eq!(found[0].value.to_string(), "5000000000000");
+        assert_eq!(found[1].value.to_string(), "2500000000000");
+        assert_eq!(found[1].value.system(), NumberSystem::Indian);
+    }
+}
@@ -0,0 +1,54 @@
+#[cfg(test)]
+mod tests {
+    use nlp_rust::entity::EntityParser;
+    use nlp_rust::parsers::number::{parse_unit, Config, Data, Dimension, NumberEntityParser, NumberParser};
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() <= 1e-9 * b.abs().max(1.0)
+    }
+
+    #[test]
+    fn test_units() {
+        let mut parser = NumberParser::default();
+        let expected = [
+            ("ran 5 km", "5 km", "km", Dimension::Length, 5000.0),
+            ("3.2 kilograms", "3.2 kilograms", "kilograms", Dimension::Mass, 3.2),
+            ("twelve mph", "twelve mph", "mph", Dimension::Speed, 5.36448),
+            ("450 mL of water", "450 mL", "mL", Dimension::Volume, 0.45),
+            ("98.6 °F", "98.6 °F", "°F", Dimension::Temperature, 310.15),
+            ("a 10GB disk", "10GB", "GB", Dimension::DataSize, 1e10),
+            ("wait 3 ms", "3 ms", "ms", Dimension::Time, 0.003),
+            ("60 miles per hour", "60 miles per hour", "miles per hour", Dimension::Speed, 26.8224),
+            ("it took 5h", "5h", "h", Dimension::Time, 18_000.0),
+            ("5 kilo meters", "5 kilo meters", "meters", Dimension::Length, 5000.0),
+        ];
+        for (text, found, unit, dimension, base) in expected {
+            let result = parser.extract_quantities(text);
+            assert_eq!(result.len(), 1, "{text}");
+            let quantity = &result[0];
+            assert_eq!((quantity.text.as_str(), quantity.unit.name.as_str(), quantity.unit.dimension), (found, unit, dimension), "{text}");
+            let found_base = quantity.base_value().unwrap();
+            assert!(close(found_base, base), "{text}: {found_base}");
+        }
+        assert!(parser.extract_quantities("meet at 5 pm with 3 kids").is_empty());
+    }
+
+    #[test]
+    fn test_unit_conversion() {
+        let data = Data::new(Config::default());
+        let celsius = parse_unit("°C", &data).unwrap();
+        let fever = &NumberParser::default().extract_quantities("98.6 °F")[0];
+        assert!(close(fever.to(&celsius).unwrap(), 37.0));
+        assert_eq!(fever.to(&parse_unit("kg", &data).unwrap()), None);
+        let mile = parse_unit("miles", &data).unwrap();
+        assert!(close(parse_unit("km", &data).unwrap().convert(1.609344, &mile).unwrap(), 1.0));
+        assert!(close(parse_unit("MiB", &data).unwrap().convert(1.0, &parse_unit("kilobytes", &data).unwrap()).unwrap(), 1048.576));
+        assert_eq!(parse_unit("Mbit", &data).unwrap().factor, 125_000.0);
+        assert_eq!(parse_unit("µs", &data).unwrap().dimension, Dimension::Time);
+        assert!(parse_unit("kids", &data).is_none());
+        let tokens = NumberEntityParser::quantities(Config::default()).parse("a 2 kg bag");
+        assert_eq!(tokens.len(), 1);
+        assert_eq!(tokens[0].text, "2 kg");
+        assert_eq!(tokens[0].entity, Some("quantity".to_string()));
+    }
+}
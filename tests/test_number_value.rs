@@ -0,0 +1,79 @@
+#[cfg(test)]
+mod tests {
+    use nlp_rust::parsers::number::{
+        parse_number, string_to_num, words2num, Config, Data, DataHolder, NumberForm, NumberParser, NumberValue,
+    };
+    use nlp_rust::parsers::number::constants::{BINARY, FLOAT, HEX, INTEGER, OCTAL, ORDINAL, RATIONAL, SPOKEN};
+
+    fn convert(text: &str) -> Option<NumberValue> {
+        let data = Data::new(Config::default());
+        let holder = DataHolder {
+            type_: "text",
+            text: Some(text.to_string()),
+            int: None,
+            float: None,
+        };
+        words2num(holder, &data)
+    }
+
+    #[test]
+    fn test_parse_number_radix() {
+        assert_eq!(parse_number("123"), Ok(NumberValue::from(123)));
+        assert_eq!(parse_number("0b1010").map(|v| (v.radix(), v.kind())), Ok((2, BINARY)));
+        assert_eq!(parse_number("0o755").map(|v| (v.radix(), v.kind())), Ok((8, OCTAL)));
+        assert_eq!(parse_number("0x1A3F"), Ok(NumberValue::Integer { value: 6719, form: NumberForm::radix(16) }));
+        assert!(parse_number("0b102").is_err());
+        assert!(parse_number("xyz").is_err());
+    }
+
+    #[test]
+    fn test_string_to_num() {
+        assert_eq!(string_to_num("123".to_string()), Some(NumberValue::from(123)));
+        assert_eq!(string_to_num("123.45".to_string()), Some(NumberValue::from(123.45)));
+        assert_eq!(string_to_num("Hello, World!".to_string()), None);
+    }
+
+    #[test]
+    fn test_words2num_variants() {
+        match convert("forty-two") {
+            Some(NumberValue::Integer { value, form }) => {
+                assert_eq!(value, 42);
+                assert!(form.spoken && !form.ordinal);
+            }
+            other => panic!("expected an integer, got {:?}", other),
+        }
+        assert!(matches!(convert("one point five"), Some(NumberValue::Float { value, .. }) if value == 1.5));
+        assert!(matches!(convert("1,287"), Some(NumberValue::Integer { value: 1287, .. })));
+        assert_eq!(convert("0x1F").map(|v| v.kind()), Some(HEX));
+        assert_eq!(convert("twenty first").map(|v| (v.kind(), v.to_f64())), Some((ORDINAL, Some(21.0))));
+        assert_eq!(convert("2.5").map(|v| v.kind()), Some(FLOAT));
+        assert_eq!(convert("invalid text"), None);
+    }
+
+    #[test]
+    fn test_kind_and_display() {
+        let half = NumberValue::rational(-2, -4).unwrap();
+        assert_eq!(half, NumberValue::Rational { numer: 1, denom: 2, form: NumberForm::default() });
+        assert_eq!((half.kind(), half.to_string(), half.to_f64()), (RATIONAL, "1/2".to_string(), Some(0.5)));
+        assert_eq!(NumberValue::rational(1, 0), None);
+        // the ends of `i64`
+        assert_eq!(NumberValue::rational(i64::MIN, 3).unwrap().to_string(), format!("{}/3", i64::MIN));
+        assert_eq!(NumberValue::rational(i64::MIN, i64::MIN).unwrap().to_string(), "1/1");
+        assert_eq!(NumberValue::rational(2, i64::MIN).unwrap().to_string(), format!("-1/{}", 1u64 << 62));
+        assert_eq!(NumberValue::rational(1, i64::MIN), None);
+
+        let complex = NumberValue::Complex { re: 1.0, im: -2.0, form: NumberForm::default() };
+        assert_eq!((complex.to_string(), complex.to_f64()), ("1-2j".to_string(), None));
+
+        assert_eq!(NumberValue::from_float(3.0).kind(), INTEGER);
+        assert_eq!(NumberValue::from_float(3.5).kind(), FLOAT);
+    }
+
+    #[test]
+    fn test_number_match_value() {
+        let numbers = NumberParser::default().extract("seven 0b11 oranges");
+        assert_eq!(numbers[0].value.kind(), SPOKEN);
+        assert_eq!(numbers[0].value, 7.0);
+        assert_eq!(numbers[1].value, NumberValue::Integer { value: 3, form: NumberForm::radix(2) });
+    }
+}
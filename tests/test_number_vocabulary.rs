@@ -0,0 +1,58 @@
+#[cfg(test)]
+mod tests {
+    use nlp_rust::parsers::number::{
+        builtin_pack, Config, Data, NumberParser, Vocabulary, VocabularyError,
+    };
+
+    fn extract(vocabulary: Vocabulary, text: &str) -> Vec<(String, String)> {
+        NumberParser::with_data(Data::with_language(Config::default(), vocabulary))
+            .extract(text)
+            .into_iter()
+            .map(|m| (m.text, m.value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_extending_english() {
+        let vocabulary = Vocabulary::from_json(r#"{"extends": "en", "multiples": {"Lakh": 100000}}"#).unwrap();
+        assert_eq!(vocabulary.code, "en");
+        assert_eq!(extract(vocabulary, "two lakh rupees and twenty-one paise"), vec![
+            ("two lakh".to_string(), "200000".to_string()),
+            ("twenty-one".to_string(), "21".to_string()),
+        ]);
+        // the built-in tables stay the default
+        let mut parser = NumberParser::default();
+        assert_eq!(parser.extract("two lakh").len(), 1);
+    }
+
+    #[test]
+    fn test_builtin_packs_round_trip() {
+        for code in ["en", "es", "fr", "de"] {
+            let vocabulary = Vocabulary::from_pack(builtin_pack(code).unwrap().as_ref());
+            vocabulary.validate().unwrap();
+            let json = vocabulary.to_json().unwrap();
+            let read = Vocabulary::from_json(&json).unwrap();
+            assert_eq!(read.to_json().unwrap(), json);
+        }
+    }
+
+    #[test]
+    fn test_invalid_vocabularies() {
+        let error = |json: &str| Vocabulary::from_json(json).unwrap_err();
+        assert!(matches!(
+            error(r#"{"extends": "en", "multiples": {"lakh": 100000, "lakh": 100000}}"#),
+            VocabularyError::Duplicate { table, word } if table == "multiples" && word == "lakh"
+        ));
+        assert!(matches!(
+            error(r#"{"extends": "en", "tens": {"one": 10}}"#),
+            VocabularyError::Conflict { word, first, second } if word == "one" && first == "ones" && second == "tens"
+        ));
+        assert!(matches!(
+            error(r#"{"extends": "en", "ands": ["point"]}"#),
+            VocabularyError::Conflict { word, .. } if word == "point"
+        ));
+        assert!(matches!(error(r#"{"ones": {"uno": 1}}"#), VocabularyError::Empty(table) if table == "ands"));
+        assert!(matches!(error(r#"{"extends": "xx"}"#), VocabularyError::UnknownLanguage(code) if code == "xx"));
+        assert!(matches!(error(r#"{"extends": "en", "multiple": {}}"#), VocabularyError::Json(_)));
+    }
+}
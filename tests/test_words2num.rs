@@ -6,7 +6,7 @@ mod tests {
     #[test]
     fn test_single_digit_conversion() {
         let config = Config::default();
-        let mut data = Data::new(config);
+        let data = Data::new(config);
         let dataholder = DataHolder {
             type_: "text",
             text: Some("five".to_string()),
@@ -14,14 +14,14 @@ mod tests {
             float: None,
         };
         
-        let result = words2num(dataholder, &mut data);
-        assert_eq!(result, Some(5.0));
+        let result = words2num(dataholder, &data);
+        assert_eq!(result.and_then(|v| v.to_f64()), Some(5.0));
     }
 
     #[test]
     fn test_teens_conversion() {
         let config = Config::default();
-        let mut data = Data::new(config);
+        let data = Data::new(config);
         let dataholder = DataHolder {
             type_: "text",
             text: Some("17".to_string()),
@@ -29,14 +29,14 @@ mod tests {
             float: None,
         };
         
-        let result = words2num(dataholder, &mut data);
-        assert_eq!(result, Some(17.0));
+        let result = words2num(dataholder, &data);
+        assert_eq!(result.and_then(|v| v.to_f64()), Some(17.0));
     }
 
     #[test]
     fn test_tens_conversion() {
         let config = Config::default();
-        let mut data = Data::new(config);
+        let data = Data::new(config);
         let dataholder = DataHolder {
             type_: "text",
             text: Some("42".to_string()),
@@ -44,14 +44,14 @@ mod tests {
             float: None,
         };
         
-        let result = words2num(dataholder, &mut data);
-        assert_eq!(result, Some(42.0));
+        let result = words2num(dataholder, &data);
+        assert_eq!(result.and_then(|v| v.to_f64()), Some(42.0));
     }
 
     #[test]
     fn test_large_number_conversion() {
         let config = Config::default();
-        let mut data = Data::new(config);
+        let data = Data::new(config);
         let dataholder = DataHolder {
             type_: "text",
             text: Some("1234".to_string()),
@@ -59,14 +59,14 @@ mod tests {
             float: None,
         };
         
-        let result = words2num(dataholder, &mut data);
-        assert_eq!(result, Some(1234.0));
+        let result = words2num(dataholder, &data);
+        assert_eq!(result.and_then(|v| v.to_f64()), Some(1234.0));
     }
 
     #[test]
     fn test_invalid_text() {
         let config = Config::default();
-        let mut data = Data::new(config);
+        let data = Data::new(config);
         let dataholder = DataHolder {
             type_: "text",
             text: Some("invalid text".to_string()),
@@ -74,14 +74,14 @@ mod tests {
             float: None,
         };
         
-        let result = words2num(dataholder, &mut data);
+        let result = words2num(dataholder, &data);
         assert_eq!(result, None);
     }
 
     #[test]
     fn test_mixed_case_text() {
         let config = Config::default();
-        let mut data = Data::new(config);
+        let data = Data::new(config);
         let dataholder = DataHolder {
             type_: "text",
             text: Some("356".to_string()),
@@ -89,14 +89,14 @@ mod tests {
             float: None,
         };
         
-        let result = words2num(dataholder, &mut data);
-        assert_eq!(result, Some(356.0));
+        let result = words2num(dataholder, &data);
+        assert_eq!(result.and_then(|v| v.to_f64()), Some(356.0));
     }
 
     #[test]
     fn test_float_conversion() {
         let config = Config::default();
-        let mut data = Data::new(config);
+        let data = Data::new(config);
         let dataholder = DataHolder {
             type_: "text",
             text: Some("1.5".to_string()),
@@ -104,7 +104,7 @@ mod tests {
             float: None,
         };
         
-        let result = words2num(dataholder, &mut data);
-        assert_eq!(result, Some(1.5));
+        let result = words2num(dataholder, &data);
+        assert_eq!(result.and_then(|v| v.to_f64()), Some(1.5));
     }
 }
@@ -14,7 +14,7 @@ fn test_tokenization() {
     }
 }
 
-fn text_to_num(text: &str, data: &mut Data) -> Option<f64> {
+fn text_to_num(text: &str, data: &mut Data) -> Option<NumberValue> {
     let holder = DataHolder { type_: "text", text: Some(text.to_string()), int: None, float: None};
     words2num(holder, data)
 }